@@ -1,3 +1,4 @@
 mod genesis;
 pub mod start;
+pub mod store;
 pub use genesis::make_genesis;
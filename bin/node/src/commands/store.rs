@@ -0,0 +1,126 @@
+use std::{fs, path::Path};
+
+use anyhow::{anyhow, Result};
+use miden_node_store::{
+    archive::StateArchive,
+    config::StoreConfig,
+    db::Db,
+    state::{verify_chain_tip, verify_db, State},
+};
+
+// ROLLBACK
+// ===================================================================================================
+
+/// Reverts the store to block `block_num`, deleting the data of all the blocks after it.
+pub async fn rollback_store(config: StoreConfig, block_num: u32) -> Result<()> {
+    let state = load_state(config).await?;
+
+    let count = state
+        .rollback_to(block_num)
+        .await
+        .map_err(|err| anyhow!("Failed to rollback store: {}", err))?;
+
+    println!("Store rolled back to block {block_num}, {count} database rows affected.");
+
+    Ok(())
+}
+
+// VERIFY
+// ===================================================================================================
+
+/// Checks the data of every block in the store against its block header.
+pub async fn verify_store(config: StoreConfig) -> Result<()> {
+    let db = Db::setup(config)
+        .await
+        .map_err(|err| anyhow!("Failed to setup database: {}", err))?;
+
+    let chain_tip = verify_db(&db).await.map_err(|err| anyhow!("Verification failed: {}", err))?;
+
+    println!("Store verified, blocks 0 to {chain_tip} match their headers.");
+
+    Ok(())
+}
+
+// EXPORT
+// ===================================================================================================
+
+/// Writes the state of the store at block `block_num`, or at the chain tip if [None], to an archive
+/// at `output_path`.
+pub async fn export_store(
+    config: StoreConfig,
+    block_num: Option<u32>,
+    output_path: &Path,
+) -> Result<()> {
+    let db = Db::setup(config)
+        .await
+        .map_err(|err| anyhow!("Failed to setup database: {}", err))?;
+
+    let archive = StateArchive::export(&db, block_num)
+        .await
+        .map_err(|err| anyhow!("Failed to export store: {}", err))?;
+    archive
+        .write(output_path)
+        .map_err(|err| anyhow!("Failed to write archive: {}", err))?;
+
+    let block_num = archive.block_header().map(|header| header.block_num()).unwrap_or_default();
+    println!("Store exported at block {block_num} to {}.", output_path.display());
+
+    Ok(())
+}
+
+// IMPORT
+// ===================================================================================================
+
+/// Creates the store's database from the archive at `input_path`, and checks the imported state
+/// against the last block header of the archive.
+pub async fn import_store(config: StoreConfig, input_path: &Path) -> Result<()> {
+    let archive =
+        StateArchive::read(input_path).map_err(|err| anyhow!("Failed to read archive: {}", err))?;
+
+    let database_filepath = config.database_filepath.clone();
+    let db = Db::import(config, archive)
+        .await
+        .map_err(|err| anyhow!("Failed to import archive: {}", err))?;
+
+    match verify_chain_tip(&db).await {
+        Ok(chain_tip) => {
+            println!("Store imported, chain tip at block {chain_tip} matches its header.");
+            Ok(())
+        },
+        Err(err) => {
+            drop(db);
+            remove_database(&database_filepath)?;
+            Err(anyhow!("Imported state doesn't match the archive, database removed: {}", err))
+        },
+    }
+}
+
+// HELPERS
+// ===================================================================================================
+
+async fn load_state(config: StoreConfig) -> Result<State> {
+    let snapshot_file = config.snapshot_file();
+
+    let db = Db::setup(config)
+        .await
+        .map_err(|err| anyhow!("Failed to setup database: {}", err))?;
+
+    State::load(db, snapshot_file)
+        .await
+        .map_err(|err| anyhow!("Failed to load store state: {}", err))
+}
+
+/// Removes the database file at `database_filepath`, together with its WAL files.
+fn remove_database(database_filepath: &Path) -> Result<()> {
+    for suffix in ["", "-wal", "-shm"] {
+        let mut path = database_filepath.as_os_str().to_owned();
+        path.push(suffix);
+        let path = Path::new(&path);
+        if path.exists() {
+            fs::remove_file(path)
+                .map_err(|err| anyhow!("Failed to remove {}: {}", path.display(), err))?;
+        }
+    }
+
+    Ok(())
+}
@@ -18,7 +18,9 @@ mod tests {
     use figment::Jail;
     use miden_node_block_producer::config::BlockProducerConfig;
     use miden_node_rpc::config::RpcConfig;
-    use miden_node_store::config::StoreConfig;
+    use miden_node_store::config::{
+        StoreConfig, DEFAULT_ACCOUNT_CHECKPOINT_INTERVAL, DEFAULT_SNAPSHOT_INTERVAL,
+    };
     use miden_node_utils::config::{load_config, Endpoint};
 
     use super::NodeConfig;
@@ -75,7 +77,11 @@ mod tests {
                             port: 8080,
                         },
                         database_filepath: "local.sqlite3".into(),
-                        genesis_filepath: "genesis.dat".into()
+                        genesis_filepath: "genesis.dat".into(),
+                        snapshot_filepath: None,
+                        snapshot_interval: DEFAULT_SNAPSHOT_INTERVAL,
+                        account_checkpoint_interval: DEFAULT_ACCOUNT_CHECKPOINT_INTERVAL,
+                        retention: None,
                     }),
                 }
             );
@@ -2,7 +2,10 @@ use std::path::PathBuf;
 
 use anyhow::{anyhow, Context};
 use clap::{Parser, Subcommand};
-use commands::start::{start_block_producer, start_node, start_rpc, start_store};
+use commands::{
+    start::{start_block_producer, start_node, start_rpc, start_store},
+    store::{export_store, import_store, rollback_store, verify_store},
+};
 use config::NodeConfig;
 use miden_node_utils::config::load_config;
 
@@ -38,6 +41,15 @@ pub enum Command {
         config: PathBuf,
     },
 
+    /// Maintenance operations on the store's database
+    Store {
+        #[command(subcommand)]
+        command: StoreCommand,
+
+        #[arg(short, long, value_name = "FILE", default_value = NODE_CONFIG_FILE_PATH)]
+        config: PathBuf,
+    },
+
     /// Generates a genesis file and associated account files based on a specified genesis input
     ///
     /// This command creates a new genesis file and associated account files at the specified output
@@ -66,6 +78,50 @@ pub enum StartCommand {
     Store,
 }
 
+#[derive(Subcommand)]
+pub enum StoreCommand {
+    /// Reverts the chain to the specified block
+    ///
+    /// Deletes the headers, notes, nullifiers and transactions of all the blocks after the target
+    /// block, and restores the accounts to their state at that block. The node must not be running.
+    Rollback {
+        /// Number of the block to revert the chain to
+        #[arg(long, value_name = "BLOCK_NUM")]
+        to: u32,
+    },
+
+    /// Checks the data of every block against its block header
+    ///
+    /// Rebuilds the account tree, nullifier tree, chain MMR and note tree of each block from the
+    /// database, and reports the first block whose header doesn't match them.
+    Verify,
+
+    /// Writes the state of the chain at the specified block to an archive file
+    ///
+    /// The archive contains the block headers, the accounts, the unconsumed notes and the
+    /// nullifiers, and is used to bootstrap a new store with the `import` command.
+    Export {
+        /// Number of the block to export the state at, defaults to the chain tip
+        #[arg(long, value_name = "BLOCK_NUM")]
+        block_num: Option<u32>,
+
+        /// Write the archive to this location
+        #[arg(short, long, value_name = "FILE")]
+        output_path: PathBuf,
+    },
+
+    /// Creates the store's database from an archive file
+    ///
+    /// The database must not exist yet. The account tree, nullifier tree and chain MMR rebuilt from
+    /// the archive are checked against its last block header, and the database is removed if they
+    /// don't match.
+    Import {
+        /// Read the archive from this location
+        #[arg(short, long, value_name = "FILE")]
+        input_path: PathBuf,
+    },
+}
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     miden_node_utils::logging::setup_logging()?;
@@ -93,6 +149,20 @@ async fn main() -> anyhow::Result<()> {
                 },
             }
         },
+        Command::Store { command, config } => {
+            let config: NodeConfig = load_config(config).extract().map_err(|err| {
+                anyhow!("failed to load config file `{}`: {err}", config.display())
+            })?;
+            let config = config.store.context("Missing store configuration.")?;
+            match command {
+                StoreCommand::Rollback { to } => rollback_store(config, *to).await,
+                StoreCommand::Verify => verify_store(config).await,
+                StoreCommand::Export { block_num, output_path } => {
+                    export_store(config, *block_num, output_path).await
+                },
+                StoreCommand::Import { input_path } => import_store(config, input_path).await,
+            }
+        },
         Command::MakeGenesis { output_path, force, inputs_path } => {
             commands::make_genesis(inputs_path, output_path, force)
         },
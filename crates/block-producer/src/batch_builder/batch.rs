@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 
-use miden_node_proto::domain::accounts::AccountUpdateDetails;
+use miden_node_proto::domain::{accounts::AccountUpdateDetails, transactions::TransactionHeader};
 use miden_objects::{
     accounts::AccountId,
     batches::BatchNoteTree,
@@ -25,6 +25,7 @@ pub type BatchId = Blake3Digest<32>;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TransactionBatch {
     id: BatchId,
+    transactions: Vec<TransactionHeader>,
     updated_accounts: BTreeMap<AccountId, AccountStates>,
     produced_nullifiers: Vec<Nullifier>,
     created_notes_smt: BatchNoteTree,
@@ -46,6 +47,8 @@ impl TransactionBatch {
     pub fn new(txs: Vec<ProvenTransaction>) -> Result<Self, BuildBatchError> {
         let id = Self::compute_id(&txs);
 
+        let transactions = txs.iter().map(TransactionHeader::from).collect();
+
         let updated_accounts = txs
             .iter()
             .map(|tx| {
@@ -78,6 +81,7 @@ impl TransactionBatch {
 
         Ok(Self {
             id,
+            transactions,
             updated_accounts,
             produced_nullifiers,
             created_notes_smt,
@@ -93,6 +97,11 @@ impl TransactionBatch {
         self.id
     }
 
+    /// Returns headers of the transactions in this batch, in the order they were batched.
+    pub fn transactions(&self) -> &[TransactionHeader] {
+        &self.transactions
+    }
+
     /// Returns an iterator over (account_id, init_state_hash) tuples for accounts that were
     /// modified in this transaction batch.
     pub fn account_initial_states(&self) -> impl Iterator<Item = (AccountId, Digest)> + '_ {
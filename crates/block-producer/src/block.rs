@@ -1,7 +1,7 @@
 use std::collections::BTreeMap;
 
 use miden_node_proto::{
-    domain::accounts::AccountUpdateDetails,
+    domain::{accounts::AccountUpdateDetails, transactions::TransactionHeader},
     errors::{ConversionError, MissingFieldHelper},
     generated::responses::GetBlockInputsResponse,
     AccountInputRecord, NullifierWitness,
@@ -24,6 +24,7 @@ pub struct Block {
     pub updated_accounts: Vec<AccountUpdateDetails>,
     pub created_notes: Vec<NoteBatch>,
     pub produced_nullifiers: Vec<Nullifier>,
+    pub transactions: Vec<TransactionHeader>,
     // TODO:
     // - full states for created public notes
     // - zk proof
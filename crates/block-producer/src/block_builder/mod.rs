@@ -79,6 +79,9 @@ where
         let produced_nullifiers: Vec<Nullifier> =
             batches.iter().flat_map(TransactionBatch::produced_nullifiers).collect();
 
+        let transactions =
+            batches.iter().flat_map(|batch| batch.transactions().iter().cloned()).collect();
+
         let block_inputs = self
             .store
             .get_block_inputs(
@@ -98,6 +101,7 @@ where
             updated_accounts,
             created_notes,
             produced_nullifiers,
+            transactions,
         };
 
         // TODO: Change to block.hash(), once it implemented
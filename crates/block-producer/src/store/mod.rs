@@ -155,6 +155,7 @@ impl ApplyBlock for DefaultStore {
             accounts: convert(&block.updated_accounts),
             nullifiers: convert(&block.produced_nullifiers),
             notes,
+            transactions: convert(&block.transactions),
         });
 
         let _ = self
@@ -146,6 +146,7 @@ impl MockBlockBuilder {
             updated_accounts: self.updated_accounts.unwrap_or_default(),
             created_notes,
             produced_nullifiers: self.produced_nullifiers.unwrap_or_default(),
+            transactions: Vec::new(),
         }
     }
 }
@@ -77,7 +77,7 @@ impl TryFrom<AccountIdPb> for AccountId {
 // ACCOUNT UPDATE
 // ================================================================================================
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AccountSummary {
     pub account_id: AccountId,
     pub account_hash: RpoDigest,
@@ -94,7 +94,7 @@ impl From<&AccountSummary> for AccountSummaryPb {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AccountInfo {
     pub summary: AccountSummary,
     pub details: Option<Account>,
@@ -1,7 +1,7 @@
 use std::fmt::{Debug, Display, Formatter};
 
 use hex::{FromHex, ToHex};
-use miden_objects::{notes::NoteId, Digest, Felt, StarkField};
+use miden_objects::{notes::NoteId, transaction::TransactionId, Digest, Felt, StarkField};
 
 use crate::{errors::ConversionError, generated::digest};
 
@@ -146,6 +146,18 @@ impl From<NoteId> for digest::Digest {
     }
 }
 
+impl From<&TransactionId> for digest::Digest {
+    fn from(value: &TransactionId) -> Self {
+        (*value).inner().into()
+    }
+}
+
+impl From<TransactionId> for digest::Digest {
+    fn from(value: TransactionId) -> Self {
+        value.inner().into()
+    }
+}
+
 // FROM DIGEST
 // ================================================================================================
 
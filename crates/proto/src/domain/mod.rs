@@ -3,6 +3,7 @@ pub mod blocks;
 pub mod digest;
 pub mod merkle;
 pub mod nullifiers;
+pub mod transactions;
 
 // UTILITIES
 // ================================================================================================
@@ -0,0 +1,86 @@
+use miden_objects::{
+    accounts::AccountId,
+    notes::{NoteId, Nullifier},
+    transaction::{ProvenTransaction, TransactionId},
+    Digest,
+};
+
+use crate::{
+    errors::{ConversionError, MissingFieldHelper},
+    generated::transaction::TransactionHeader as TransactionHeaderPb,
+    try_convert,
+};
+
+// TRANSACTION HEADER
+// ================================================================================================
+
+/// Summary of a proven transaction which is recorded by the store for every transaction included
+/// in a block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionHeader {
+    pub id: TransactionId,
+    pub account_id: AccountId,
+    pub initial_account_hash: Digest,
+    pub final_account_hash: Digest,
+    /// Nullifiers of the notes consumed by the transaction.
+    pub input_notes: Vec<Nullifier>,
+    /// IDs of the notes created by the transaction.
+    pub output_notes: Vec<NoteId>,
+}
+
+impl From<&ProvenTransaction> for TransactionHeader {
+    fn from(tx: &ProvenTransaction) -> Self {
+        Self {
+            id: tx.id(),
+            account_id: tx.account_id(),
+            initial_account_hash: tx.initial_account_hash(),
+            final_account_hash: tx.final_account_hash(),
+            input_notes: tx.input_notes().iter().copied().collect(),
+            output_notes: tx.output_notes().iter().map(|note| note.id()).collect(),
+        }
+    }
+}
+
+impl From<&TransactionHeader> for TransactionHeaderPb {
+    fn from(header: &TransactionHeader) -> Self {
+        Self {
+            transaction_id: Some(header.id.into()),
+            account_id: Some(header.account_id.into()),
+            initial_account_hash: Some(header.initial_account_hash.into()),
+            final_account_hash: Some(header.final_account_hash.into()),
+            input_notes: header.input_notes.iter().map(Into::into).collect(),
+            output_notes: header.output_notes.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<TransactionHeaderPb> for TransactionHeader {
+    type Error = ConversionError;
+
+    fn try_from(header: TransactionHeaderPb) -> Result<Self, Self::Error> {
+        let id: Digest = header
+            .transaction_id
+            .ok_or(TransactionHeaderPb::missing_field(stringify!(transaction_id)))?
+            .try_into()?;
+
+        let output_notes: Vec<Digest> = try_convert(header.output_notes)?;
+
+        Ok(Self {
+            id: id.into(),
+            account_id: header
+                .account_id
+                .ok_or(TransactionHeaderPb::missing_field(stringify!(account_id)))?
+                .try_into()?,
+            initial_account_hash: header
+                .initial_account_hash
+                .ok_or(TransactionHeaderPb::missing_field(stringify!(initial_account_hash)))?
+                .try_into()?,
+            final_account_hash: header
+                .final_account_hash
+                .ok_or(TransactionHeaderPb::missing_field(stringify!(final_account_hash)))?
+                .try_into()?,
+            input_notes: try_convert(header.input_notes)?,
+            output_notes: output_notes.into_iter().map(Into::into).collect(),
+        })
+    }
+}
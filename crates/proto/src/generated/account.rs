@@ -30,3 +30,25 @@ pub struct AccountInfo {
     #[prost(bytes = "vec", optional, tag = "2")]
     pub details: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
 }
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountMetadata {
+    #[prost(message, optional, tag = "1")]
+    pub summary: ::core::option::Option<AccountSummary>,
+    /// Type of the account: 0 - wallet, 1 - fungible faucet, 2 - non-fungible faucet.
+    #[prost(uint32, tag = "2")]
+    pub account_type: u32,
+    /// Storage mode of the account, as encoded in its ID.
+    #[prost(bool, tag = "3")]
+    pub on_chain: bool,
+    /// Block of the first recorded state of the account.
+    #[prost(uint32, tag = "4")]
+    pub created_block_num: u32,
+    /// Number of blocks which updated the account, including its creation.
+    #[prost(uint32, tag = "5")]
+    pub num_updates: u32,
+    /// Root of the account code, only set for on-chain accounts with known details.
+    #[prost(message, optional, tag = "6")]
+    pub code_root: ::core::option::Option<super::digest::Digest>,
+}
@@ -10,3 +10,4 @@ pub mod responses;
 pub mod rpc;
 pub mod smt;
 pub mod store;
+pub mod transaction;
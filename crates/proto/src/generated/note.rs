@@ -38,6 +38,9 @@ pub struct NoteSyncRecord {
     pub note_type: u32,
     #[prost(message, optional, tag = "6")]
     pub merkle_path: ::core::option::Option<super::merkle::MerklePath>,
+    /// Set when the Merkle path of the note was pruned by the node, the path is then empty.
+    #[prost(bool, tag = "7")]
+    pub pruned: bool,
 }
 #[derive(Eq, PartialOrd, Ord, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -23,6 +23,8 @@ pub struct ApplyBlockRequest {
     pub nullifiers: ::prost::alloc::vec::Vec<super::digest::Digest>,
     #[prost(message, repeated, tag = "4")]
     pub notes: ::prost::alloc::vec::Vec<super::note::NoteCreated>,
+    #[prost(message, repeated, tag = "5")]
+    pub transactions: ::prost::alloc::vec::Vec<super::transaction::TransactionHeader>,
 }
 #[derive(Eq, PartialOrd, Ord, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -30,6 +32,10 @@ pub struct ApplyBlockRequest {
 pub struct CheckNullifiersRequest {
     #[prost(message, repeated, tag = "1")]
     pub nullifiers: ::prost::alloc::vec::Vec<super::digest::Digest>,
+    /// Block number whose nullifier root the proofs are generated against. If unset, the proofs are
+    /// generated against the latest block. Only recent blocks can be requested.
+    #[prost(fixed32, optional, tag = "2")]
+    pub block_num: ::core::option::Option<u32>,
 }
 #[derive(Eq, PartialOrd, Ord, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -76,6 +82,104 @@ pub struct SyncStateRequest {
     #[prost(uint32, repeated, tag = "4")]
     pub nullifiers: ::prost::alloc::vec::Vec<u32>,
 }
+/// Subscribes to the notes created in new blocks, which match the requested tags or senders in the
+/// same way as in `SyncStateRequest`.
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeNotesRequest {
+    /// Determines the tags which the client is interested in. These are only the 16high bits of the
+    /// note's complete tag.
+    #[prost(uint32, repeated, tag = "1")]
+    pub note_tags: ::prost::alloc::vec::Vec<u32>,
+    /// Senders of the notes which the client is interested in.
+    #[prost(message, repeated, tag = "2")]
+    pub senders: ::prost::alloc::vec::Vec<super::account::AccountId>,
+}
+/// Returns the nullifiers matching the requested prefixes created in the block range
+/// `(from_block_num, to_block_num]`.
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SyncNullifiersRequest {
+    /// Determines the nullifiers the client is interested in. These are only the 16high bits of the
+    /// nullifiers.
+    #[prost(uint32, repeated, tag = "1")]
+    pub nullifiers: ::prost::alloc::vec::Vec<u32>,
+    /// The nullifiers are returned starting from the block after this one.
+    #[prost(fixed32, tag = "2")]
+    pub from_block_num: u32,
+    /// Last block number to include in the response. If unset, the nullifiers are returned up to
+    /// the chain tip.
+    #[prost(fixed32, optional, tag = "3")]
+    pub to_block_num: ::core::option::Option<u32>,
+    /// The requested page of nullifiers.
+    #[prost(message, optional, tag = "4")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Returns the notes matching the requested tags or senders in several blocks at once.
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SyncNotesRequest {
+    /// Last block known by the client. The response will contain the notes of the following blocks.
+    #[prost(fixed32, tag = "1")]
+    pub block_num: u32,
+    /// Determines the tags which the client is interested in. These are only the 16high bits of the
+    /// note's complete tag.
+    #[prost(uint32, repeated, tag = "2")]
+    pub note_tags: ::prost::alloc::vec::Vec<u32>,
+    /// Senders of the notes which the client is interested in.
+    #[prost(message, repeated, tag = "3")]
+    pub senders: ::prost::alloc::vec::Vec<super::account::AccountId>,
+    /// Maximum number of blocks with matching notes to include in the response. If zero, the
+    /// maximum allowed by the server is used.
+    #[prost(uint32, tag = "4")]
+    pub max_blocks: u32,
+}
+/// Subscribes to the headers of the blocks, starting from the requested block.
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeBlocksRequest {
+    /// Number of the first block to stream. The headers of the blocks already in the chain are
+    /// streamed first, followed by the new blocks as they are committed. If unset, only the blocks
+    /// committed after subscribing are streamed.
+    #[prost(fixed32, optional, tag = "1")]
+    pub block_num: ::core::option::Option<u32>,
+    /// Whether to include the number of account updates, nullifiers and notes of each block.
+    #[prost(bool, tag = "2")]
+    pub include_counts: bool,
+}
+/// Returns the authentication paths of block headers in the chain MMR.
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockInclusionProofsRequest {
+    /// The block numbers of the block headers to authenticate.
+    #[prost(fixed32, repeated, tag = "1")]
+    pub block_nums: ::prost::alloc::vec::Vec<u32>,
+    /// Block number whose chain root the proofs verify against, the MMR forest is equal to this
+    /// block number. If unset, the latest block is used.
+    #[prost(fixed32, optional, tag = "2")]
+    pub block_num: ::core::option::Option<u32>,
+}
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockByNumberRequest {
+    /// The block number of the target block.
+    #[prost(fixed32, tag = "1")]
+    pub block_num: u32,
+}
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockByHashRequest {
+    /// The hash of the target block's header.
+    #[prost(message, optional, tag = "1")]
+    pub block_hash: ::core::option::Option<super::digest::Digest>,
+}
 #[derive(Eq, PartialOrd, Ord, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -107,23 +211,126 @@ pub struct SubmitProvenTransactionRequest {
 #[derive(Eq, PartialOrd, Ord, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTransactionInclusionRequest {
+    /// List of transaction IDs to look up
+    #[prost(message, repeated, tag = "1")]
+    pub transaction_ids: ::prost::alloc::vec::Vec<super::digest::Digest>,
+}
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetNotesByIdRequest {
     /// List of NoteId's to be queried from the database
     #[prost(message, repeated, tag = "1")]
     pub note_ids: ::prost::alloc::vec::Vec<super::digest::Digest>,
 }
+/// Returns the inclusion and consumption status of notes.
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNoteStatusRequest {
+    /// List of NoteId's to be queried from the database
+    #[prost(message, repeated, tag = "1")]
+    pub note_ids: ::prost::alloc::vec::Vec<super::digest::Digest>,
+}
+/// Page of the results of a list request, items are sorted by a key specific to each list.
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Pagination {
+    /// Maximum number of items in the page. The default page size is used if unset or greater
+    /// than the maximum.
+    #[prost(uint32, tag = "1")]
+    pub page_size: u32,
+    /// Token returned with the previous page, the first page is returned if unset.
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub continuation_token: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
+/// Lists the nullifiers, sorted by nullifier.
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNullifiersRequest {
+    #[prost(message, optional, tag = "1")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Only return the nullifiers created at or after this block.
+    #[prost(fixed32, optional, tag = "2")]
+    pub block_num_from: ::core::option::Option<u32>,
+    /// Only return the nullifiers created at or before this block.
+    #[prost(fixed32, optional, tag = "3")]
+    pub block_num_to: ::core::option::Option<u32>,
+}
+/// Lists the accounts, sorted by account ID.
 #[derive(Eq, PartialOrd, Ord, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ListNullifiersRequest {}
+pub struct ListAccountsRequest {
+    #[prost(message, optional, tag = "1")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Only return the accounts last updated at or after this block.
+    #[prost(fixed32, optional, tag = "2")]
+    pub block_num_from: ::core::option::Option<u32>,
+    /// Only return the accounts last updated at or before this block.
+    #[prost(fixed32, optional, tag = "3")]
+    pub block_num_to: ::core::option::Option<u32>,
+    /// Only return on-chain accounts if set to true, or off-chain accounts if set to false.
+    #[prost(bool, optional, tag = "4")]
+    pub on_chain: ::core::option::Option<bool>,
+}
+/// Lists the notes, sorted by block number and index in the block.
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNotesRequest {
+    #[prost(message, optional, tag = "1")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Only return the notes created at or after this block.
+    #[prost(fixed32, optional, tag = "2")]
+    pub block_num_from: ::core::option::Option<u32>,
+    /// Only return the notes created at or before this block.
+    #[prost(fixed32, optional, tag = "3")]
+    pub block_num_to: ::core::option::Option<u32>,
+    /// Only return the notes created by this account.
+    #[prost(message, optional, tag = "4")]
+    pub sender: ::core::option::Option<super::account::AccountId>,
+    /// Only return the notes with this tag.
+    #[prost(fixed32, optional, tag = "5")]
+    pub tag: ::core::option::Option<u32>,
+    /// Only return the notes of this type.
+    #[prost(uint32, optional, tag = "6")]
+    pub note_type: ::core::option::Option<u32>,
+    /// Only return the public notes with this recipient digest.
+    #[prost(message, optional, tag = "7")]
+    pub recipient: ::core::option::Option<super::digest::Digest>,
+    /// Only return the public notes with this script root.
+    #[prost(message, optional, tag = "8")]
+    pub script_root: ::core::option::Option<super::digest::Digest>,
+    /// Only return the public notes holding a fungible asset issued by this faucet.
+    #[prost(message, optional, tag = "9")]
+    pub faucet_id: ::core::option::Option<super::account::AccountId>,
+}
+/// Returns the public notes with the given recipient digest, sorted by block number and index in
+/// the block.
 #[derive(Eq, PartialOrd, Ord, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ListAccountsRequest {}
+pub struct GetNotesByRecipientRequest {
+    #[prost(message, optional, tag = "1")]
+    pub recipient: ::core::option::Option<super::digest::Digest>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
+/// Returns the public notes holding a fungible asset issued by the given faucet, sorted by block
+/// number and index in the block.
 #[derive(Eq, PartialOrd, Ord, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ListNotesRequest {}
+pub struct GetNotesByFaucetRequest {
+    #[prost(message, optional, tag = "1")]
+    pub faucet_id: ::core::option::Option<super::account::AccountId>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<Pagination>,
+}
 /// Returns the latest state of an account with the specified ID.
 #[derive(Eq, PartialOrd, Ord, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -133,3 +340,95 @@ pub struct GetAccountDetailsRequest {
     #[prost(message, optional, tag = "1")]
     pub account_id: ::core::option::Option<super::account::AccountId>,
 }
+/// Returns the hashes of the specified accounts with their authentication paths.
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAccountProofsRequest {
+    /// Account IDs to get the proofs of.
+    #[prost(message, repeated, tag = "1")]
+    pub account_ids: ::prost::alloc::vec::Vec<super::account::AccountId>,
+    /// Whether to include the full state of the public accounts.
+    #[prost(bool, tag = "2")]
+    pub include_details: bool,
+    /// Block number whose account root the proofs are generated against. If unset, the proofs are
+    /// generated against the latest block. Only recent blocks can be requested.
+    #[prost(fixed32, optional, tag = "3")]
+    pub block_num: ::core::option::Option<u32>,
+}
+/// Returns the values of storage slots of a public account with their authentication paths in the
+/// account's storage.
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAccountStorageSlotsRequest {
+    /// ID of the public account.
+    #[prost(message, optional, tag = "1")]
+    pub account_id: ::core::option::Option<super::account::AccountId>,
+    /// Indices of the requested storage slots, between 0 and 255.
+    #[prost(uint32, repeated, tag = "2")]
+    pub slot_indices: ::prost::alloc::vec::Vec<u32>,
+}
+/// Returns the balances of fungible assets in the vault of a public account with their openings
+/// in the account's vault.
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAccountVaultBalanceRequest {
+    /// ID of the public account.
+    #[prost(message, optional, tag = "1")]
+    pub account_id: ::core::option::Option<super::account::AccountId>,
+    /// IDs of the fungible faucets issuing the requested assets.
+    #[prost(message, repeated, tag = "2")]
+    pub faucet_ids: ::prost::alloc::vec::Vec<super::account::AccountId>,
+}
+/// Returns the state of an account with the specified ID as of the specified block.
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAccountStateAtBlockRequest {
+    /// Account ID to get the state of.
+    #[prost(message, optional, tag = "1")]
+    pub account_id: ::core::option::Option<super::account::AccountId>,
+    /// Block number at which the account state is requested.
+    #[prost(fixed32, tag = "2")]
+    pub block_num: u32,
+}
+/// Returns the updates of an on-chain account in the block range `(from_block_num, to_block_num]`.
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAccountDeltaRequest {
+    /// ID of the on-chain account to get the updates of.
+    #[prost(message, optional, tag = "1")]
+    pub account_id: ::core::option::Option<super::account::AccountId>,
+    /// The updates are returned starting from the block after this one.
+    #[prost(fixed32, tag = "2")]
+    pub from_block_num: u32,
+    /// Last block number to include in the returned updates.
+    #[prost(fixed32, tag = "3")]
+    pub to_block_num: u32,
+}
+/// Returns the metadata of the accounts matching the filters, sorted by account ID.
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryAccountsRequest {
+    #[prost(message, optional, tag = "1")]
+    pub pagination: ::core::option::Option<Pagination>,
+    /// Only return the accounts of this type: 0 - wallet, 1 - fungible faucet, 2 - non-fungible faucet.
+    #[prost(uint32, optional, tag = "2")]
+    pub account_type: ::core::option::Option<u32>,
+    /// Only return on-chain accounts if set to true, or off-chain accounts if set to false.
+    #[prost(bool, optional, tag = "3")]
+    pub on_chain: ::core::option::Option<bool>,
+    /// Only return the accounts created at or after this block.
+    #[prost(fixed32, optional, tag = "4")]
+    pub created_block_num_from: ::core::option::Option<u32>,
+    /// Only return the accounts created at or before this block.
+    #[prost(fixed32, optional, tag = "5")]
+    pub created_block_num_to: ::core::option::Option<u32>,
+    /// Only return the on-chain accounts with this code root.
+    #[prost(message, optional, tag = "6")]
+    pub code_root: ::core::option::Option<super::digest::Digest>,
+}
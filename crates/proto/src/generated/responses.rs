@@ -18,6 +18,48 @@ pub struct GetBlockHeaderByNumberResponse {
     #[prost(message, optional, tag = "1")]
     pub block_header: ::core::option::Option<super::block_header::BlockHeader>,
 }
+/// A block header returned as a response to the GetBlockInclusionProofs
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockInclusionProof {
+    #[prost(message, optional, tag = "1")]
+    pub block_header: ::core::option::Option<super::block_header::BlockHeader>,
+    /// authentication path of the block header's hash in the chain MMR
+    #[prost(message, optional, tag = "2")]
+    pub mmr_path: ::core::option::Option<super::merkle::MerklePath>,
+}
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockInclusionProofsResponse {
+    /// header of the block whose `chain_root` the proofs verify against
+    #[prost(message, optional, tag = "1")]
+    pub block_header: ::core::option::Option<super::block_header::BlockHeader>,
+    /// the requested block headers with their authentication paths, in the order of the request
+    #[prost(message, repeated, tag = "2")]
+    pub proofs: ::prost::alloc::vec::Vec<BlockInclusionProof>,
+}
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockByNumberResponse {
+    /// The block data, encoded as the `ApplyBlockRequest` which added the block to the chain.
+    ///
+    /// Not present if the store doesn't have the requested block.
+    #[prost(bytes = "vec", optional, tag = "1")]
+    pub block: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockByHashResponse {
+    /// The block data, encoded as the `ApplyBlockRequest` which added the block to the chain.
+    ///
+    /// Not present if the store doesn't have the requested block.
+    #[prost(bytes = "vec", optional, tag = "1")]
+    pub block: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
 #[derive(Eq, PartialOrd, Ord, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -50,6 +92,83 @@ pub struct SyncStateResponse {
     #[prost(message, repeated, tag = "7")]
     pub nullifiers: ::prost::alloc::vec::Vec<NullifierUpdate>,
 }
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SyncNullifiersResponse {
+    /// last block number of the block range of the response, either the requested `to_block_num` or
+    /// the chain tip
+    #[prost(fixed32, tag = "1")]
+    pub to_block_num: u32,
+    /// a list of nullifiers created between `from_block_num + 1` and `to_block_num`, sorted by block
+    /// number
+    #[prost(message, repeated, tag = "2")]
+    pub nullifiers: ::prost::alloc::vec::Vec<NullifierUpdate>,
+    /// Token to request the next page, unset if this is the last page.
+    #[prost(bytes = "vec", optional, tag = "3")]
+    pub continuation_token: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
+/// Notes created in a single block, returned by a `SyncNotesRequest`.
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockNotes {
+    #[prost(message, optional, tag = "1")]
+    pub block_header: ::core::option::Option<super::block_header::BlockHeader>,
+    /// Merkle path of the block in the chain MMR after applying the response's `mmr_delta`, not set
+    /// for the last block of the response which is not part of that MMR
+    #[prost(message, optional, tag = "2")]
+    pub mmr_path: ::core::option::Option<super::merkle::MerklePath>,
+    /// a list of the matching notes together with the Merkle paths from `block_header.note_root`
+    #[prost(message, repeated, tag = "3")]
+    pub notes: ::prost::alloc::vec::Vec<super::note::NoteSyncRecord>,
+}
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SyncNotesResponse {
+    /// number of the latest block in the chain
+    #[prost(fixed32, tag = "1")]
+    pub chain_tip: u32,
+    /// block header of the last block covered by the response
+    #[prost(message, optional, tag = "2")]
+    pub block_header: ::core::option::Option<super::block_header::BlockHeader>,
+    /// data needed to update the partial MMR from `block_num + 1` to `block_header.block_num`
+    #[prost(message, optional, tag = "3")]
+    pub mmr_delta: ::core::option::Option<super::mmr::MmrDelta>,
+    /// the blocks between `block_num + 1` and `block_header.block_num` containing matching notes
+    #[prost(message, repeated, tag = "4")]
+    pub blocks: ::prost::alloc::vec::Vec<BlockNotes>,
+}
+/// Header of a block streamed to a `SubscribeBlocksRequest`.
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeBlocksResponse {
+    #[prost(message, optional, tag = "1")]
+    pub block_header: ::core::option::Option<super::block_header::BlockHeader>,
+    /// number of accounts updated in the block, set if the counts were requested
+    #[prost(uint32, optional, tag = "2")]
+    pub num_account_updates: ::core::option::Option<u32>,
+    /// number of nullifiers created in the block, set if the counts were requested
+    #[prost(uint32, optional, tag = "3")]
+    pub num_nullifiers: ::core::option::Option<u32>,
+    /// number of notes created in the block, set if the counts were requested
+    #[prost(uint32, optional, tag = "4")]
+    pub num_notes: ::core::option::Option<u32>,
+}
+/// Notes of a newly committed block matching a `SubscribeNotesRequest`.
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeNotesResponse {
+    /// block header of the block which created the notes
+    #[prost(message, optional, tag = "1")]
+    pub block_header: ::core::option::Option<super::block_header::BlockHeader>,
+    /// the matching notes together with the Merkle paths from `block_header.note_root`
+    #[prost(message, repeated, tag = "2")]
+    pub notes: ::prost::alloc::vec::Vec<super::note::NoteSyncRecord>,
+}
 /// An account returned as a response to the GetBlockInputs
 #[derive(Eq, PartialOrd, Ord, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -127,34 +246,115 @@ pub struct SubmitProvenTransactionResponse {}
 #[derive(Eq, PartialOrd, Ord, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionInclusion {
+    #[prost(message, optional, tag = "1")]
+    pub transaction_id: ::core::option::Option<super::digest::Digest>,
+    /// The block which included the transaction
+    #[prost(fixed32, tag = "2")]
+    pub block_num: u32,
+    /// Position of the transaction within the block's ordered list of transactions
+    #[prost(uint32, tag = "3")]
+    pub position: u32,
+}
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTransactionInclusionResponse {
+    /// Inclusion records for the requested transactions which were found in a block
+    #[prost(message, repeated, tag = "1")]
+    pub transactions: ::prost::alloc::vec::Vec<TransactionInclusion>,
+}
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetNotesByIdResponse {
     /// Lists Note's returned by the database
     #[prost(message, repeated, tag = "1")]
     pub notes: ::prost::alloc::vec::Vec<super::note::Note>,
 }
+/// Inclusion and consumption status of a note, returned as a response to the GetNoteStatus
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NoteStatus {
+    #[prost(message, optional, tag = "1")]
+    pub note_id: ::core::option::Option<super::digest::Digest>,
+    /// block number at which the note was created
+    #[prost(fixed32, tag = "2")]
+    pub block_num: u32,
+    /// index of the note in the note tree of the block
+    #[prost(uint32, tag = "3")]
+    pub note_index: u32,
+    /// authentication path of the note in the note tree of the block
+    #[prost(message, optional, tag = "4")]
+    pub merkle_path: ::core::option::Option<super::merkle::MerklePath>,
+    /// block number at which the note was consumed, unset if the note is not consumed yet or if
+    /// its nullifier is unknown to the store
+    #[prost(fixed32, optional, tag = "5")]
+    pub consumed_block_num: ::core::option::Option<u32>,
+}
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNoteStatusResponse {
+    /// the statuses of the requested notes, notes unknown to the store are omitted
+    #[prost(message, repeated, tag = "1")]
+    pub notes: ::prost::alloc::vec::Vec<NoteStatus>,
+}
 #[derive(Eq, PartialOrd, Ord, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListNullifiersResponse {
-    /// Lists all nullifiers of the current chain
+    /// Lists the nullifiers of the requested page
     #[prost(message, repeated, tag = "1")]
     pub nullifiers: ::prost::alloc::vec::Vec<super::smt::SmtLeafEntry>,
+    /// Token to request the next page, unset if this is the last page.
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub continuation_token: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
 }
 #[derive(Eq, PartialOrd, Ord, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListAccountsResponse {
-    /// Lists all accounts of the current chain
+    /// Lists the accounts of the requested page
     #[prost(message, repeated, tag = "1")]
     pub accounts: ::prost::alloc::vec::Vec<super::account::AccountInfo>,
+    /// Token to request the next page, unset if this is the last page.
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub continuation_token: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
 }
 #[derive(Eq, PartialOrd, Ord, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListNotesResponse {
-    /// Lists all notes of the current chain
+    /// Lists the notes of the requested page
+    #[prost(message, repeated, tag = "1")]
+    pub notes: ::prost::alloc::vec::Vec<super::note::Note>,
+    /// Token to request the next page, unset if this is the last page.
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub continuation_token: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNotesByRecipientResponse {
+    /// Lists the notes of the requested page
+    #[prost(message, repeated, tag = "1")]
+    pub notes: ::prost::alloc::vec::Vec<super::note::Note>,
+    /// Token to request the next page, unset if this is the last page.
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub continuation_token: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNotesByFaucetResponse {
+    /// Lists the notes of the requested page
     #[prost(message, repeated, tag = "1")]
     pub notes: ::prost::alloc::vec::Vec<super::note::Note>,
+    /// Token to request the next page, unset if this is the last page.
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub continuation_token: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
 }
 #[derive(Eq, PartialOrd, Ord, Hash)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -164,3 +364,140 @@ pub struct GetAccountDetailsResponse {
     #[prost(message, optional, tag = "1")]
     pub account: ::core::option::Option<super::account::AccountInfo>,
 }
+/// An account returned as a response to the GetAccountProofs
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountProof {
+    #[prost(message, optional, tag = "1")]
+    pub account_id: ::core::option::Option<super::account::AccountId>,
+    /// hash of the account, empty if the account is not part of the chain
+    #[prost(message, optional, tag = "2")]
+    pub account_hash: ::core::option::Option<super::digest::Digest>,
+    /// authentication path of the account hash in the account tree of the response's block header
+    #[prost(message, optional, tag = "3")]
+    pub account_proof: ::core::option::Option<super::merkle::MerklePath>,
+    /// full state of a public account as of the response's block, set if the details were requested
+    #[prost(bytes = "vec", optional, tag = "4")]
+    pub details: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAccountProofsResponse {
+    /// header of the requested block, whose `account_root` the proofs verify against
+    #[prost(message, optional, tag = "1")]
+    pub block_header: ::core::option::Option<super::block_header::BlockHeader>,
+    /// the proofs of the requested accounts, in the order of the request
+    #[prost(message, repeated, tag = "2")]
+    pub account_proofs: ::prost::alloc::vec::Vec<AccountProof>,
+}
+/// Latest state of a public account, as the commitments its hash is computed from
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountStateHeader {
+    /// ID, hash and block of the latest update of the account
+    #[prost(message, optional, tag = "1")]
+    pub summary: ::core::option::Option<super::account::AccountSummary>,
+    #[prost(fixed64, tag = "2")]
+    pub nonce: u64,
+    #[prost(message, optional, tag = "3")]
+    pub vault_root: ::core::option::Option<super::digest::Digest>,
+    #[prost(message, optional, tag = "4")]
+    pub storage_root: ::core::option::Option<super::digest::Digest>,
+    #[prost(message, optional, tag = "5")]
+    pub code_root: ::core::option::Option<super::digest::Digest>,
+}
+/// A storage slot returned as a response to the GetAccountStorageSlots
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StorageSlotProof {
+    #[prost(uint32, tag = "1")]
+    pub index: u32,
+    #[prost(message, optional, tag = "2")]
+    pub value: ::core::option::Option<super::digest::Digest>,
+    /// authentication path of the value in the storage of the account, verifies against its
+    /// `storage_root`
+    #[prost(message, optional, tag = "3")]
+    pub path: ::core::option::Option<super::merkle::MerklePath>,
+}
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAccountStorageSlotsResponse {
+    /// state of the account the slots are read from
+    #[prost(message, optional, tag = "1")]
+    pub account: ::core::option::Option<AccountStateHeader>,
+    /// the requested storage slots, in the order of the request
+    #[prost(message, repeated, tag = "2")]
+    pub slots: ::prost::alloc::vec::Vec<StorageSlotProof>,
+}
+/// An asset balance returned as a response to the GetAccountVaultBalance
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VaultBalanceProof {
+    #[prost(message, optional, tag = "1")]
+    pub faucet_id: ::core::option::Option<super::account::AccountId>,
+    #[prost(fixed64, tag = "2")]
+    pub balance: u64,
+    /// opening of the asset in the vault of the account, verifies against its `vault_root`
+    #[prost(message, optional, tag = "3")]
+    pub opening: ::core::option::Option<super::smt::SmtOpening>,
+}
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAccountVaultBalanceResponse {
+    /// state of the account the balances are read from
+    #[prost(message, optional, tag = "1")]
+    pub account: ::core::option::Option<AccountStateHeader>,
+    /// the balances of the requested assets, in the order of the request
+    #[prost(message, repeated, tag = "2")]
+    pub balances: ::prost::alloc::vec::Vec<VaultBalanceProof>,
+}
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAccountStateAtBlockResponse {
+    /// Account info as of the requested block (with details for on-chain accounts). The block
+    /// number in the summary is the one of the last update at or before the requested block.
+    #[prost(message, optional, tag = "1")]
+    pub account: ::core::option::Option<super::account::AccountInfo>,
+}
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountHistoryRecord {
+    /// Block in which the account was updated.
+    #[prost(fixed32, tag = "1")]
+    pub block_num: u32,
+    /// Hash of the account state after the block.
+    #[prost(message, optional, tag = "2")]
+    pub account_hash: ::core::option::Option<super::digest::Digest>,
+    /// Account update applied in the block, encoded as `AccountDetails` using miden's native format.
+    /// This is either the full account state (e.g. when the account was created) or a delta.
+    #[prost(bytes = "vec", tag = "3")]
+    pub details: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAccountDeltaResponse {
+    /// Updates of the account in the requested block range, ordered by block number.
+    #[prost(message, repeated, tag = "1")]
+    pub updates: ::prost::alloc::vec::Vec<AccountHistoryRecord>,
+}
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryAccountsResponse {
+    /// Lists the metadata of the accounts of the requested page
+    #[prost(message, repeated, tag = "1")]
+    pub accounts: ::prost::alloc::vec::Vec<super::account::AccountMetadata>,
+    /// Token to request the next page, unset if this is the last page.
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub continuation_token: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
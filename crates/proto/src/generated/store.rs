@@ -132,6 +132,57 @@ pub mod api_client {
             req.extensions_mut().insert(GrpcMethod::new("store.Api", "CheckNullifiers"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn get_block_by_hash(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::requests::GetBlockByHashRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetBlockByHashResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/store.Api/GetBlockByHash");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("store.Api", "GetBlockByHash"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_block_by_number(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::requests::GetBlockByNumberRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetBlockByNumberResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/store.Api/GetBlockByNumber",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("store.Api", "GetBlockByNumber"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn get_block_header_by_number(
             &mut self,
             request: impl tonic::IntoRequest<
@@ -159,6 +210,33 @@ pub mod api_client {
                 .insert(GrpcMethod::new("store.Api", "GetBlockHeaderByNumber"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn get_block_inclusion_proofs(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::requests::GetBlockInclusionProofsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetBlockInclusionProofsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/store.Api/GetBlockInclusionProofs",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("store.Api", "GetBlockInclusionProofs"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn get_block_inputs(
             &mut self,
             request: impl tonic::IntoRequest<
@@ -183,6 +261,33 @@ pub mod api_client {
             req.extensions_mut().insert(GrpcMethod::new("store.Api", "GetBlockInputs"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn get_notes_by_faucet(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::requests::GetNotesByFaucetRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetNotesByFaucetResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/store.Api/GetNotesByFaucet",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("store.Api", "GetNotesByFaucet"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn get_notes_by_id(
             &mut self,
             request: impl tonic::IntoRequest<super::super::requests::GetNotesByIdRequest>,
@@ -205,6 +310,84 @@ pub mod api_client {
             req.extensions_mut().insert(GrpcMethod::new("store.Api", "GetNotesById"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn get_notes_by_recipient(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::requests::GetNotesByRecipientRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetNotesByRecipientResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/store.Api/GetNotesByRecipient",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("store.Api", "GetNotesByRecipient"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_note_status(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::requests::GetNoteStatusRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetNoteStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/store.Api/GetNoteStatus");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("store.Api", "GetNoteStatus"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_transaction_inclusion(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::requests::GetTransactionInclusionRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetTransactionInclusionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/store.Api/GetTransactionInclusion",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("store.Api", "GetTransactionInclusion"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn get_transaction_inputs(
             &mut self,
             request: impl tonic::IntoRequest<
@@ -254,6 +437,106 @@ pub mod api_client {
             req.extensions_mut().insert(GrpcMethod::new("store.Api", "SyncState"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn sync_notes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::requests::SyncNotesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::SyncNotesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/store.Api/SyncNotes");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("store.Api", "SyncNotes"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn sync_nullifiers(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::requests::SyncNullifiersRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::SyncNullifiersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/store.Api/SyncNullifiers");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("store.Api", "SyncNullifiers"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn subscribe_blocks(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::requests::SubscribeBlocksRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::super::responses::SubscribeBlocksResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/store.Api/SubscribeBlocks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("store.Api", "SubscribeBlocks"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn subscribe_notes(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::requests::SubscribeNotesRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::super::responses::SubscribeNotesResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/store.Api/SubscribeNotes");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("store.Api", "SubscribeNotes"));
+            self.inner.server_streaming(req, path, codec).await
+        }
         pub async fn list_nullifiers(
             &mut self,
             request: impl tonic::IntoRequest<
@@ -322,13 +605,15 @@ pub mod api_client {
             req.extensions_mut().insert(GrpcMethod::new("store.Api", "ListNotes"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn get_account_details(
+        pub async fn stream_nullifiers(
             &mut self,
             request: impl tonic::IntoRequest<
-                super::super::requests::GetAccountDetailsRequest,
+                super::super::requests::ListNullifiersRequest,
             >,
         ) -> std::result::Result<
-            tonic::Response<super::super::responses::GetAccountDetailsResponse>,
+            tonic::Response<
+                tonic::codec::Streaming<super::super::responses::ListNullifiersResponse>,
+            >,
             tonic::Status,
         > {
             self.inner
@@ -342,36 +627,283 @@ pub mod api_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/store.Api/GetAccountDetails",
+                "/store.Api/StreamNullifiers",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("store.Api", "GetAccountDetails"));
-            self.inner.unary(req, path, codec).await
+                .insert(GrpcMethod::new("store.Api", "StreamNullifiers"));
+            self.inner.server_streaming(req, path, codec).await
         }
-    }
-}
-/// Generated server implementations.
-pub mod api_server {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with ApiServer.
-    #[async_trait]
-    pub trait Api: Send + Sync + 'static {
-        async fn apply_block(
-            &self,
-            request: tonic::Request<super::super::requests::ApplyBlockRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::super::responses::ApplyBlockResponse>,
-            tonic::Status,
-        >;
-        async fn check_nullifiers(
-            &self,
-            request: tonic::Request<super::super::requests::CheckNullifiersRequest>,
+        pub async fn stream_accounts(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::requests::ListAccountsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::super::responses::CheckNullifiersResponse>,
+            tonic::Response<
+                tonic::codec::Streaming<super::super::responses::ListAccountsResponse>,
+            >,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/store.Api/StreamAccounts");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("store.Api", "StreamAccounts"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn stream_notes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::requests::ListNotesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::super::responses::ListNotesResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/store.Api/StreamNotes");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("store.Api", "StreamNotes"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn get_account_details(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::requests::GetAccountDetailsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetAccountDetailsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/store.Api/GetAccountDetails",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("store.Api", "GetAccountDetails"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_account_proofs(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::requests::GetAccountProofsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetAccountProofsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/store.Api/GetAccountProofs",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("store.Api", "GetAccountProofs"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_account_storage_slots(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::requests::GetAccountStorageSlotsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetAccountStorageSlotsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/store.Api/GetAccountStorageSlots",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("store.Api", "GetAccountStorageSlots"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_account_vault_balance(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::requests::GetAccountVaultBalanceRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetAccountVaultBalanceResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/store.Api/GetAccountVaultBalance",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("store.Api", "GetAccountVaultBalance"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_account_state_at_block(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::requests::GetAccountStateAtBlockRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetAccountStateAtBlockResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/store.Api/GetAccountStateAtBlock",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("store.Api", "GetAccountStateAtBlock"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_account_delta(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::requests::GetAccountDeltaRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetAccountDeltaResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/store.Api/GetAccountDelta",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("store.Api", "GetAccountDelta"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn query_accounts(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::requests::QueryAccountsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::QueryAccountsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/store.Api/QueryAccounts");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("store.Api", "QueryAccounts"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod api_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ApiServer.
+    #[async_trait]
+    pub trait Api: Send + Sync + 'static {
+        async fn apply_block(
+            &self,
+            request: tonic::Request<super::super::requests::ApplyBlockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::ApplyBlockResponse>,
+            tonic::Status,
+        >;
+        async fn check_nullifiers(
+            &self,
+            request: tonic::Request<super::super::requests::CheckNullifiersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::CheckNullifiersResponse>,
+            tonic::Status,
+        >;
+        async fn get_block_by_hash(
+            &self,
+            request: tonic::Request<super::super::requests::GetBlockByHashRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetBlockByHashResponse>,
+            tonic::Status,
+        >;
+        async fn get_block_by_number(
+            &self,
+            request: tonic::Request<super::super::requests::GetBlockByNumberRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetBlockByNumberResponse>,
+            tonic::Status,
+        >;
         async fn get_block_header_by_number(
             &self,
             request: tonic::Request<
@@ -381,6 +913,15 @@ pub mod api_server {
             tonic::Response<super::super::responses::GetBlockHeaderByNumberResponse>,
             tonic::Status,
         >;
+        async fn get_block_inclusion_proofs(
+            &self,
+            request: tonic::Request<
+                super::super::requests::GetBlockInclusionProofsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetBlockInclusionProofsResponse>,
+            tonic::Status,
+        >;
         async fn get_block_inputs(
             &self,
             request: tonic::Request<super::super::requests::GetBlockInputsRequest>,
@@ -388,6 +929,13 @@ pub mod api_server {
             tonic::Response<super::super::responses::GetBlockInputsResponse>,
             tonic::Status,
         >;
+        async fn get_notes_by_faucet(
+            &self,
+            request: tonic::Request<super::super::requests::GetNotesByFaucetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetNotesByFaucetResponse>,
+            tonic::Status,
+        >;
         async fn get_notes_by_id(
             &self,
             request: tonic::Request<super::super::requests::GetNotesByIdRequest>,
@@ -395,6 +943,29 @@ pub mod api_server {
             tonic::Response<super::super::responses::GetNotesByIdResponse>,
             tonic::Status,
         >;
+        async fn get_notes_by_recipient(
+            &self,
+            request: tonic::Request<super::super::requests::GetNotesByRecipientRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetNotesByRecipientResponse>,
+            tonic::Status,
+        >;
+        async fn get_note_status(
+            &self,
+            request: tonic::Request<super::super::requests::GetNoteStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetNoteStatusResponse>,
+            tonic::Status,
+        >;
+        async fn get_transaction_inclusion(
+            &self,
+            request: tonic::Request<
+                super::super::requests::GetTransactionInclusionRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetTransactionInclusionResponse>,
+            tonic::Status,
+        >;
         async fn get_transaction_inputs(
             &self,
             request: tonic::Request<super::super::requests::GetTransactionInputsRequest>,
@@ -409,35 +980,177 @@ pub mod api_server {
             tonic::Response<super::super::responses::SyncStateResponse>,
             tonic::Status,
         >;
-        async fn list_nullifiers(
+        async fn sync_notes(
             &self,
-            request: tonic::Request<super::super::requests::ListNullifiersRequest>,
+            request: tonic::Request<super::super::requests::SyncNotesRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::super::responses::ListNullifiersResponse>,
+            tonic::Response<super::super::responses::SyncNotesResponse>,
             tonic::Status,
         >;
-        async fn list_accounts(
+        async fn sync_nullifiers(
             &self,
-            request: tonic::Request<super::super::requests::ListAccountsRequest>,
+            request: tonic::Request<super::super::requests::SyncNullifiersRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::super::responses::ListAccountsResponse>,
+            tonic::Response<super::super::responses::SyncNullifiersResponse>,
             tonic::Status,
         >;
-        async fn list_notes(
+        /// Server streaming response type for the SubscribeBlocks method.
+        type SubscribeBlocksStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::super::responses::SubscribeBlocksResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        async fn subscribe_blocks(
             &self,
-            request: tonic::Request<super::super::requests::ListNotesRequest>,
+            request: tonic::Request<super::super::requests::SubscribeBlocksRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::super::responses::ListNotesResponse>,
+            tonic::Response<Self::SubscribeBlocksStream>,
             tonic::Status,
         >;
-        async fn get_account_details(
+        /// Server streaming response type for the SubscribeNotes method.
+        type SubscribeNotesStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::super::responses::SubscribeNotesResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        async fn subscribe_notes(
             &self,
-            request: tonic::Request<super::super::requests::GetAccountDetailsRequest>,
+            request: tonic::Request<super::super::requests::SubscribeNotesRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::super::responses::GetAccountDetailsResponse>,
+            tonic::Response<Self::SubscribeNotesStream>,
             tonic::Status,
         >;
-    }
+        async fn list_nullifiers(
+            &self,
+            request: tonic::Request<super::super::requests::ListNullifiersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::ListNullifiersResponse>,
+            tonic::Status,
+        >;
+        async fn list_accounts(
+            &self,
+            request: tonic::Request<super::super::requests::ListAccountsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::ListAccountsResponse>,
+            tonic::Status,
+        >;
+        async fn list_notes(
+            &self,
+            request: tonic::Request<super::super::requests::ListNotesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::ListNotesResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the StreamNullifiers method.
+        type StreamNullifiersStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::super::responses::ListNullifiersResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        async fn stream_nullifiers(
+            &self,
+            request: tonic::Request<super::super::requests::ListNullifiersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::StreamNullifiersStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the StreamAccounts method.
+        type StreamAccountsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::super::responses::ListAccountsResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        async fn stream_accounts(
+            &self,
+            request: tonic::Request<super::super::requests::ListAccountsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::StreamAccountsStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the StreamNotes method.
+        type StreamNotesStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::super::responses::ListNotesResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        async fn stream_notes(
+            &self,
+            request: tonic::Request<super::super::requests::ListNotesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::StreamNotesStream>,
+            tonic::Status,
+        >;
+        async fn get_account_details(
+            &self,
+            request: tonic::Request<super::super::requests::GetAccountDetailsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetAccountDetailsResponse>,
+            tonic::Status,
+        >;
+        async fn get_account_proofs(
+            &self,
+            request: tonic::Request<super::super::requests::GetAccountProofsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetAccountProofsResponse>,
+            tonic::Status,
+        >;
+        async fn get_account_storage_slots(
+            &self,
+            request: tonic::Request<
+                super::super::requests::GetAccountStorageSlotsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetAccountStorageSlotsResponse>,
+            tonic::Status,
+        >;
+        async fn get_account_vault_balance(
+            &self,
+            request: tonic::Request<
+                super::super::requests::GetAccountVaultBalanceRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetAccountVaultBalanceResponse>,
+            tonic::Status,
+        >;
+        async fn get_account_state_at_block(
+            &self,
+            request: tonic::Request<
+                super::super::requests::GetAccountStateAtBlockRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetAccountStateAtBlockResponse>,
+            tonic::Status,
+        >;
+        async fn get_account_delta(
+            &self,
+            request: tonic::Request<super::super::requests::GetAccountDeltaRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::GetAccountDeltaResponse>,
+            tonic::Status,
+        >;
+        async fn query_accounts(
+            &self,
+            request: tonic::Request<super::super::requests::QueryAccountsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::responses::QueryAccountsResponse>,
+            tonic::Status,
+        >;
+    }
     #[derive(Debug)]
     pub struct ApiServer<T: Api> {
         inner: _Inner<T>,
@@ -519,26 +1232,863 @@ pub mod api_server {
             match req.uri().path() {
                 "/store.Api/ApplyBlock" => {
                     #[allow(non_camel_case_types)]
-                    struct ApplyBlockSvc<T: Api>(pub Arc<T>);
+                    struct ApplyBlockSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::UnaryService<
+                        super::super::requests::ApplyBlockRequest,
+                    > for ApplyBlockSvc<T> {
+                        type Response = super::super::responses::ApplyBlockResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::ApplyBlockRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::apply_block(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ApplyBlockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/CheckNullifiers" => {
+                    #[allow(non_camel_case_types)]
+                    struct CheckNullifiersSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::UnaryService<
+                        super::super::requests::CheckNullifiersRequest,
+                    > for CheckNullifiersSvc<T> {
+                        type Response = super::super::responses::CheckNullifiersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::CheckNullifiersRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::check_nullifiers(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CheckNullifiersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/GetBlockByHash" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockByHashSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::UnaryService<
+                        super::super::requests::GetBlockByHashRequest,
+                    > for GetBlockByHashSvc<T> {
+                        type Response = super::super::responses::GetBlockByHashResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::GetBlockByHashRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::get_block_by_hash(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetBlockByHashSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/GetBlockByNumber" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockByNumberSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::UnaryService<
+                        super::super::requests::GetBlockByNumberRequest,
+                    > for GetBlockByNumberSvc<T> {
+                        type Response = super::super::responses::GetBlockByNumberResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::GetBlockByNumberRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::get_block_by_number(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetBlockByNumberSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/GetBlockHeaderByNumber" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockHeaderByNumberSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::UnaryService<
+                        super::super::requests::GetBlockHeaderByNumberRequest,
+                    > for GetBlockHeaderByNumberSvc<T> {
+                        type Response = super::super::responses::GetBlockHeaderByNumberResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::GetBlockHeaderByNumberRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::get_block_header_by_number(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetBlockHeaderByNumberSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/GetBlockInclusionProofs" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockInclusionProofsSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::UnaryService<
+                        super::super::requests::GetBlockInclusionProofsRequest,
+                    > for GetBlockInclusionProofsSvc<T> {
+                        type Response = super::super::responses::GetBlockInclusionProofsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::GetBlockInclusionProofsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::get_block_inclusion_proofs(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetBlockInclusionProofsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/GetBlockInputs" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockInputsSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::UnaryService<
+                        super::super::requests::GetBlockInputsRequest,
+                    > for GetBlockInputsSvc<T> {
+                        type Response = super::super::responses::GetBlockInputsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::GetBlockInputsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::get_block_inputs(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetBlockInputsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/GetNotesByFaucet" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetNotesByFaucetSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::UnaryService<
+                        super::super::requests::GetNotesByFaucetRequest,
+                    > for GetNotesByFaucetSvc<T> {
+                        type Response = super::super::responses::GetNotesByFaucetResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::GetNotesByFaucetRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::get_notes_by_faucet(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetNotesByFaucetSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/GetNotesById" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetNotesByIdSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::UnaryService<
+                        super::super::requests::GetNotesByIdRequest,
+                    > for GetNotesByIdSvc<T> {
+                        type Response = super::super::responses::GetNotesByIdResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::GetNotesByIdRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::get_notes_by_id(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetNotesByIdSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/GetNotesByRecipient" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetNotesByRecipientSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::UnaryService<
+                        super::super::requests::GetNotesByRecipientRequest,
+                    > for GetNotesByRecipientSvc<T> {
+                        type Response = super::super::responses::GetNotesByRecipientResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::GetNotesByRecipientRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::get_notes_by_recipient(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetNotesByRecipientSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/GetNoteStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetNoteStatusSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::UnaryService<
+                        super::super::requests::GetNoteStatusRequest,
+                    > for GetNoteStatusSvc<T> {
+                        type Response = super::super::responses::GetNoteStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::GetNoteStatusRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::get_note_status(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetNoteStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/GetTransactionInclusion" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTransactionInclusionSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::UnaryService<
+                        super::super::requests::GetTransactionInclusionRequest,
+                    > for GetTransactionInclusionSvc<T> {
+                        type Response = super::super::responses::GetTransactionInclusionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::GetTransactionInclusionRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::get_transaction_inclusion(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetTransactionInclusionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/GetTransactionInputs" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTransactionInputsSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::UnaryService<
+                        super::super::requests::GetTransactionInputsRequest,
+                    > for GetTransactionInputsSvc<T> {
+                        type Response = super::super::responses::GetTransactionInputsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::GetTransactionInputsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::get_transaction_inputs(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetTransactionInputsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/SyncState" => {
+                    #[allow(non_camel_case_types)]
+                    struct SyncStateSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::UnaryService<
+                        super::super::requests::SyncStateRequest,
+                    > for SyncStateSvc<T> {
+                        type Response = super::super::responses::SyncStateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::SyncStateRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::sync_state(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SyncStateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/SyncNotes" => {
+                    #[allow(non_camel_case_types)]
+                    struct SyncNotesSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::UnaryService<
+                        super::super::requests::SyncNotesRequest,
+                    > for SyncNotesSvc<T> {
+                        type Response = super::super::responses::SyncNotesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::SyncNotesRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::sync_notes(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SyncNotesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/SyncNullifiers" => {
+                    #[allow(non_camel_case_types)]
+                    struct SyncNullifiersSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::UnaryService<
+                        super::super::requests::SyncNullifiersRequest,
+                    > for SyncNullifiersSvc<T> {
+                        type Response = super::super::responses::SyncNullifiersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::SyncNullifiersRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::sync_nullifiers(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SyncNullifiersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/SubscribeBlocks" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubscribeBlocksSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::ServerStreamingService<
+                        super::super::requests::SubscribeBlocksRequest,
+                    > for SubscribeBlocksSvc<T> {
+                        type Response = super::super::responses::SubscribeBlocksResponse;
+                        type ResponseStream = T::SubscribeBlocksStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::SubscribeBlocksRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::subscribe_blocks(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SubscribeBlocksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/SubscribeNotes" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubscribeNotesSvc<T: Api>(pub Arc<T>);
                     impl<
                         T: Api,
-                    > tonic::server::UnaryService<
-                        super::super::requests::ApplyBlockRequest,
-                    > for ApplyBlockSvc<T> {
-                        type Response = super::super::responses::ApplyBlockResponse;
+                    > tonic::server::ServerStreamingService<
+                        super::super::requests::SubscribeNotesRequest,
+                    > for SubscribeNotesSvc<T> {
+                        type Response = super::super::responses::SubscribeNotesResponse;
+                        type ResponseStream = T::SubscribeNotesStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::requests::ApplyBlockRequest,
+                                super::super::requests::SubscribeNotesRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Api>::apply_block(&inner, request).await
+                                <T as Api>::subscribe_notes(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -550,7 +2100,7 @@ pub mod api_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = ApplyBlockSvc(inner);
+                        let method = SubscribeNotesSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -561,20 +2111,20 @@ pub mod api_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/store.Api/CheckNullifiers" => {
+                "/store.Api/ListNullifiers" => {
                     #[allow(non_camel_case_types)]
-                    struct CheckNullifiersSvc<T: Api>(pub Arc<T>);
+                    struct ListNullifiersSvc<T: Api>(pub Arc<T>);
                     impl<
                         T: Api,
                     > tonic::server::UnaryService<
-                        super::super::requests::CheckNullifiersRequest,
-                    > for CheckNullifiersSvc<T> {
-                        type Response = super::super::responses::CheckNullifiersResponse;
+                        super::super::requests::ListNullifiersRequest,
+                    > for ListNullifiersSvc<T> {
+                        type Response = super::super::responses::ListNullifiersResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -582,12 +2132,12 @@ pub mod api_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::requests::CheckNullifiersRequest,
+                                super::super::requests::ListNullifiersRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Api>::check_nullifiers(&inner, request).await
+                                <T as Api>::list_nullifiers(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -599,7 +2149,7 @@ pub mod api_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = CheckNullifiersSvc(inner);
+                        let method = ListNullifiersSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -615,15 +2165,15 @@ pub mod api_server {
                     };
                     Box::pin(fut)
                 }
-                "/store.Api/GetBlockHeaderByNumber" => {
+                "/store.Api/ListAccounts" => {
                     #[allow(non_camel_case_types)]
-                    struct GetBlockHeaderByNumberSvc<T: Api>(pub Arc<T>);
+                    struct ListAccountsSvc<T: Api>(pub Arc<T>);
                     impl<
                         T: Api,
                     > tonic::server::UnaryService<
-                        super::super::requests::GetBlockHeaderByNumberRequest,
-                    > for GetBlockHeaderByNumberSvc<T> {
-                        type Response = super::super::responses::GetBlockHeaderByNumberResponse;
+                        super::super::requests::ListAccountsRequest,
+                    > for ListAccountsSvc<T> {
+                        type Response = super::super::responses::ListAccountsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -631,13 +2181,12 @@ pub mod api_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::requests::GetBlockHeaderByNumberRequest,
+                                super::super::requests::ListAccountsRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Api>::get_block_header_by_number(&inner, request)
-                                    .await
+                                <T as Api>::list_accounts(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -649,7 +2198,7 @@ pub mod api_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetBlockHeaderByNumberSvc(inner);
+                        let method = ListAccountsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -665,15 +2214,15 @@ pub mod api_server {
                     };
                     Box::pin(fut)
                 }
-                "/store.Api/GetBlockInputs" => {
+                "/store.Api/ListNotes" => {
                     #[allow(non_camel_case_types)]
-                    struct GetBlockInputsSvc<T: Api>(pub Arc<T>);
+                    struct ListNotesSvc<T: Api>(pub Arc<T>);
                     impl<
                         T: Api,
                     > tonic::server::UnaryService<
-                        super::super::requests::GetBlockInputsRequest,
-                    > for GetBlockInputsSvc<T> {
-                        type Response = super::super::responses::GetBlockInputsResponse;
+                        super::super::requests::ListNotesRequest,
+                    > for ListNotesSvc<T> {
+                        type Response = super::super::responses::ListNotesResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -681,12 +2230,12 @@ pub mod api_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::requests::GetBlockInputsRequest,
+                                super::super::requests::ListNotesRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Api>::get_block_inputs(&inner, request).await
+                                <T as Api>::list_notes(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -698,7 +2247,7 @@ pub mod api_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetBlockInputsSvc(inner);
+                        let method = ListNotesSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -714,15 +2263,165 @@ pub mod api_server {
                     };
                     Box::pin(fut)
                 }
-                "/store.Api/GetNotesById" => {
+                "/store.Api/StreamNullifiers" => {
                     #[allow(non_camel_case_types)]
-                    struct GetNotesByIdSvc<T: Api>(pub Arc<T>);
+                    struct StreamNullifiersSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::ServerStreamingService<
+                        super::super::requests::ListNullifiersRequest,
+                    > for StreamNullifiersSvc<T> {
+                        type Response = super::super::responses::ListNullifiersResponse;
+                        type ResponseStream = T::StreamNullifiersStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::ListNullifiersRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::stream_nullifiers(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = StreamNullifiersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/StreamAccounts" => {
+                    #[allow(non_camel_case_types)]
+                    struct StreamAccountsSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::ServerStreamingService<
+                        super::super::requests::ListAccountsRequest,
+                    > for StreamAccountsSvc<T> {
+                        type Response = super::super::responses::ListAccountsResponse;
+                        type ResponseStream = T::StreamAccountsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::ListAccountsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::stream_accounts(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = StreamAccountsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/StreamNotes" => {
+                    #[allow(non_camel_case_types)]
+                    struct StreamNotesSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::ServerStreamingService<
+                        super::super::requests::ListNotesRequest,
+                    > for StreamNotesSvc<T> {
+                        type Response = super::super::responses::ListNotesResponse;
+                        type ResponseStream = T::StreamNotesStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::requests::ListNotesRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::stream_notes(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = StreamNotesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/store.Api/GetAccountDetails" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetAccountDetailsSvc<T: Api>(pub Arc<T>);
                     impl<
                         T: Api,
                     > tonic::server::UnaryService<
-                        super::super::requests::GetNotesByIdRequest,
-                    > for GetNotesByIdSvc<T> {
-                        type Response = super::super::responses::GetNotesByIdResponse;
+                        super::super::requests::GetAccountDetailsRequest,
+                    > for GetAccountDetailsSvc<T> {
+                        type Response = super::super::responses::GetAccountDetailsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -730,12 +2429,12 @@ pub mod api_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::requests::GetNotesByIdRequest,
+                                super::super::requests::GetAccountDetailsRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Api>::get_notes_by_id(&inner, request).await
+                                <T as Api>::get_account_details(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -747,7 +2446,7 @@ pub mod api_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetNotesByIdSvc(inner);
+                        let method = GetAccountDetailsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -763,15 +2462,15 @@ pub mod api_server {
                     };
                     Box::pin(fut)
                 }
-                "/store.Api/GetTransactionInputs" => {
+                "/store.Api/GetAccountProofs" => {
                     #[allow(non_camel_case_types)]
-                    struct GetTransactionInputsSvc<T: Api>(pub Arc<T>);
+                    struct GetAccountProofsSvc<T: Api>(pub Arc<T>);
                     impl<
                         T: Api,
                     > tonic::server::UnaryService<
-                        super::super::requests::GetTransactionInputsRequest,
-                    > for GetTransactionInputsSvc<T> {
-                        type Response = super::super::responses::GetTransactionInputsResponse;
+                        super::super::requests::GetAccountProofsRequest,
+                    > for GetAccountProofsSvc<T> {
+                        type Response = super::super::responses::GetAccountProofsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -779,12 +2478,12 @@ pub mod api_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::requests::GetTransactionInputsRequest,
+                                super::super::requests::GetAccountProofsRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Api>::get_transaction_inputs(&inner, request).await
+                                <T as Api>::get_account_proofs(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -796,7 +2495,7 @@ pub mod api_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetTransactionInputsSvc(inner);
+                        let method = GetAccountProofsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -812,15 +2511,15 @@ pub mod api_server {
                     };
                     Box::pin(fut)
                 }
-                "/store.Api/SyncState" => {
+                "/store.Api/GetAccountStorageSlots" => {
                     #[allow(non_camel_case_types)]
-                    struct SyncStateSvc<T: Api>(pub Arc<T>);
+                    struct GetAccountStorageSlotsSvc<T: Api>(pub Arc<T>);
                     impl<
                         T: Api,
                     > tonic::server::UnaryService<
-                        super::super::requests::SyncStateRequest,
-                    > for SyncStateSvc<T> {
-                        type Response = super::super::responses::SyncStateResponse;
+                        super::super::requests::GetAccountStorageSlotsRequest,
+                    > for GetAccountStorageSlotsSvc<T> {
+                        type Response = super::super::responses::GetAccountStorageSlotsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -828,12 +2527,12 @@ pub mod api_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::requests::SyncStateRequest,
+                                super::super::requests::GetAccountStorageSlotsRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Api>::sync_state(&inner, request).await
+                                <T as Api>::get_account_storage_slots(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -845,7 +2544,7 @@ pub mod api_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = SyncStateSvc(inner);
+                        let method = GetAccountStorageSlotsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -861,15 +2560,15 @@ pub mod api_server {
                     };
                     Box::pin(fut)
                 }
-                "/store.Api/ListNullifiers" => {
+                "/store.Api/GetAccountVaultBalance" => {
                     #[allow(non_camel_case_types)]
-                    struct ListNullifiersSvc<T: Api>(pub Arc<T>);
+                    struct GetAccountVaultBalanceSvc<T: Api>(pub Arc<T>);
                     impl<
                         T: Api,
                     > tonic::server::UnaryService<
-                        super::super::requests::ListNullifiersRequest,
-                    > for ListNullifiersSvc<T> {
-                        type Response = super::super::responses::ListNullifiersResponse;
+                        super::super::requests::GetAccountVaultBalanceRequest,
+                    > for GetAccountVaultBalanceSvc<T> {
+                        type Response = super::super::responses::GetAccountVaultBalanceResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -877,12 +2576,12 @@ pub mod api_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::requests::ListNullifiersRequest,
+                                super::super::requests::GetAccountVaultBalanceRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Api>::list_nullifiers(&inner, request).await
+                                <T as Api>::get_account_vault_balance(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -894,7 +2593,7 @@ pub mod api_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = ListNullifiersSvc(inner);
+                        let method = GetAccountVaultBalanceSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -910,15 +2609,15 @@ pub mod api_server {
                     };
                     Box::pin(fut)
                 }
-                "/store.Api/ListAccounts" => {
+                "/store.Api/GetAccountStateAtBlock" => {
                     #[allow(non_camel_case_types)]
-                    struct ListAccountsSvc<T: Api>(pub Arc<T>);
+                    struct GetAccountStateAtBlockSvc<T: Api>(pub Arc<T>);
                     impl<
                         T: Api,
                     > tonic::server::UnaryService<
-                        super::super::requests::ListAccountsRequest,
-                    > for ListAccountsSvc<T> {
-                        type Response = super::super::responses::ListAccountsResponse;
+                        super::super::requests::GetAccountStateAtBlockRequest,
+                    > for GetAccountStateAtBlockSvc<T> {
+                        type Response = super::super::responses::GetAccountStateAtBlockResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -926,12 +2625,13 @@ pub mod api_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::requests::ListAccountsRequest,
+                                super::super::requests::GetAccountStateAtBlockRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Api>::list_accounts(&inner, request).await
+                                <T as Api>::get_account_state_at_block(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -943,7 +2643,7 @@ pub mod api_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = ListAccountsSvc(inner);
+                        let method = GetAccountStateAtBlockSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -959,15 +2659,15 @@ pub mod api_server {
                     };
                     Box::pin(fut)
                 }
-                "/store.Api/ListNotes" => {
+                "/store.Api/GetAccountDelta" => {
                     #[allow(non_camel_case_types)]
-                    struct ListNotesSvc<T: Api>(pub Arc<T>);
+                    struct GetAccountDeltaSvc<T: Api>(pub Arc<T>);
                     impl<
                         T: Api,
                     > tonic::server::UnaryService<
-                        super::super::requests::ListNotesRequest,
-                    > for ListNotesSvc<T> {
-                        type Response = super::super::responses::ListNotesResponse;
+                        super::super::requests::GetAccountDeltaRequest,
+                    > for GetAccountDeltaSvc<T> {
+                        type Response = super::super::responses::GetAccountDeltaResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -975,12 +2675,12 @@ pub mod api_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::requests::ListNotesRequest,
+                                super::super::requests::GetAccountDeltaRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Api>::list_notes(&inner, request).await
+                                <T as Api>::get_account_delta(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -992,7 +2692,7 @@ pub mod api_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = ListNotesSvc(inner);
+                        let method = GetAccountDeltaSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1008,15 +2708,15 @@ pub mod api_server {
                     };
                     Box::pin(fut)
                 }
-                "/store.Api/GetAccountDetails" => {
+                "/store.Api/QueryAccounts" => {
                     #[allow(non_camel_case_types)]
-                    struct GetAccountDetailsSvc<T: Api>(pub Arc<T>);
+                    struct QueryAccountsSvc<T: Api>(pub Arc<T>);
                     impl<
                         T: Api,
                     > tonic::server::UnaryService<
-                        super::super::requests::GetAccountDetailsRequest,
-                    > for GetAccountDetailsSvc<T> {
-                        type Response = super::super::responses::GetAccountDetailsResponse;
+                        super::super::requests::QueryAccountsRequest,
+                    > for QueryAccountsSvc<T> {
+                        type Response = super::super::responses::QueryAccountsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -1024,12 +2724,12 @@ pub mod api_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::requests::GetAccountDetailsRequest,
+                                super::super::requests::QueryAccountsRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Api>::get_account_details(&inner, request).await
+                                <T as Api>::query_accounts(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -1041,7 +2741,7 @@ pub mod api_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetAccountDetailsSvc(inner);
+                        let method = QueryAccountsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -0,0 +1,24 @@
+// This file is @generated by prost-build.
+#[derive(Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionHeader {
+    /// Hash of the transaction, uniquely identifying it.
+    #[prost(message, optional, tag = "1")]
+    pub transaction_id: ::core::option::Option<super::digest::Digest>,
+    /// The account against which the transaction was executed.
+    #[prost(message, optional, tag = "2")]
+    pub account_id: ::core::option::Option<super::account::AccountId>,
+    /// Hash of the account state before the transaction was executed.
+    #[prost(message, optional, tag = "3")]
+    pub initial_account_hash: ::core::option::Option<super::digest::Digest>,
+    /// Hash of the account state after the transaction was executed.
+    #[prost(message, optional, tag = "4")]
+    pub final_account_hash: ::core::option::Option<super::digest::Digest>,
+    /// Nullifiers of the notes consumed by the transaction.
+    #[prost(message, repeated, tag = "5")]
+    pub input_notes: ::prost::alloc::vec::Vec<super::digest::Digest>,
+    /// IDs of the notes created by the transaction.
+    #[prost(message, repeated, tag = "6")]
+    pub output_notes: ::prost::alloc::vec::Vec<super::digest::Digest>,
+}
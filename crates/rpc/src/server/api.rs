@@ -2,12 +2,24 @@ use miden_node_proto::{
     generated::{
         block_producer::api_client as block_producer_client,
         requests::{
-            CheckNullifiersRequest, GetAccountDetailsRequest, GetBlockHeaderByNumberRequest,
-            GetNotesByIdRequest, SubmitProvenTransactionRequest, SyncStateRequest,
+            CheckNullifiersRequest, GetAccountDeltaRequest, GetAccountDetailsRequest,
+            GetAccountProofsRequest, GetAccountStateAtBlockRequest, GetAccountStorageSlotsRequest,
+            GetAccountVaultBalanceRequest, GetBlockByHashRequest, GetBlockByNumberRequest,
+            GetBlockHeaderByNumberRequest, GetBlockInclusionProofsRequest, GetNoteStatusRequest,
+            GetNotesByFaucetRequest, GetNotesByIdRequest, GetNotesByRecipientRequest,
+            GetTransactionInclusionRequest, QueryAccountsRequest, SubmitProvenTransactionRequest,
+            SubscribeBlocksRequest, SubscribeNotesRequest, SyncNotesRequest, SyncNullifiersRequest,
+            SyncStateRequest,
         },
         responses::{
-            CheckNullifiersResponse, GetAccountDetailsResponse, GetBlockHeaderByNumberResponse,
-            GetNotesByIdResponse, SubmitProvenTransactionResponse, SyncStateResponse,
+            CheckNullifiersResponse, GetAccountDeltaResponse, GetAccountDetailsResponse,
+            GetAccountProofsResponse, GetAccountStateAtBlockResponse,
+            GetAccountStorageSlotsResponse, GetAccountVaultBalanceResponse, GetBlockByHashResponse,
+            GetBlockByNumberResponse, GetBlockHeaderByNumberResponse,
+            GetBlockInclusionProofsResponse, GetNoteStatusResponse, GetNotesByFaucetResponse,
+            GetNotesByIdResponse, GetNotesByRecipientResponse, GetTransactionInclusionResponse,
+            QueryAccountsResponse, SubmitProvenTransactionResponse, SubscribeBlocksResponse,
+            SubscribeNotesResponse, SyncNotesResponse, SyncNullifiersResponse, SyncStateResponse,
         },
         rpc::api_server,
         store::api_client as store_client,
@@ -21,7 +33,7 @@ use miden_objects::{
 use miden_tx::TransactionVerifier;
 use tonic::{
     transport::{Channel, Error},
-    Request, Response, Status,
+    Request, Response, Status, Streaming,
 };
 use tracing::{debug, info, instrument};
 
@@ -96,6 +108,63 @@ impl api_server::Api for RpcApi {
         self.store.clone().get_block_header_by_number(request).await
     }
 
+    #[instrument(
+        target = "miden-rpc",
+        name = "rpc:get_block_inclusion_proofs",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_block_inclusion_proofs(
+        &self,
+        request: Request<GetBlockInclusionProofsRequest>,
+    ) -> Result<Response<GetBlockInclusionProofsResponse>, Status> {
+        debug!(target: COMPONENT, request = ?request.get_ref());
+
+        self.store.clone().get_block_inclusion_proofs(request).await
+    }
+
+    #[instrument(
+        target = "miden-rpc",
+        name = "rpc:get_block_by_number",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_block_by_number(
+        &self,
+        request: Request<GetBlockByNumberRequest>,
+    ) -> Result<Response<GetBlockByNumberResponse>, Status> {
+        info!(target: COMPONENT, request = ?request.get_ref());
+
+        self.store.clone().get_block_by_number(request).await
+    }
+
+    #[instrument(
+        target = "miden-rpc",
+        name = "rpc:get_block_by_hash",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_block_by_hash(
+        &self,
+        request: Request<GetBlockByHashRequest>,
+    ) -> Result<Response<GetBlockByHashResponse>, Status> {
+        info!(target: COMPONENT, request = ?request.get_ref());
+
+        // validate the block hash from the user request
+        let _: Digest = request
+            .get_ref()
+            .block_hash
+            .as_ref()
+            .ok_or(Status::invalid_argument("block_hash is missing"))?
+            .try_into()
+            .or(Err(Status::invalid_argument("Digest field is not in the modulus range")))?;
+
+        self.store.clone().get_block_by_hash(request).await
+    }
+
     #[instrument(
         target = "miden-rpc",
         name = "rpc:sync_state",
@@ -112,6 +181,62 @@ impl api_server::Api for RpcApi {
         self.store.clone().sync_state(request).await
     }
 
+    #[instrument(
+        target = "miden-rpc",
+        name = "rpc:sync_notes",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn sync_notes(
+        &self,
+        request: Request<SyncNotesRequest>,
+    ) -> Result<Response<SyncNotesResponse>, Status> {
+        debug!(target: COMPONENT, request = ?request.get_ref());
+
+        self.store.clone().sync_notes(request).await
+    }
+
+    #[instrument(
+        target = "miden-rpc",
+        name = "rpc:sync_nullifiers",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn sync_nullifiers(
+        &self,
+        request: Request<SyncNullifiersRequest>,
+    ) -> Result<Response<SyncNullifiersResponse>, Status> {
+        debug!(target: COMPONENT, request = ?request.get_ref());
+
+        self.store.clone().sync_nullifiers(request).await
+    }
+
+    type SubscribeBlocksStream = Streaming<SubscribeBlocksResponse>;
+
+    #[instrument(target = "miden-rpc", name = "rpc:subscribe_blocks", skip_all, err)]
+    async fn subscribe_blocks(
+        &self,
+        request: Request<SubscribeBlocksRequest>,
+    ) -> Result<Response<Self::SubscribeBlocksStream>, Status> {
+        debug!(target: COMPONENT, request = ?request.get_ref());
+
+        self.store.clone().subscribe_blocks(request).await
+    }
+
+    type SubscribeNotesStream = Streaming<SubscribeNotesResponse>;
+
+    #[instrument(target = "miden-rpc", name = "rpc:subscribe_notes", skip_all, err)]
+    async fn subscribe_notes(
+        &self,
+        request: Request<SubscribeNotesRequest>,
+    ) -> Result<Response<Self::SubscribeNotesStream>, Status> {
+        debug!(target: COMPONENT, request = ?request.get_ref());
+
+        self.store.clone().subscribe_notes(request).await
+    }
+
     #[instrument(
         target = "miden-rpc",
         name = "rpc:get_notes_by_id",
@@ -134,6 +259,76 @@ impl api_server::Api for RpcApi {
         self.store.clone().get_notes_by_id(request).await
     }
 
+    #[instrument(
+        target = "miden-rpc",
+        name = "rpc:get_note_status",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_note_status(
+        &self,
+        request: Request<GetNoteStatusRequest>,
+    ) -> Result<Response<GetNoteStatusResponse>, Status> {
+        debug!(target: COMPONENT, request = ?request.get_ref());
+
+        self.store.clone().get_note_status(request).await
+    }
+
+    #[instrument(
+        target = "miden-rpc",
+        name = "rpc:get_notes_by_recipient",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_notes_by_recipient(
+        &self,
+        request: Request<GetNotesByRecipientRequest>,
+    ) -> Result<Response<GetNotesByRecipientResponse>, Status> {
+        debug!(target: COMPONENT, request = ?request.get_ref());
+
+        self.store.clone().get_notes_by_recipient(request).await
+    }
+
+    #[instrument(
+        target = "miden-rpc",
+        name = "rpc:get_notes_by_faucet",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_notes_by_faucet(
+        &self,
+        request: Request<GetNotesByFaucetRequest>,
+    ) -> Result<Response<GetNotesByFaucetResponse>, Status> {
+        debug!(target: COMPONENT, request = ?request.get_ref());
+
+        self.store.clone().get_notes_by_faucet(request).await
+    }
+
+    #[instrument(
+        target = "miden-rpc",
+        name = "rpc:get_transaction_inclusion",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_transaction_inclusion(
+        &self,
+        request: Request<GetTransactionInclusionRequest>,
+    ) -> Result<Response<GetTransactionInclusionResponse>, Status> {
+        debug!(target: COMPONENT, request = ?request.get_ref());
+
+        // Validation checking for correct TransactionId's
+        let transaction_ids = request.get_ref().transaction_ids.clone();
+
+        let _: Vec<RpoDigest> = try_convert(transaction_ids)
+            .map_err(|err| Status::invalid_argument(format!("Invalid TransactionId: {}", err)))?;
+
+        self.store.clone().get_transaction_inclusion(request).await
+    }
+
     #[instrument(target = "miden-rpc", name = "rpc:submit_proven_transaction", skip_all, err)]
     async fn submit_proven_transaction(
         &self,
@@ -183,4 +378,157 @@ impl api_server::Api for RpcApi {
 
         self.store.clone().get_account_details(request).await
     }
+
+    /// Returns the latest hashes of accounts with their authentication paths in the account tree.
+    #[instrument(
+        target = "miden-rpc",
+        name = "rpc:get_account_proofs",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_account_proofs(
+        &self,
+        request: Request<GetAccountProofsRequest>,
+    ) -> std::result::Result<Response<GetAccountProofsResponse>, Status> {
+        debug!(target: COMPONENT, request = ?request.get_ref());
+
+        // Validating accounts using conversion:
+        for account_id in request.get_ref().account_ids.iter().cloned() {
+            let _account_id: AccountId = account_id
+                .try_into()
+                .map_err(|err| Status::invalid_argument(format!("Invalid account id: {err}")))?;
+        }
+
+        self.store.clone().get_account_proofs(request).await
+    }
+
+    /// Returns storage slots of a public account with their authentication paths in its storage.
+    #[instrument(
+        target = "miden-rpc",
+        name = "rpc:get_account_storage_slots",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_account_storage_slots(
+        &self,
+        request: Request<GetAccountStorageSlotsRequest>,
+    ) -> std::result::Result<Response<GetAccountStorageSlotsResponse>, Status> {
+        debug!(target: COMPONENT, request = ?request.get_ref());
+
+        // Validating account using conversion:
+        let _account_id: AccountId = request
+            .get_ref()
+            .account_id
+            .clone()
+            .ok_or(Status::invalid_argument("account_id is missing"))?
+            .try_into()
+            .map_err(|err| Status::invalid_argument(format!("Invalid account id: {err}")))?;
+
+        self.store.clone().get_account_storage_slots(request).await
+    }
+
+    /// Returns fungible asset balances of a public account with their openings in its vault.
+    #[instrument(
+        target = "miden-rpc",
+        name = "rpc:get_account_vault_balance",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_account_vault_balance(
+        &self,
+        request: Request<GetAccountVaultBalanceRequest>,
+    ) -> std::result::Result<Response<GetAccountVaultBalanceResponse>, Status> {
+        debug!(target: COMPONENT, request = ?request.get_ref());
+
+        // Validating accounts using conversion:
+        let _account_id: AccountId = request
+            .get_ref()
+            .account_id
+            .clone()
+            .ok_or(Status::invalid_argument("account_id is missing"))?
+            .try_into()
+            .map_err(|err| Status::invalid_argument(format!("Invalid account id: {err}")))?;
+        for faucet_id in request.get_ref().faucet_ids.iter().cloned() {
+            let _faucet_id: AccountId = faucet_id
+                .try_into()
+                .map_err(|err| Status::invalid_argument(format!("Invalid faucet id: {err}")))?;
+        }
+
+        self.store.clone().get_account_vault_balance(request).await
+    }
+
+    #[instrument(
+        target = "miden-rpc",
+        name = "rpc:get_account_state_at_block",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_account_state_at_block(
+        &self,
+        request: Request<GetAccountStateAtBlockRequest>,
+    ) -> std::result::Result<Response<GetAccountStateAtBlockResponse>, Status> {
+        debug!(target: COMPONENT, request = ?request.get_ref());
+
+        // Validating account using conversion:
+        let _account_id: AccountId = request
+            .get_ref()
+            .account_id
+            .clone()
+            .ok_or(Status::invalid_argument("account_id is missing"))?
+            .try_into()
+            .map_err(|err| Status::invalid_argument(format!("Invalid account id: {err}")))?;
+
+        self.store.clone().get_account_state_at_block(request).await
+    }
+
+    #[instrument(
+        target = "miden-rpc",
+        name = "rpc:get_account_delta",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_account_delta(
+        &self,
+        request: Request<GetAccountDeltaRequest>,
+    ) -> std::result::Result<Response<GetAccountDeltaResponse>, Status> {
+        debug!(target: COMPONENT, request = ?request.get_ref());
+
+        // Validating account using conversion:
+        let account_id: AccountId = request
+            .get_ref()
+            .account_id
+            .clone()
+            .ok_or(Status::invalid_argument("account_id is missing"))?
+            .try_into()
+            .map_err(|err| Status::invalid_argument(format!("Invalid account id: {err}")))?;
+
+        if !account_id.is_on_chain() {
+            return Err(Status::invalid_argument(
+                "Account deltas are only available for on-chain accounts",
+            ));
+        }
+
+        self.store.clone().get_account_delta(request).await
+    }
+
+    #[instrument(
+        target = "miden-rpc",
+        name = "rpc:query_accounts",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn query_accounts(
+        &self,
+        request: Request<QueryAccountsRequest>,
+    ) -> Result<Response<QueryAccountsResponse>, Status> {
+        debug!(target: COMPONENT, request = ?request.get_ref());
+
+        self.store.clone().query_accounts(request).await
+    }
 }
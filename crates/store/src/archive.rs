@@ -0,0 +1,376 @@
+//! Portable archives of the chain state, used to bootstrap new stores.
+//!
+//! An archive contains the state of the chain as of a given block: the headers of the blocks up to
+//! it, the state of every account, the notes which were not consumed yet and the nullifiers.
+//! Importing an archive into an empty DB yields a store which serves and extends the chain from
+//! that block, without replaying the chain from the genesis block.
+//!
+//! The data of the blocks and the transactions included in them are not part of the archive, so
+//! the blocks and transaction inclusions up to the block of the archive can't be queried from an
+//! imported store.
+//!
+//! The archive file is the serialized archive, starting with its format version, followed by the
+//! RPO hash of the serialized archive, used as a checksum.
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use miden_node_proto::domain::accounts::{AccountInfo, AccountSummary};
+use miden_objects::{
+    accounts::Account,
+    crypto::{
+        hash::rpo::{Rpo256, RpoDigest},
+        merkle::MerklePath,
+    },
+    notes::{NoteType, Nullifier},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    BlockHeader,
+};
+
+use crate::{
+    db::{Note, NoteCreated, Storage},
+    errors::ArchiveError,
+    types::BlockNumber,
+};
+
+/// Version of the archive file format, bumped on incompatible changes.
+const ARCHIVE_VERSION: u8 = 1;
+
+/// Size of the checksum at the end of the archive file.
+const CHECKSUM_SIZE: usize = 32;
+
+// STATE ARCHIVE
+// ================================================================================================
+
+/// State of the chain as of its last block header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateArchive {
+    block_headers: Vec<BlockHeader>,
+    accounts: Vec<AccountInfo>,
+    notes: Vec<Note>,
+    nullifiers: Vec<(Nullifier, BlockNumber)>,
+}
+
+impl StateArchive {
+    /// Exports the state of the chain as of block `block_num` from the `db`, or the state as of
+    /// the chain tip if [None].
+    ///
+    /// Notes which were pruned are not exported, since their Merkle paths are not available.
+    pub async fn export(
+        db: &dyn Storage,
+        block_num: Option<BlockNumber>,
+    ) -> Result<Self, ArchiveError> {
+        let chain_tip = db
+            .select_block_header_by_block_num(None)
+            .await?
+            .ok_or(ArchiveError::DbBlockHeaderEmpty)?
+            .block_num();
+        let block_num = block_num.unwrap_or(chain_tip);
+        if block_num > chain_tip {
+            return Err(ArchiveError::BlockAfterChainTip { block_num, chain_tip });
+        }
+
+        let block_headers = db
+            .select_block_headers()
+            .await?
+            .into_iter()
+            .take_while(|block_header| block_header.block_num() <= block_num)
+            .collect();
+
+        let account_ids =
+            db.select_account_hashes().await?.into_iter().map(|(account_id, _)| account_id);
+        let accounts = db.select_accounts_at_block(account_ids.collect(), block_num).await?;
+
+        let notes = db.select_unconsumed_notes(block_num).await?;
+
+        let nullifiers = db
+            .select_nullifiers()
+            .await?
+            .into_iter()
+            .filter(|(_, nullifier_block_num)| *nullifier_block_num <= block_num)
+            .collect();
+
+        Ok(Self {
+            block_headers,
+            accounts,
+            notes,
+            nullifiers,
+        })
+    }
+
+    /// Returns the header of the block the archive was exported at.
+    pub fn block_header(&self) -> Option<&BlockHeader> {
+        self.block_headers.last()
+    }
+
+    /// Returns the block headers, accounts, notes and nullifiers of the archive.
+    #[allow(clippy::type_complexity)]
+    pub fn into_parts(
+        self,
+    ) -> (Vec<BlockHeader>, Vec<AccountInfo>, Vec<Note>, Vec<(Nullifier, BlockNumber)>) {
+        (self.block_headers, self.accounts, self.notes, self.nullifiers)
+    }
+
+    /// Reads the archive from `path`, checking its checksum.
+    pub fn read(path: &Path) -> Result<Self, ArchiveError> {
+        Self::decode(&fs::read(path)?)
+    }
+
+    /// Writes the archive to `path`.
+    ///
+    /// The archive is written to a temporary file next to `path` first, which then replaces any
+    /// existing file, so that a crash while writing never leaves a truncated archive behind.
+    pub fn write(&self, path: &Path) -> Result<(), ArchiveError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut tmp_path = path.as_os_str().to_owned();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+        fs::write(&tmp_path, self.encode())?;
+        fs::rename(&tmp_path, path)?;
+
+        Ok(())
+    }
+
+    /// Serializes the archive followed by its checksum.
+    fn encode(&self) -> Vec<u8> {
+        let mut data = self.to_bytes();
+        let checksum = Rpo256::hash(&data);
+        data.extend_from_slice(&checksum.as_bytes());
+
+        data
+    }
+
+    /// Deserializes an archive written by [StateArchive::encode].
+    fn decode(data: &[u8]) -> Result<Self, ArchiveError> {
+        let split = data.len().checked_sub(CHECKSUM_SIZE).ok_or(ArchiveError::ChecksumMismatch)?;
+        let (data, checksum) = data.split_at(split);
+        if Rpo256::hash(data).as_bytes() != checksum {
+            return Err(ArchiveError::ChecksumMismatch);
+        }
+
+        let archive = Self::read_from_bytes(data).map_err(ArchiveError::DeserializationError)?;
+        if archive.block_headers.is_empty() {
+            return Err(ArchiveError::EmptyArchive);
+        }
+
+        Ok(archive)
+    }
+}
+
+impl Serializable for StateArchive {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(ARCHIVE_VERSION);
+
+        target.write_usize(self.block_headers.len());
+        target.write_many(&self.block_headers);
+
+        target.write_usize(self.accounts.len());
+        for info in self.accounts.iter() {
+            target.write_u64(info.summary.account_id.into());
+            info.summary.account_hash.write_into(target);
+            target.write_u32(info.summary.block_num);
+            target.write_bool(info.details.is_some());
+            if let Some(details) = &info.details {
+                details.write_into(target);
+            }
+        }
+
+        target.write_usize(self.notes.len());
+        for note in self.notes.iter() {
+            target.write_u32(note.block_num);
+            target.write_u32(note.note_created.batch_index);
+            target.write_u32(note.note_created.note_index);
+            note.note_created.note_id.write_into(target);
+            target.write_u8(note.note_created.note_type as u8);
+            target.write_u64(note.note_created.sender);
+            target.write_u32(note.note_created.tag);
+            target.write_bool(note.note_created.details.is_some());
+            if let Some(details) = &note.note_created.details {
+                target.write_usize(details.len());
+                target.write_bytes(details);
+            }
+            note.merkle_path.write_into(target);
+        }
+
+        target.write_usize(self.nullifiers.len());
+        for (nullifier, block_num) in self.nullifiers.iter() {
+            nullifier.write_into(target);
+            target.write_u32(*block_num);
+        }
+    }
+}
+
+impl Deserializable for StateArchive {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let version = source.read_u8()?;
+        if version != ARCHIVE_VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "Unsupported archive version {version}, expected {ARCHIVE_VERSION}"
+            )));
+        }
+
+        let num_block_headers = source.read_usize()?;
+        let block_headers = source.read_many::<BlockHeader>(num_block_headers)?;
+
+        let num_accounts = source.read_usize()?;
+        let accounts = (0..num_accounts)
+            .map(|_| {
+                let account_id = source
+                    .read_u64()?
+                    .try_into()
+                    .map_err(|err| DeserializationError::InvalidValue(format!("{err}")))?;
+                let account_hash = RpoDigest::read_from(source)?;
+                let block_num = source.read_u32()?;
+                let details = if source.read_bool()? {
+                    Some(Account::read_from(source)?)
+                } else {
+                    None
+                };
+
+                Ok(AccountInfo {
+                    summary: AccountSummary { account_id, account_hash, block_num },
+                    details,
+                })
+            })
+            .collect::<Result<_, DeserializationError>>()?;
+
+        let num_notes = source.read_usize()?;
+        let notes = (0..num_notes)
+            .map(|_| {
+                let block_num = source.read_u32()?;
+                let batch_index = source.read_u32()?;
+                let note_index = source.read_u32()?;
+                let note_id = RpoDigest::read_from(source)?;
+                let note_type = NoteType::try_from(source.read_u8()?)
+                    .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+                let sender = source.read_u64()?;
+                let tag = source.read_u32()?;
+                let details = if source.read_bool()? {
+                    let len = source.read_usize()?;
+                    Some(source.read_vec(len)?)
+                } else {
+                    None
+                };
+                let merkle_path = MerklePath::read_from(source)?;
+
+                Ok(Note {
+                    block_num,
+                    note_created: NoteCreated {
+                        batch_index,
+                        note_index,
+                        note_id,
+                        note_type,
+                        sender,
+                        tag,
+                        details,
+                    },
+                    merkle_path,
+                })
+            })
+            .collect::<Result<_, DeserializationError>>()?;
+
+        let num_nullifiers = source.read_usize()?;
+        let nullifiers = (0..num_nullifiers)
+            .map(|_| Ok((Nullifier::read_from(source)?, source.read_u32()?)))
+            .collect::<Result<_, DeserializationError>>()?;
+
+        Ok(Self {
+            block_headers,
+            accounts,
+            notes,
+            nullifiers,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::{Felt, ZERO};
+
+    use super::*;
+
+    fn num_to_rpo_digest(n: u64) -> RpoDigest {
+        RpoDigest::new([ZERO, ZERO, ZERO, Felt::new(n)])
+    }
+
+    fn archive() -> StateArchive {
+        let block_headers = (0..2)
+            .map(|block_num| {
+                BlockHeader::new(
+                    num_to_rpo_digest(1),
+                    block_num,
+                    num_to_rpo_digest(3),
+                    num_to_rpo_digest(4),
+                    num_to_rpo_digest(5),
+                    num_to_rpo_digest(6),
+                    num_to_rpo_digest(7),
+                    num_to_rpo_digest(8),
+                    ZERO,
+                    Felt::new(10),
+                )
+            })
+            .collect();
+        let notes = vec![Note {
+            block_num: 1,
+            note_created: NoteCreated {
+                batch_index: 0,
+                note_index: 1,
+                note_id: num_to_rpo_digest(9),
+                note_type: NoteType::Public,
+                sender: 10,
+                tag: 11,
+                details: Some(vec![1, 2, 3]),
+            },
+            merkle_path: MerklePath::new(vec![num_to_rpo_digest(12)]),
+        }];
+
+        StateArchive {
+            block_headers,
+            accounts: vec![],
+            notes,
+            nullifiers: vec![(Nullifier::from(num_to_rpo_digest(13)), 1)],
+        }
+    }
+
+    #[test]
+    fn test_archive_roundtrip() {
+        let archive = archive();
+
+        let decoded = StateArchive::decode(&archive.encode()).unwrap();
+        assert_eq!(decoded, archive);
+        assert_eq!(decoded.block_header().map(BlockHeader::block_num), Some(1));
+    }
+
+    #[test]
+    fn test_archive_write() {
+        let dir = std::env::temp_dir().join(format!("miden-archive-{}", std::process::id()));
+        let path = dir.join("state.archive");
+
+        // a file sharing the stem of the archive isn't used as the temporary file
+        let other_path = dir.join("state.tmp");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&other_path, [1, 2, 3]).unwrap();
+
+        archive().write(&path).unwrap();
+        assert_eq!(StateArchive::read(&path).unwrap(), archive());
+        assert_eq!(fs::read(&other_path).unwrap(), vec![1, 2, 3]);
+        assert!(!dir.join("state.archive.tmp").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_archive_checksum_mismatch() {
+        let mut data = archive().encode();
+        data[1] ^= 1;
+        assert!(matches!(StateArchive::decode(&data), Err(ArchiveError::ChecksumMismatch)));
+
+        let data = archive().encode();
+        let truncated = &data[..data.len() - 1];
+        assert!(matches!(StateArchive::decode(truncated), Err(ArchiveError::ChecksumMismatch)));
+    }
+}
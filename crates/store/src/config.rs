@@ -6,6 +6,17 @@ use std::{
 use miden_node_utils::config::Endpoint;
 use serde::{Deserialize, Serialize};
 
+use crate::{db::PruningTarget, snapshot::SnapshotFile, types::BlockNumber};
+
+/// Default number of blocks between two snapshots of the in-memory structures.
+pub const DEFAULT_SNAPSHOT_INTERVAL: u32 = 1000;
+
+/// Default number of account updates stored as deltas between two full checkpoints of the account.
+pub const DEFAULT_ACCOUNT_CHECKPOINT_INTERVAL: u32 = 100;
+
+/// Default number of seconds between two runs of the pruning task.
+pub const DEFAULT_PRUNING_INTERVAL: u64 = 600;
+
 // Main config
 // ================================================================================================
 
@@ -17,19 +28,89 @@ pub struct StoreConfig {
     pub database_filepath: PathBuf,
     /// Genesis file
     pub genesis_filepath: PathBuf,
+    /// Snapshot file of the in-memory Merkle structures, used to speed up startup. Snapshots are
+    /// disabled if not set.
+    #[serde(default)]
+    pub snapshot_filepath: Option<PathBuf>,
+    /// Number of blocks between two snapshots
+    #[serde(default = "default_snapshot_interval")]
+    pub snapshot_interval: u32,
+    /// Number of updates of an on-chain account stored as deltas between two full checkpoints of
+    /// its state
+    #[serde(default = "default_account_checkpoint_interval")]
+    pub account_checkpoint_interval: u32,
+    /// Retention of the data of old blocks, nothing is pruned if not set.
+    #[serde(default)]
+    pub retention: Option<RetentionConfig>,
 }
 
 impl StoreConfig {
     pub fn endpoint_url(&self) -> String {
         self.endpoint.to_string()
     }
+
+    /// Returns the configured snapshot file, if snapshots are enabled.
+    pub fn snapshot_file(&self) -> Option<SnapshotFile> {
+        self.snapshot_filepath
+            .clone()
+            .map(|path| SnapshotFile::new(path, self.snapshot_interval))
+    }
+}
+
+fn default_snapshot_interval() -> u32 {
+    DEFAULT_SNAPSHOT_INTERVAL
+}
+
+fn default_account_checkpoint_interval() -> u32 {
+    DEFAULT_ACCOUNT_CHECKPOINT_INTERVAL
 }
 
 impl Display for StoreConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.write_fmt(format_args!(
-            "{{ endpoint: \"{}\",  database_filepath: {:?}, genesis_filepath: {:?} }}",
-            self.endpoint, self.database_filepath, self.genesis_filepath
+            "{{ endpoint: \"{}\",  database_filepath: {:?}, genesis_filepath: {:?}, snapshot_filepath: {:?}, snapshot_interval: {}, account_checkpoint_interval: {}, retention: {:?} }}",
+            self.endpoint, self.database_filepath, self.genesis_filepath, self.snapshot_filepath, self.snapshot_interval, self.account_checkpoint_interval, self.retention
         ))
     }
 }
+
+// Retention config
+// ================================================================================================
+
+/// Number of blocks for which the data of the store is kept, counted back from the chain tip.
+///
+/// The block headers, the note IDs and metadata, the nullifiers and the account hashes are always
+/// kept, since they are needed to rebuild the Merkle structures and to generate proofs.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize, Deserialize)]
+pub struct RetentionConfig {
+    /// Number of blocks after which the Merkle paths and details of the notes are pruned.
+    #[serde(default)]
+    pub notes: Option<u32>,
+    /// Number of blocks after which the Merkle paths and details of the consumed notes are pruned,
+    /// counted from the block in which they were consumed.
+    #[serde(default)]
+    pub consumed_notes: Option<u32>,
+    /// Number of blocks after which the account states replaced by a newer state are pruned.
+    #[serde(default)]
+    pub account_history: Option<u32>,
+    /// Number of seconds between two runs of the pruning task
+    #[serde(default = "default_pruning_interval")]
+    pub interval: u64,
+}
+
+impl RetentionConfig {
+    /// Returns the blocks up to which the data is pruned when the chain tip is `chain_tip`.
+    pub fn pruning_target(&self, chain_tip: BlockNumber) -> PruningTarget {
+        let target = |blocks: Option<u32>| blocks.and_then(|blocks| chain_tip.checked_sub(blocks));
+
+        PruningTarget {
+            notes: target(self.notes),
+            consumed_notes: target(self.consumed_notes),
+            account_history: target(self.account_history),
+        }
+    }
+}
+
+fn default_pruning_interval() -> u64 {
+    DEFAULT_PRUNING_INTERVAL
+}
@@ -0,0 +1,69 @@
+//! Cache of the state of the on-chain accounts.
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::Mutex,
+};
+
+use miden_objects::{accounts::Account, crypto::hash::rpo::RpoDigest};
+
+use crate::types::AccountId;
+
+/// Cache of recently used on-chain account states, which saves rebuilding them from the checkpoints
+/// and deltas of the account history.
+///
+/// The states are looked up by account hash, so that a cached state which doesn't match the DB
+/// anymore, e.g. after a failed block or a rollback, is never returned. The oldest entries are
+/// evicted once the cache is full.
+#[derive(Debug)]
+pub struct AccountCache {
+    capacity: usize,
+    entries: Mutex<CacheEntries>,
+}
+
+#[derive(Debug, Default)]
+struct CacheEntries {
+    accounts: BTreeMap<AccountId, Account>,
+    /// Account IDs in insertion order, used for eviction.
+    order: VecDeque<AccountId>,
+}
+
+impl AccountCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(CacheEntries::default()),
+        }
+    }
+
+    /// Returns the cached state of the account `account_id` if its hash is `account_hash`.
+    pub fn get(&self, account_id: AccountId, account_hash: RpoDigest) -> Option<Account> {
+        let entries = self.entries.lock().expect("account cache lock poisoned");
+
+        entries
+            .accounts
+            .get(&account_id)
+            .filter(|account| account.hash() == account_hash)
+            .cloned()
+    }
+
+    /// Caches the state of an account, replacing the previous state of the same account.
+    pub fn insert(&self, account: Account) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut entries = self.entries.lock().expect("account cache lock poisoned");
+
+        let account_id = account.id().into();
+        if entries.accounts.insert(account_id, account).is_none() {
+            entries.order.push_back(account_id);
+        }
+
+        while entries.order.len() > self.capacity {
+            if let Some(evicted) = entries.order.pop_front() {
+                entries.accounts.remove(&evicted);
+            }
+        }
+    }
+}
@@ -0,0 +1,1169 @@
+//! In-memory implementation of the [Storage].
+//!
+//! The data is kept in ordered maps mirroring the tables of the SQLite DB, so that both
+//! implementations return the items of the queries in the same order.
+use std::collections::{BTreeMap, BTreeSet};
+
+use async_trait::async_trait;
+use miden_node_proto::domain::{
+    accounts::{AccountInfo, AccountSummary, AccountUpdateDetails},
+    transactions::TransactionHeader,
+};
+use miden_objects::{
+    accounts::{Account, AccountDelta},
+    assets::Asset,
+    crypto::{hash::rpo::RpoDigest, merkle::MerklePath},
+    notes::{Note as NoteData, NoteId, Nullifier},
+    transaction::{AccountDetails, TransactionId},
+    utils::serde::{Deserializable, Serializable},
+    BlockHeader,
+};
+use tokio::sync::{oneshot, RwLock};
+use tracing::instrument;
+
+use super::{
+    genesis_block, into_page, sql::get_nullifier_prefix, AccountFilter, AccountHistoryRecord,
+    AccountKind, AccountMetadata, AccountMetadataFilter, BlockSummary, Note, NoteFilter, NoteKey,
+    NoteStatus, NoteSyncUpdate, NullifierFilter, NullifierInfo, NullifierSyncKey, Page,
+    PageRequest, PruningTarget, Result, StateReplay, StateSyncUpdate, Storage,
+    TransactionInclusion,
+};
+use crate::{
+    errors::{DatabaseError, GenesisError, StateSyncError},
+    genesis::GenesisState,
+    types::{AccountId, BlockNumber},
+};
+
+// MEMORY DB
+// ================================================================================================
+
+/// [Storage] keeping all the data in memory.
+///
+/// The data is lost once the [MemoryDb] is dropped.
+pub struct MemoryDb {
+    tables: RwLock<Tables>,
+}
+
+impl MemoryDb {
+    /// Returns a new [MemoryDb] holding only the genesis block of `genesis_state`.
+    pub fn new(genesis_state: GenesisState) -> Result<Self, GenesisError> {
+        let (block_header, block_data, accounts) = genesis_block(genesis_state)?;
+
+        let mut tables = Tables::default();
+        let block =
+            tables.stage_block(block_header, block_data, vec![], vec![], accounts, vec![])?;
+        tables.commit_block(block);
+
+        Ok(Self { tables: RwLock::new(tables) })
+    }
+}
+
+#[async_trait]
+impl Storage for MemoryDb {
+    async fn select_nullifiers(&self) -> Result<Vec<(Nullifier, BlockNumber)>> {
+        let tables = self.tables.read().await;
+
+        let mut nullifiers: Vec<_> = tables.nullifiers.values().copied().collect();
+        nullifiers.sort_by_key(|(_, block_num)| *block_num);
+
+        Ok(nullifiers)
+    }
+
+    async fn select_notes(&self) -> Result<Vec<Note>> {
+        let tables = self.tables.read().await;
+
+        Ok(tables.notes.values().map(|record| record.note.clone()).collect())
+    }
+
+    async fn select_unconsumed_notes(&self, block_num: BlockNumber) -> Result<Vec<Note>> {
+        let tables = self.tables.read().await;
+
+        Ok(tables
+            .notes
+            .values()
+            .filter(|record| {
+                record.note.block_num <= block_num
+                    && record.consumed_block_num.map_or(true, |consumed| consumed > block_num)
+                    && !record.pruned
+            })
+            .map(|record| record.note.clone())
+            .collect())
+    }
+
+    async fn select_accounts(&self) -> Result<Vec<AccountInfo>> {
+        let tables = self.tables.read().await;
+
+        let mut accounts: Vec<_> = tables.accounts.values().cloned().collect();
+        accounts.sort_by_key(|info| info.summary.block_num);
+
+        Ok(accounts)
+    }
+
+    async fn select_nullifiers_page(
+        &self,
+        filter: NullifierFilter,
+        page: PageRequest<Nullifier>,
+    ) -> Result<Page<(Nullifier, BlockNumber), Nullifier>> {
+        let tables = self.tables.read().await;
+
+        let after = page.after.map(|nullifier| nullifier.to_bytes());
+        let nullifiers = tables
+            .nullifiers
+            .iter()
+            .filter(|(key, _)| after.as_ref().map_or(true, |after| *key > after))
+            .map(|(_, entry)| *entry)
+            .filter(|(_, block_num)| {
+                filter.block_num_from.map_or(true, |from| *block_num >= from)
+                    && filter.block_num_to.map_or(true, |to| *block_num <= to)
+            })
+            .take(page.size.saturating_add(1))
+            .collect();
+
+        Ok(into_page(nullifiers, page.size, |(nullifier, _)| *nullifier))
+    }
+
+    async fn select_nullifiers_by_block_range_page(
+        &self,
+        block_start: BlockNumber,
+        block_end: BlockNumber,
+        nullifier_prefixes: Vec<u32>,
+        page: PageRequest<NullifierSyncKey>,
+    ) -> Result<Page<NullifierInfo, NullifierSyncKey>> {
+        let tables = self.tables.read().await;
+
+        Ok(
+            tables.nullifiers_by_block_range_page(
+                block_start,
+                block_end,
+                &nullifier_prefixes,
+                page,
+            ),
+        )
+    }
+
+    async fn select_accounts_page(
+        &self,
+        filter: AccountFilter,
+        page: PageRequest<AccountId>,
+    ) -> Result<Page<AccountInfo, AccountId>> {
+        let tables = self.tables.read().await;
+
+        let accounts = tables
+            .accounts
+            .iter()
+            .filter(|(account_id, _)| page.after.map_or(true, |after| **account_id > after))
+            .map(|(_, info)| info)
+            .filter(|info| {
+                filter.block_num_from.map_or(true, |from| info.summary.block_num >= from)
+                    && filter.block_num_to.map_or(true, |to| info.summary.block_num <= to)
+                    && filter
+                        .on_chain
+                        .map_or(true, |on_chain| info.summary.account_id.is_on_chain() == on_chain)
+            })
+            .take(page.size.saturating_add(1))
+            .cloned()
+            .collect();
+
+        Ok(into_page(accounts, page.size, |info| info.summary.account_id.into()))
+    }
+
+    async fn select_account_metadata_page(
+        &self,
+        filter: AccountMetadataFilter,
+        page: PageRequest<AccountId>,
+    ) -> Result<Page<AccountMetadata, AccountId>> {
+        let tables = self.tables.read().await;
+
+        let accounts = tables
+            .accounts
+            .iter()
+            .filter(|(account_id, _)| page.after.map_or(true, |after| **account_id > after))
+            .map(|(_, info)| tables.account_metadata(info))
+            .filter(|metadata| {
+                filter.kind.map_or(true, |kind| metadata.kind == kind)
+                    && filter.on_chain.map_or(true, |on_chain| metadata.on_chain == on_chain)
+                    && filter
+                        .created_block_num_from
+                        .map_or(true, |from| metadata.created_block_num >= from)
+                    && filter
+                        .created_block_num_to
+                        .map_or(true, |to| metadata.created_block_num <= to)
+                    && filter
+                        .code_root
+                        .map_or(true, |code_root| metadata.code_root == Some(code_root))
+            })
+            .take(page.size.saturating_add(1))
+            .collect();
+
+        Ok(into_page(accounts, page.size, |metadata| metadata.summary.account_id.into()))
+    }
+
+    async fn select_notes_page(
+        &self,
+        filter: NoteFilter,
+        page: PageRequest<NoteKey>,
+    ) -> Result<Page<Note, NoteKey>> {
+        let tables = self.tables.read().await;
+
+        let notes = tables
+            .notes
+            .iter()
+            .filter(|(key, _)| page.after.map_or(true, |after| **key > after))
+            .map(|(_, record)| record)
+            .filter(|record| record.matches(&filter))
+            .take(page.size.saturating_add(1))
+            .map(NoteRecord::unpruned_note)
+            .collect::<Result<_>>()?;
+
+        Ok(into_page(notes, page.size, NoteKey::from))
+    }
+
+    async fn select_block_header_by_block_num(
+        &self,
+        block_number: Option<BlockNumber>,
+    ) -> Result<Option<BlockHeader>> {
+        let tables = self.tables.read().await;
+
+        Ok(tables.block_header(block_number))
+    }
+
+    async fn select_block_by_num(&self, block_num: BlockNumber) -> Result<Option<Vec<u8>>> {
+        let tables = self.tables.read().await;
+
+        Ok(tables.blocks.get(&block_num).map(|(_, block_data)| block_data.clone()))
+    }
+
+    async fn select_block_by_hash(&self, block_hash: RpoDigest) -> Result<Option<Vec<u8>>> {
+        let tables = self.tables.read().await;
+
+        Ok(tables
+            .blocks
+            .values()
+            .find(|(hash, _)| *hash == block_hash)
+            .map(|(_, block_data)| block_data.clone()))
+    }
+
+    async fn select_block_headers(&self) -> Result<Vec<BlockHeader>> {
+        let tables = self.tables.read().await;
+
+        Ok(tables.block_headers.values().copied().collect())
+    }
+
+    async fn select_block_headers_by_block_nums(
+        &self,
+        block_nums: Vec<BlockNumber>,
+    ) -> Result<Vec<BlockHeader>> {
+        let tables = self.tables.read().await;
+
+        Ok(tables.block_headers_by_block_nums(&block_nums))
+    }
+
+    async fn select_block_summaries(
+        &self,
+        block_num: BlockNumber,
+        limit: usize,
+    ) -> Result<Vec<BlockSummary>> {
+        let tables = self.tables.read().await;
+
+        let summaries = tables
+            .block_headers
+            .range(block_num..)
+            .take(limit)
+            .map(|(block_num, block_header)| {
+                let num_account_updates = tables
+                    .account_history
+                    .keys()
+                    .filter(|(_, history_block_num)| history_block_num == block_num)
+                    .count();
+                let num_nullifiers = tables
+                    .nullifiers
+                    .values()
+                    .filter(|(_, nullifier_block_num)| nullifier_block_num == block_num)
+                    .count();
+                let num_notes =
+                    tables.notes.keys().filter(|key| key.block_num == *block_num).count();
+
+                BlockSummary {
+                    block_header: *block_header,
+                    num_account_updates: num_account_updates as u32,
+                    num_nullifiers: num_nullifiers as u32,
+                    num_notes: num_notes as u32,
+                }
+            })
+            .collect();
+
+        Ok(summaries)
+    }
+
+    async fn select_account_hashes(&self) -> Result<Vec<(AccountId, RpoDigest)>> {
+        let tables = self.tables.read().await;
+
+        let mut accounts: Vec<_> = tables.accounts.values().collect();
+        accounts.sort_by_key(|info| info.summary.block_num);
+
+        Ok(accounts
+            .into_iter()
+            .map(|info| (info.summary.account_id.into(), info.summary.account_hash))
+            .collect())
+    }
+
+    async fn select_account_hash_history(
+        &self,
+    ) -> Result<Vec<(AccountId, BlockNumber, RpoDigest)>> {
+        let tables = self.tables.read().await;
+
+        let mut history: Vec<_> = tables
+            .account_history
+            .iter()
+            .map(|((account_id, block_num), entry)| (*account_id, *block_num, entry.account_hash))
+            .collect();
+        history.sort_by_key(|(_, block_num, _)| *block_num);
+
+        Ok(history)
+    }
+
+    async fn select_account_updates_since(
+        &self,
+        block_num: BlockNumber,
+    ) -> Result<Vec<(AccountId, BlockNumber, Option<RpoDigest>)>> {
+        let tables = self.tables.read().await;
+
+        // the history is sorted by account and block, so the previous entry of an update holds
+        // the prior state of the account if it is for the same account
+        let mut updates = Vec::new();
+        let mut prev: Option<(AccountId, RpoDigest)> = None;
+        for (&(account_id, update_block_num), entry) in tables.account_history.iter() {
+            if update_block_num > block_num {
+                let prev_hash = prev
+                    .filter(|(prev_account_id, _)| *prev_account_id == account_id)
+                    .map(|(_, prev_hash)| prev_hash);
+                updates.push((account_id, update_block_num, prev_hash));
+            }
+            prev = Some((account_id, entry.account_hash));
+        }
+        updates.sort_by_key(|(_, update_block_num, _)| *update_block_num);
+
+        Ok(updates)
+    }
+
+    async fn select_state_since(&self, block_num: BlockNumber) -> Result<StateReplay> {
+        let tables = self.tables.read().await;
+
+        Ok(tables.state_since(Some(block_num)))
+    }
+
+    async fn select_account(&self, id: AccountId) -> Result<AccountInfo> {
+        let tables = self.tables.read().await;
+
+        tables.accounts.get(&id).cloned().ok_or(DatabaseError::AccountNotFoundInDb(id))
+    }
+
+    async fn select_accounts_by_ids(
+        &self,
+        account_ids: Vec<AccountId>,
+    ) -> Result<Vec<AccountInfo>> {
+        let tables = self.tables.read().await;
+
+        let account_ids: BTreeSet<_> = account_ids.into_iter().collect();
+
+        Ok(account_ids
+            .into_iter()
+            .filter_map(|account_id| tables.accounts.get(&account_id).cloned())
+            .collect())
+    }
+
+    async fn select_accounts_at_block(
+        &self,
+        account_ids: Vec<AccountId>,
+        block_num: BlockNumber,
+    ) -> Result<Vec<AccountInfo>> {
+        let tables = self.tables.read().await;
+
+        let account_ids: BTreeSet<_> = account_ids.into_iter().collect();
+
+        account_ids
+            .into_iter()
+            .filter_map(|account_id| tables.account_at_block(account_id, block_num).transpose())
+            .collect()
+    }
+
+    async fn select_account_at_block(
+        &self,
+        id: AccountId,
+        block_num: BlockNumber,
+    ) -> Result<AccountInfo> {
+        let tables = self.tables.read().await;
+
+        tables
+            .account_at_block(id, block_num)?
+            .ok_or(DatabaseError::AccountNotFoundInDb(id))
+    }
+
+    async fn select_account_history(
+        &self,
+        id: AccountId,
+        block_start: BlockNumber,
+        block_end: BlockNumber,
+    ) -> Result<Vec<AccountHistoryRecord>> {
+        let tables = self.tables.read().await;
+
+        tables
+            .account_history
+            .range((id, 0)..=(id, BlockNumber::MAX))
+            .filter(|((_, block_num), _)| *block_num > block_start && *block_num <= block_end)
+            .map(|((_, block_num), entry)| {
+                if entry.pruned {
+                    return Err(DatabaseError::AccountStatePruned {
+                        account_id: id,
+                        block_num: *block_num,
+                    });
+                }
+
+                let details = match (&entry.delta, &entry.details) {
+                    (Some(delta), _) => AccountDetails::Delta(delta.clone()),
+                    (None, Some(account)) => AccountDetails::Full(account.clone()),
+                    (None, None) => return Err(DatabaseError::AccountNotOnChain(id)),
+                };
+
+                Ok(AccountHistoryRecord {
+                    block_num: *block_num,
+                    account_hash: entry.account_hash,
+                    details,
+                })
+            })
+            .collect()
+    }
+
+    async fn get_state_sync(
+        &self,
+        block_num: BlockNumber,
+        account_ids: &[AccountId],
+        note_tag_prefixes: &[u32],
+        nullifier_prefixes: &[u32],
+    ) -> Result<StateSyncUpdate, StateSyncError> {
+        let tables = self.tables.read().await;
+
+        // notes of the next block which contains at least one note with a matching tag or sender
+        let matching_notes = || {
+            tables.notes.values().filter(|record| {
+                record.note.block_num > block_num
+                    && record.matches_tags_or_senders(note_tag_prefixes, account_ids)
+            })
+        };
+        let notes: Vec<Note> = match matching_notes().next() {
+            Some(first) => matching_notes()
+                .filter(|record| record.note.block_num == first.note.block_num)
+                .map(|record| record.note.clone())
+                .collect(),
+            None => vec![],
+        };
+
+        let tip = tables.block_header(None).ok_or(StateSyncError::EmptyBlockHeadersTable)?;
+        let block_header = match notes.first() {
+            Some(note) => tables
+                .block_header(Some(note.block_num))
+                .ok_or(StateSyncError::EmptyBlockHeadersTable)?,
+            None => tip,
+        };
+
+        let mut account_updates: Vec<AccountSummary> = tables
+            .accounts
+            .values()
+            .filter(|info| {
+                info.summary.block_num > block_num
+                    && info.summary.block_num <= block_header.block_num()
+                    && account_ids.contains(&info.summary.account_id.into())
+            })
+            .map(|info| info.summary.clone())
+            .collect();
+        account_updates.sort_by_key(|summary| summary.block_num);
+
+        let page = PageRequest { after: None, size: usize::MAX };
+        let nullifiers = tables
+            .nullifiers_by_block_range_page(
+                block_num,
+                block_header.block_num(),
+                nullifier_prefixes,
+                page,
+            )
+            .items;
+
+        Ok(StateSyncUpdate {
+            notes,
+            block_header,
+            chain_tip: tip.block_num(),
+            account_updates,
+            nullifiers,
+        })
+    }
+
+    async fn get_note_sync(
+        &self,
+        block_num: BlockNumber,
+        note_tag_prefixes: &[u32],
+        account_ids: &[AccountId],
+        max_blocks: usize,
+    ) -> Result<NoteSyncUpdate, StateSyncError> {
+        let tables = self.tables.read().await;
+
+        let matching_notes = tables.notes.values().filter(|record| {
+            record.note.block_num > block_num
+                && record.matches_tags_or_senders(note_tag_prefixes, account_ids)
+        });
+
+        let mut notes = vec![];
+        let mut block_nums = vec![];
+        for record in matching_notes {
+            if block_nums.last() != Some(&record.note.block_num) {
+                if block_nums.len() == max_blocks {
+                    break;
+                }
+                block_nums.push(record.note.block_num);
+            }
+            notes.push(record.note.clone());
+        }
+
+        let block_headers = tables.block_headers_by_block_nums(&block_nums);
+
+        let tip = tables.block_header(None).ok_or(StateSyncError::EmptyBlockHeadersTable)?;
+        let block_header = match block_headers.last() {
+            Some(last) if block_headers.len() >= max_blocks => *last,
+            _ => tip,
+        };
+
+        Ok(NoteSyncUpdate {
+            notes,
+            block_headers,
+            block_header,
+            chain_tip: tip.block_num(),
+        })
+    }
+
+    async fn select_notes_by_id(&self, note_ids: Vec<NoteId>) -> Result<Vec<Note>> {
+        let tables = self.tables.read().await;
+
+        tables
+            .notes
+            .values()
+            .filter(|record| note_ids.contains(&record.note.note_created.note_id.into()))
+            .map(NoteRecord::unpruned_note)
+            .collect()
+    }
+
+    async fn select_note_statuses_by_id(&self, note_ids: Vec<NoteId>) -> Result<Vec<NoteStatus>> {
+        let tables = self.tables.read().await;
+
+        tables
+            .notes
+            .values()
+            .filter(|record| note_ids.contains(&record.note.note_created.note_id.into()))
+            .map(|record| {
+                let note = record.unpruned_note()?;
+                Ok(NoteStatus {
+                    note_id: note.note_created.note_id,
+                    block_num: note.block_num,
+                    note_index: note.note_created.absolute_note_index(),
+                    merkle_path: note.merkle_path,
+                    consumed_block_num: record.consumed_block_num,
+                })
+            })
+            .collect()
+    }
+
+    async fn select_transactions_by_id(
+        &self,
+        transaction_ids: Vec<TransactionId>,
+    ) -> Result<Vec<TransactionInclusion>> {
+        let tables = self.tables.read().await;
+
+        Ok(tables
+            .transactions
+            .values()
+            .filter(|inclusion| transaction_ids.contains(&inclusion.transaction_id))
+            .cloned()
+            .collect())
+    }
+
+    #[instrument(target = "miden-store", skip_all, err)]
+    async fn apply_block(
+        &self,
+        allow_acquire: oneshot::Sender<()>,
+        acquire_done: oneshot::Receiver<()>,
+        block_header: BlockHeader,
+        block_data: Vec<u8>,
+        notes: Vec<Note>,
+        nullifiers: Vec<Nullifier>,
+        accounts: Vec<AccountUpdateDetails>,
+        transactions: Vec<TransactionHeader>,
+    ) -> Result<()> {
+        // the block is validated while readers can still access the tables, the writes are
+        // serialized by the caller so the tables can't change until the block is committed
+        let block = self.tables.read().await.stage_block(
+            block_header,
+            block_data,
+            notes,
+            nullifiers,
+            accounts,
+            transactions,
+        )?;
+
+        let _ = allow_acquire.send(());
+        acquire_done.await.map_err(DatabaseError::ApplyBlockFailedClosedChannel)?;
+
+        self.tables.write().await.commit_block(block);
+
+        Ok(())
+    }
+
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn rollback_to(
+        &self,
+        block_num: BlockNumber,
+        rolled_back: oneshot::Sender<StateReplay>,
+        commit: oneshot::Receiver<()>,
+    ) -> Result<usize> {
+        let mut tables = self.tables.write().await;
+
+        // the rollback is applied to a copy of the tables, which only replaces them once committed
+        let mut rolled_back_tables = tables.clone();
+        let count = rolled_back_tables.rollback_to(block_num)?;
+
+        let _ = rolled_back.send(rolled_back_tables.state_since(None));
+        commit.await.map_err(DatabaseError::RollbackFailedClosedChannel)?;
+
+        *tables = rolled_back_tables;
+
+        Ok(count)
+    }
+
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn prune(&self, target: PruningTarget) -> Result<usize> {
+        let mut tables = self.tables.write().await;
+
+        Ok(tables.prune(&target))
+    }
+}
+
+// TABLES
+// ================================================================================================
+
+/// Data of the [MemoryDb], each map mirrors a table of the SQLite DB.
+#[derive(Clone, Default)]
+struct Tables {
+    block_headers: BTreeMap<BlockNumber, BlockHeader>,
+    /// Hash of the block header and data of each block.
+    blocks: BTreeMap<BlockNumber, (RpoDigest, Vec<u8>)>,
+    notes: BTreeMap<NoteKey, NoteRecord>,
+    /// Keys of the notes with public details, indexed by the serialized form of their nullifier.
+    notes_by_nullifier: BTreeMap<Vec<u8>, BTreeSet<NoteKey>>,
+    /// Nullifiers keyed by their serialized form, which is the order of the nullifiers in the
+    /// SQLite DB.
+    nullifiers: BTreeMap<Vec<u8>, (Nullifier, BlockNumber)>,
+    accounts: BTreeMap<AccountId, AccountInfo>,
+    account_history: BTreeMap<(AccountId, BlockNumber), AccountHistoryEntry>,
+    /// Inclusion records keyed by block number and position in the block.
+    transactions: BTreeMap<(BlockNumber, u32), TransactionInclusion>,
+}
+
+/// A note together with the indexed contents of its public details.
+#[derive(Clone)]
+struct NoteRecord {
+    note: Note,
+    nullifier: Option<Nullifier>,
+    recipient: Option<RpoDigest>,
+    script_root: Option<RpoDigest>,
+    faucet_ids: Vec<AccountId>,
+    consumed_block_num: Option<BlockNumber>,
+    /// Whether the Merkle path and details of the note were pruned.
+    pruned: bool,
+}
+
+impl NoteRecord {
+    /// Parses the public details of the note, details which can't be deserialized into a note
+    /// are stored as is, without being indexed.
+    fn new(note: Note) -> Self {
+        let note_data = note
+            .note_created
+            .details
+            .as_deref()
+            .and_then(|details| NoteData::read_from_bytes(details).ok());
+
+        let faucet_ids = note_data
+            .iter()
+            .flat_map(|data| data.assets().iter())
+            .filter_map(|asset| match asset {
+                Asset::Fungible(asset) => Some(asset.faucet_id().into()),
+                Asset::NonFungible(_) => None,
+            })
+            .collect();
+
+        Self {
+            note,
+            nullifier: note_data.as_ref().map(NoteData::nullifier),
+            recipient: note_data.as_ref().map(NoteData::recipient),
+            script_root: note_data.as_ref().map(|data| data.script().hash()),
+            faucet_ids,
+            consumed_block_num: None,
+            pruned: false,
+        }
+    }
+
+    /// Returns the note, failing if its Merkle path and details were pruned.
+    fn unpruned_note(&self) -> Result<Note> {
+        if self.pruned {
+            return Err(DatabaseError::NotePruned(self.note.note_created.note_id));
+        }
+
+        Ok(self.note.clone())
+    }
+
+    fn matches(&self, filter: &NoteFilter) -> bool {
+        let note = &self.note;
+
+        filter.block_num_from.map_or(true, |from| note.block_num >= from)
+            && filter.block_num_to.map_or(true, |to| note.block_num <= to)
+            && filter.sender.map_or(true, |sender| note.note_created.sender == sender)
+            && filter.tag.map_or(true, |tag| note.note_created.tag == tag)
+            && filter
+                .note_type
+                .map_or(true, |note_type| note.note_created.note_type == note_type)
+            && filter.recipient.map_or(true, |recipient| self.recipient == Some(recipient))
+            && filter
+                .script_root
+                .map_or(true, |script_root| self.script_root == Some(script_root))
+            && filter.faucet_id.map_or(true, |faucet_id| self.faucet_ids.contains(&faucet_id))
+    }
+
+    fn matches_tags_or_senders(&self, tags: &[u32], senders: &[AccountId]) -> bool {
+        tags.contains(&self.note.note_created.tag)
+            || senders.contains(&self.note.note_created.sender)
+    }
+}
+
+/// State of an account after a block.
+#[derive(Clone)]
+struct AccountHistoryEntry {
+    account_hash: RpoDigest,
+    details: Option<Account>,
+    /// Delta applied to the account in the block, [None] if the account was updated more than
+    /// once in the block since the delta would only cover the last of the updates.
+    delta: Option<AccountDelta>,
+    /// Whether the details and delta of the entry were pruned.
+    pruned: bool,
+}
+
+/// Data of a block validated against the [Tables], ready to be committed.
+struct StagedBlock {
+    block_header: BlockHeader,
+    block_data: Vec<u8>,
+    notes: Vec<NoteRecord>,
+    nullifiers: Vec<Nullifier>,
+    accounts: BTreeMap<AccountId, (AccountInfo, AccountHistoryEntry)>,
+    transactions: Vec<TransactionInclusion>,
+}
+
+impl Tables {
+    fn block_header(&self, block_num: Option<BlockNumber>) -> Option<BlockHeader> {
+        match block_num {
+            Some(block_num) => self.block_headers.get(&block_num).copied(),
+            None => self.block_headers.values().next_back().copied(),
+        }
+    }
+
+    /// Returns the block headers, nullifiers and latest account hashes added after `block_num`,
+    /// or all of them if [None].
+    fn state_since(&self, block_num: Option<BlockNumber>) -> StateReplay {
+        let is_after = |other: BlockNumber| block_num.map_or(true, |block_num| other > block_num);
+
+        let block_headers = self
+            .block_headers
+            .values()
+            .filter(|block_header| is_after(block_header.block_num()))
+            .copied()
+            .collect();
+
+        let mut nullifiers: Vec<_> = self
+            .nullifiers
+            .values()
+            .filter(|(_, nullifier_block_num)| is_after(*nullifier_block_num))
+            .copied()
+            .collect();
+        nullifiers.sort_by_key(|(_, block_num)| *block_num);
+
+        let mut accounts: Vec<_> =
+            self.accounts.values().filter(|info| is_after(info.summary.block_num)).collect();
+        accounts.sort_by_key(|info| info.summary.block_num);
+        let account_hashes = accounts
+            .into_iter()
+            .map(|info| (info.summary.account_id.into(), info.summary.account_hash))
+            .collect();
+
+        StateReplay {
+            block_headers,
+            nullifiers,
+            account_hashes,
+        }
+    }
+
+    fn block_headers_by_block_nums(&self, block_nums: &[BlockNumber]) -> Vec<BlockHeader> {
+        let block_nums: BTreeSet<_> = block_nums.iter().collect();
+
+        block_nums
+            .into_iter()
+            .filter_map(|block_num| self.block_headers.get(block_num).copied())
+            .collect()
+    }
+
+    /// Returns the state of the account after its last update at or before `block_num`, failing
+    /// if that state was pruned.
+    fn account_at_block(
+        &self,
+        account_id: AccountId,
+        block_num: BlockNumber,
+    ) -> Result<Option<AccountInfo>> {
+        let Some(((_, history_block_num), entry)) = self
+            .account_history
+            .range((account_id, 0)..=(account_id, block_num))
+            .next_back()
+        else {
+            return Ok(None);
+        };
+        if entry.pruned {
+            return Err(DatabaseError::AccountStatePruned { account_id, block_num });
+        }
+
+        let Some(info) = self.accounts.get(&account_id) else {
+            return Ok(None);
+        };
+        let mut info = info.clone();
+        info.summary.account_hash = entry.account_hash;
+        info.summary.block_num = *history_block_num;
+        info.details = entry.details.clone();
+
+        Ok(Some(info))
+    }
+
+    /// Derives the metadata of an account from its ID, latest details and history.
+    fn account_metadata(&self, info: &AccountInfo) -> AccountMetadata {
+        let account_id = info.summary.account_id;
+        let id = u64::from(account_id);
+        let mut history = self.account_history.range((id, 0)..=(id, BlockNumber::MAX));
+        let created_block_num =
+            history.next().map_or(info.summary.block_num, |((_, block_num), _)| *block_num);
+
+        AccountMetadata {
+            summary: info.summary.clone(),
+            kind: AccountKind::from(account_id.account_type()),
+            on_chain: account_id.is_on_chain(),
+            created_block_num,
+            num_updates: history.count() as u32 + 1,
+            code_root: info.details.as_ref().map(|account| account.code().root()),
+        }
+    }
+
+    fn nullifiers_by_block_range_page(
+        &self,
+        block_start: BlockNumber,
+        block_end: BlockNumber,
+        nullifier_prefixes: &[u32],
+        page: PageRequest<NullifierSyncKey>,
+    ) -> Page<NullifierInfo, NullifierSyncKey> {
+        let after = page.after.map(|key| (key.block_num, key.nullifier.to_bytes()));
+
+        let mut nullifiers: Vec<_> = self
+            .nullifiers
+            .iter()
+            .filter(|(key, (nullifier, block_num))| {
+                *block_num > block_start
+                    && *block_num <= block_end
+                    && nullifier_prefixes.contains(&get_nullifier_prefix(nullifier))
+                    && after.as_ref().map_or(true, |after| (*block_num, *key) > (after.0, &after.1))
+            })
+            .collect();
+        nullifiers.sort_by_key(|(key, (_, block_num))| (*block_num, *key));
+
+        let nullifiers = nullifiers
+            .into_iter()
+            .take(page.size.saturating_add(1))
+            .map(|(_, (nullifier, block_num))| NullifierInfo {
+                nullifier: *nullifier,
+                block_num: *block_num,
+            })
+            .collect();
+
+        into_page(nullifiers, page.size, NullifierSyncKey::from)
+    }
+
+    /// Validates the data of a new block, the same way the constraints of the SQLite DB do.
+    fn stage_block(
+        &self,
+        block_header: BlockHeader,
+        block_data: Vec<u8>,
+        notes: Vec<Note>,
+        nullifiers: Vec<Nullifier>,
+        account_updates: Vec<AccountUpdateDetails>,
+        transactions: Vec<TransactionHeader>,
+    ) -> Result<StagedBlock> {
+        let block_num = block_header.block_num();
+        if self.block_headers.contains_key(&block_num) {
+            return Err(DatabaseError::DuplicateEntry(format!("block {block_num}")));
+        }
+
+        let mut note_keys = BTreeSet::new();
+        for note in notes.iter() {
+            let key = NoteKey::from(note);
+            if self.notes.contains_key(&key) || !note_keys.insert(key) {
+                return Err(DatabaseError::DuplicateEntry(format!("note {key:?}")));
+            }
+        }
+
+        let mut nullifier_keys = BTreeSet::new();
+        for nullifier in nullifiers.iter() {
+            let key = nullifier.to_bytes();
+            if self.nullifiers.contains_key(&key) || !nullifier_keys.insert(key) {
+                return Err(DatabaseError::DuplicateEntry(format!("nullifier {nullifier}")));
+            }
+        }
+
+        let mut accounts: BTreeMap<AccountId, (AccountInfo, AccountHistoryEntry)> = BTreeMap::new();
+        for update in account_updates {
+            let account_id: AccountId = update.account_id.into();
+            let details = match &update.details {
+                None => None,
+                Some(AccountDetails::Full(account)) => {
+                    if account.hash() != update.final_state_hash {
+                        return Err(DatabaseError::ApplyBlockFailedAccountHashesMismatch {
+                            calculated: account.hash(),
+                            expected: update.final_state_hash,
+                        });
+                    }
+
+                    Some(account.clone())
+                },
+                Some(AccountDetails::Delta(delta)) => {
+                    let current = accounts
+                        .get(&account_id)
+                        .map(|(info, _)| info)
+                        .or_else(|| self.accounts.get(&account_id))
+                        .ok_or(DatabaseError::AccountNotFoundInDb(account_id))?;
+                    let mut account = current
+                        .details
+                        .clone()
+                        .ok_or(DatabaseError::AccountNotOnChain(account_id))?;
+
+                    account.apply_delta(delta)?;
+
+                    if account.hash() != update.final_state_hash {
+                        return Err(DatabaseError::ApplyBlockFailedAccountHashesMismatch {
+                            calculated: account.hash(),
+                            expected: update.final_state_hash,
+                        });
+                    }
+
+                    Some(account)
+                },
+            };
+
+            let delta = match update.details {
+                Some(AccountDetails::Delta(delta)) if !accounts.contains_key(&account_id) => {
+                    Some(delta)
+                },
+                _ => None,
+            };
+
+            let info = AccountInfo {
+                summary: AccountSummary {
+                    account_id: update.account_id,
+                    account_hash: update.final_state_hash,
+                    block_num,
+                },
+                details: details.clone(),
+            };
+            let entry = AccountHistoryEntry {
+                account_hash: update.final_state_hash,
+                details,
+                delta,
+                pruned: false,
+            };
+            accounts.insert(account_id, (info, entry));
+        }
+
+        let existing_transactions: BTreeSet<_> = self
+            .transactions
+            .values()
+            .map(|inclusion| inclusion.transaction_id.inner())
+            .collect();
+        let mut transaction_ids = BTreeSet::new();
+        let transactions = transactions
+            .iter()
+            .enumerate()
+            .map(|(position, header)| {
+                let id = header.id.inner();
+                if existing_transactions.contains(&id) || !transaction_ids.insert(id) {
+                    return Err(DatabaseError::DuplicateEntry(format!("transaction {id}")));
+                }
+
+                Ok(TransactionInclusion {
+                    transaction_id: header.id,
+                    block_num,
+                    position: position as u32,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(StagedBlock {
+            block_header,
+            block_data,
+            notes: notes.into_iter().map(NoteRecord::new).collect(),
+            nullifiers,
+            accounts,
+            transactions,
+        })
+    }
+
+    fn commit_block(&mut self, block: StagedBlock) {
+        let block_num = block.block_header.block_num();
+
+        self.block_headers.insert(block_num, block.block_header);
+        self.blocks.insert(block_num, (block.block_header.hash(), block.block_data));
+
+        for (account_id, (info, entry)) in block.accounts {
+            self.accounts.insert(account_id, info);
+            self.account_history.insert((account_id, block_num), entry);
+        }
+
+        for nullifier in block.nullifiers.iter() {
+            let key = nullifier.to_bytes();
+
+            // notes added before the nullifier are consumed by it
+            for note_key in self.notes_by_nullifier.get(&key).into_iter().flatten() {
+                let record = self.notes.get_mut(note_key).expect("indexed note must exist");
+                record.consumed_block_num.get_or_insert(block_num);
+            }
+
+            self.nullifiers.insert(key, (*nullifier, block_num));
+        }
+
+        // notes are consumed by the nullifiers added before the notes, including the nullifiers
+        // of the block
+        for mut record in block.notes {
+            let note_key = NoteKey::from(&record.note);
+            if let Some(nullifier) = record.nullifier {
+                let key = nullifier.to_bytes();
+                record.consumed_block_num =
+                    self.nullifiers.get(&key).map(|(_, nullifier_block_num)| *nullifier_block_num);
+                self.notes_by_nullifier.entry(key).or_default().insert(note_key);
+            }
+
+            self.notes.insert(note_key, record);
+        }
+
+        for inclusion in block.transactions {
+            self.transactions.insert((block_num, inclusion.position), inclusion);
+        }
+    }
+
+    /// Deletes the data of all the blocks after `block_num`, returns the number of deleted or
+    /// restored entries.
+    fn rollback_to(&mut self, block_num: BlockNumber) -> Result<usize> {
+        let mut count = 0;
+
+        let restored_accounts = self
+            .accounts
+            .iter()
+            .filter(|(_, info)| info.summary.block_num > block_num)
+            .map(|(account_id, _)| {
+                Ok((*account_id, self.account_at_block(*account_id, block_num)?))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        for (account_id, info) in restored_accounts {
+            match info {
+                Some(info) => self.accounts.insert(account_id, info),
+                None => self.accounts.remove(&account_id),
+            };
+            count += 1;
+        }
+
+        count += retain(&mut self.account_history, |(_, history_block_num), _| {
+            *history_block_num <= block_num
+        });
+        count += retain(&mut self.transactions, |(tx_block_num, _), _| *tx_block_num <= block_num);
+        count += retain(&mut self.notes, |key, _| key.block_num <= block_num);
+        count += retain(&mut self.blocks, |block_num_key, _| *block_num_key <= block_num);
+        count += retain(&mut self.block_headers, |block_num_key, _| *block_num_key <= block_num);
+
+        self.notes_by_nullifier.retain(|_, note_keys| {
+            note_keys.retain(|note_key| note_key.block_num <= block_num);
+            !note_keys.is_empty()
+        });
+
+        // the notes consumed by the deleted nullifiers are unconsumed
+        let mut deleted_nullifiers = vec![];
+        count += retain(&mut self.nullifiers, |key, (_, nullifier_block_num)| {
+            let is_kept = *nullifier_block_num <= block_num;
+            if !is_kept {
+                deleted_nullifiers.push(key.clone());
+            }
+            is_kept
+        });
+        for key in deleted_nullifiers {
+            for note_key in self.notes_by_nullifier.get(&key).into_iter().flatten() {
+                let record = self.notes.get_mut(note_key).expect("indexed note must exist");
+                if record.consumed_block_num.take().is_some() {
+                    count += 1;
+                }
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Prunes the notes and account states up to the `target`, returns the number of pruned
+    /// entries.
+    fn prune(&mut self, target: &PruningTarget) -> usize {
+        let mut count = 0;
+
+        for record in self.notes.values_mut() {
+            let is_old = target.notes.is_some_and(|notes| record.note.block_num <= notes);
+            let is_consumed = record.consumed_block_num.is_some_and(|consumed| {
+                target.consumed_notes.is_some_and(|consumed_notes| consumed <= consumed_notes)
+            });
+            if !record.pruned && (is_old || is_consumed) {
+                record.note.merkle_path = MerklePath::new(Vec::new());
+                record.note.note_created.details = None;
+                record.pruned = true;
+                count += 1;
+            }
+        }
+
+        let Some(account_history) = target.account_history else {
+            return count;
+        };
+        // the latest state of each account at or before the target is kept
+        let mut latest: Option<(AccountId, &mut AccountHistoryEntry)> = None;
+        for ((account_id, _), entry) in self
+            .account_history
+            .iter_mut()
+            .filter(|((_, block_num), _)| *block_num <= account_history)
+        {
+            if let Some((previous_id, previous)) = latest.replace((*account_id, entry)) {
+                if previous_id == *account_id && !previous.pruned {
+                    previous.details = None;
+                    previous.delta = None;
+                    previous.pruned = true;
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+}
+
+/// Retains the entries of `map` matching `f`, returns the number of removed entries.
+fn retain<K: Ord, V>(map: &mut BTreeMap<K, V>, mut f: impl FnMut(&K, &mut V) -> bool) -> usize {
+    let len = map.len();
+    map.retain(|key, value| f(key, value));
+
+    len - map.len()
+}
@@ -2,8 +2,9 @@ use once_cell::sync::Lazy;
 use rusqlite_migration::{Migrations, M};
 
 pub static MIGRATIONS: Lazy<Migrations> = Lazy::new(|| {
-    Migrations::new(vec![M::up(
-        "
+    Migrations::new(vec![
+        M::up(
+            "
         CREATE TABLE
             block_headers
         (
@@ -62,7 +63,154 @@ pub static MIGRATIONS: Lazy<Migrations> = Lazy::new(|| {
             CONSTRAINT nullifiers_block_num_is_u32 CHECK (block_num BETWEEN 0 AND 0xFFFFFFFF)
         ) STRICT, WITHOUT ROWID;
         ",
-    )])
+        ),
+        M::up(
+            "
+        CREATE TABLE
+            blocks
+        (
+            block_num INTEGER NOT NULL,
+            block_hash BLOB NOT NULL,
+            block BLOB NOT NULL,
+
+            PRIMARY KEY (block_num),
+            CONSTRAINT fk_block_num FOREIGN KEY (block_num) REFERENCES block_headers (block_num),
+            CONSTRAINT blocks_block_num_is_u32 CHECK (block_num BETWEEN 0 AND 0xFFFFFFFF),
+            CONSTRAINT blocks_block_hash_is_digest CHECK (length(block_hash) = 32)
+        ) STRICT, WITHOUT ROWID;
+
+        CREATE UNIQUE INDEX idx_blocks_block_hash ON blocks(block_hash);
+        ",
+        ),
+        M::up(
+            "
+        CREATE TABLE
+            transactions
+        (
+            transaction_id BLOB NOT NULL,
+            block_num INTEGER NOT NULL,
+            position INTEGER NOT NULL,  -- Index of transaction in block, starting from 0
+            account_id INTEGER NOT NULL,
+            initial_account_hash BLOB NOT NULL,
+            final_account_hash BLOB NOT NULL,
+            input_notes BLOB NOT NULL,  -- Serialized list of nullifiers of the consumed notes
+            output_notes BLOB NOT NULL, -- Serialized list of IDs of the created notes
+
+            PRIMARY KEY (transaction_id),
+            CONSTRAINT fk_block_num FOREIGN KEY (block_num) REFERENCES block_headers (block_num),
+            CONSTRAINT transactions_transaction_id_is_digest CHECK (length(transaction_id) = 32),
+            CONSTRAINT transactions_block_num_is_u32 CHECK (block_num BETWEEN 0 AND 0xFFFFFFFF),
+            CONSTRAINT transactions_position_is_u32 CHECK (position BETWEEN 0 AND 0xFFFFFFFF)
+        ) STRICT, WITHOUT ROWID;
+
+        CREATE INDEX idx_transactions_block_num ON transactions(block_num);
+        ",
+        ),
+        M::up(
+            "
+        CREATE TABLE
+            account_history
+        (
+            account_id INTEGER NOT NULL,
+            block_num INTEGER NOT NULL,
+            account_hash BLOB NOT NULL,
+            details BLOB,   -- Full account state after the block, for on-chain accounts
+            delta BLOB,     -- Delta applied in the block, NULL if the full account state was provided
+
+            PRIMARY KEY (account_id, block_num),
+            CONSTRAINT fk_block_num FOREIGN KEY (block_num) REFERENCES block_headers (block_num),
+            CONSTRAINT account_history_block_num_is_u32 CHECK (block_num BETWEEN 0 AND 0xFFFFFFFF)
+        ) STRICT, WITHOUT ROWID;
+
+        INSERT INTO
+            account_history (account_id, block_num, account_hash, details)
+        SELECT
+            account_id, block_num, account_hash, details
+        FROM
+            accounts;
+        ",
+        ),
+        M::up(
+            "
+        CREATE INDEX idx_account_history_block_num ON account_history(block_num);
+        CREATE INDEX idx_nullifiers_block_num ON nullifiers(block_num);
+        ",
+        ),
+        M::up(
+            "
+        CREATE INDEX idx_nullifiers_prefix_block_num ON nullifiers(nullifier_prefix, block_num);
+        ",
+        ),
+        M::up(
+            "
+        -- The nullifiers of the existing notes are indexed when the database is opened.
+        ALTER TABLE notes ADD COLUMN nullifier BLOB;
+        ALTER TABLE notes ADD COLUMN consumed_block_num INTEGER
+            CONSTRAINT notes_consumed_block_num_is_u32 CHECK (consumed_block_num BETWEEN 0 AND 0xFFFFFFFF);
+
+        CREATE INDEX idx_notes_nullifier ON notes(nullifier);
+        ",
+        ),
+        M::up(
+            "
+        -- The contents of the existing notes are indexed when the database is opened.
+        ALTER TABLE notes ADD COLUMN recipient BLOB;
+        ALTER TABLE notes ADD COLUMN script_root BLOB;
+
+        CREATE INDEX idx_notes_recipient ON notes(recipient);
+        CREATE INDEX idx_notes_script_root ON notes(script_root);
+
+        CREATE TABLE
+            note_assets
+        (
+            block_num INTEGER NOT NULL,
+            batch_index INTEGER NOT NULL,
+            note_index INTEGER NOT NULL,
+            faucet_id INTEGER NOT NULL,
+
+            PRIMARY KEY (faucet_id, block_num, batch_index, note_index),
+            CONSTRAINT fk_note FOREIGN KEY (block_num, batch_index, note_index) REFERENCES notes (block_num, batch_index, note_index)
+        ) STRICT, WITHOUT ROWID;
+        ",
+        ),
+        M::up(
+            "
+        ALTER TABLE notes ADD COLUMN pruned INTEGER NOT NULL DEFAULT 0
+            CONSTRAINT notes_pruned_is_bool CHECK (pruned IN (0, 1));
+        ALTER TABLE account_history ADD COLUMN pruned INTEGER NOT NULL DEFAULT 0
+            CONSTRAINT account_history_pruned_is_bool CHECK (pruned IN (0, 1));
+
+        CREATE INDEX idx_notes_consumed_block_num ON notes(consumed_block_num);
+        ",
+        ),
+        M::up(
+            "
+        -- The metadata of the existing accounts is indexed when the database is opened.
+        ALTER TABLE accounts ADD COLUMN account_type INTEGER
+            CONSTRAINT accounts_account_type_in_enum CHECK (account_type BETWEEN 0 AND 2);  -- 0-Wallet, 1-FungibleFaucet, 2-NonFungibleFaucet
+        ALTER TABLE accounts ADD COLUMN on_chain INTEGER
+            CONSTRAINT accounts_on_chain_is_bool CHECK (on_chain IN (0, 1));
+        ALTER TABLE accounts ADD COLUMN created_block_num INTEGER
+            CONSTRAINT accounts_created_block_num_is_u32 CHECK (created_block_num BETWEEN 0 AND 0xFFFFFFFF);
+        ALTER TABLE accounts ADD COLUMN num_updates INTEGER
+            CONSTRAINT accounts_num_updates_is_u32 CHECK (num_updates BETWEEN 0 AND 0xFFFFFFFF);
+        ALTER TABLE accounts ADD COLUMN code_root BLOB
+            CONSTRAINT accounts_code_root_is_digest CHECK (length(code_root) = 32);
+
+        CREATE INDEX idx_accounts_account_type ON accounts(account_type);
+        CREATE INDEX idx_accounts_created_block_num ON accounts(created_block_num);
+        CREATE INDEX idx_accounts_code_root ON accounts(code_root);
+        ",
+        ),
+        M::up(
+            "
+        -- The state of the on-chain accounts is kept in the account history only, as full
+        -- checkpoints and the deltas applied after them. The existing records all hold the full
+        -- state, so they are all checkpoints.
+        ALTER TABLE accounts DROP COLUMN details;
+        ",
+        ),
+    ])
 });
 
 #[test]
@@ -1,35 +1,67 @@
-use std::fs::{self, create_dir_all};
+use std::{
+    fs::{self, create_dir_all},
+    sync::Arc,
+};
 
+use async_trait::async_trait;
 use deadpool_sqlite::{Config as SqliteConfig, Hook, HookError, Pool, Runtime};
-use miden_node_proto::domain::accounts::{AccountInfo, AccountSummary, AccountUpdateDetails};
+use miden_node_proto::{
+    convert,
+    domain::{
+        accounts::{AccountInfo, AccountSummary, AccountUpdateDetails},
+        transactions::TransactionHeader,
+    },
+    generated::requests::ApplyBlockRequest,
+};
 use miden_objects::{
+    accounts::AccountType,
     block::BlockNoteTree,
-    crypto::{hash::rpo::RpoDigest, merkle::MerklePath, utils::Deserializable},
+    crypto::{
+        hash::rpo::RpoDigest,
+        merkle::MerklePath,
+        utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    },
     notes::{NoteId, NoteType, Nullifier},
+    transaction::{AccountDetails, TransactionId},
     BlockHeader, GENESIS_BLOCK,
 };
+use prost::Message;
 use rusqlite::vtab::array;
 use tokio::sync::oneshot;
 use tracing::{info, info_span, instrument};
 
 use crate::{
+    archive::StateArchive,
     config::StoreConfig,
-    errors::{DatabaseError, DatabaseSetupError, GenesisError, StateSyncError},
+    errors::{ArchiveError, DatabaseError, DatabaseSetupError, GenesisError, StateSyncError},
     genesis::GenesisState,
     types::{AccountId, BlockNumber},
     COMPONENT,
 };
 
+mod cache;
+mod memory;
 mod migrations;
 mod sql;
 
 #[cfg(test)]
 mod tests;
 
+use cache::AccountCache;
+pub use memory::MemoryDb;
+
 pub type Result<T, E = DatabaseError> = std::result::Result<T, E>;
 
+/// Maximum number of on-chain account states kept in the cache of the [Db].
+const ACCOUNT_CACHE_CAPACITY: usize = 1024;
+
 pub struct Db {
     pool: Pool,
+    /// Latest states of the recently used on-chain accounts, which are otherwise rebuilt from
+    /// their checkpoints and deltas.
+    account_cache: Arc<AccountCache>,
+    /// Number of deltas stored between two checkpoints of an on-chain account.
+    account_checkpoint_interval: u32,
 }
 
 #[derive(Debug, PartialEq)]
@@ -61,9 +93,116 @@ impl NoteCreated {
 pub struct Note {
     pub block_num: BlockNumber,
     pub note_created: NoteCreated,
+    /// Authentication path of the note in the note tree of its block, empty once pruned.
     pub merkle_path: MerklePath,
 }
 
+impl Note {
+    /// Returns true if the Merkle path and details of the note were pruned.
+    pub fn is_pruned(&self) -> bool {
+        self.merkle_path.depth() == 0
+    }
+}
+
+/// Kind of an account, as encoded in its ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountKind {
+    Wallet = 0,
+    FungibleFaucet = 1,
+    NonFungibleFaucet = 2,
+}
+
+impl From<AccountType> for AccountKind {
+    fn from(account_type: AccountType) -> Self {
+        match account_type {
+            AccountType::RegularAccountImmutableCode | AccountType::RegularAccountUpdatableCode => {
+                Self::Wallet
+            },
+            AccountType::FungibleFaucet => Self::FungibleFaucet,
+            AccountType::NonFungibleFaucet => Self::NonFungibleFaucet,
+        }
+    }
+}
+
+impl TryFrom<u32> for AccountKind {
+    type Error = u32;
+
+    fn try_from(value: u32) -> std::result::Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Wallet),
+            1 => Ok(Self::FungibleFaucet),
+            2 => Ok(Self::NonFungibleFaucet),
+            value => Err(value),
+        }
+    }
+}
+
+/// Latest state of an account together with the metadata indexed for account queries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountMetadata {
+    pub summary: AccountSummary,
+    pub kind: AccountKind,
+    /// Storage mode of the account, as encoded in its ID.
+    pub on_chain: bool,
+    /// Block of the first recorded state of the account.
+    pub created_block_num: BlockNumber,
+    /// Number of blocks which updated the account, including its creation.
+    pub num_updates: u32,
+    /// Root of the account code, only known for the on-chain accounts with details.
+    pub code_root: Option<RpoDigest>,
+}
+
+/// Inclusion and consumption status of a note.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoteStatus {
+    pub note_id: RpoDigest,
+    /// Block in which the note was created.
+    pub block_num: BlockNumber,
+    /// Absolute position of the note in the note tree of the block.
+    pub note_index: u32,
+    pub merkle_path: MerklePath,
+    /// Block in which the note was consumed, [None] if the note isn't consumed yet or if its
+    /// nullifier is unknown.
+    pub consumed_block_num: Option<BlockNumber>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountHistoryRecord {
+    pub block_num: BlockNumber,
+    /// Hash of the account state after the block.
+    pub account_hash: RpoDigest,
+    /// Update applied to the account in the block.
+    pub details: AccountDetails,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionInclusion {
+    pub transaction_id: TransactionId,
+    pub block_num: BlockNumber,
+    /// Position of the transaction within the block, starting from 0.
+    pub position: u32,
+}
+
+/// Header of a block together with the number of items it added to the chain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockSummary {
+    pub block_header: BlockHeader,
+    /// Number of accounts updated by the block, i.e. of account history records of the block, an
+    /// account updated more than once in the block is only counted once.
+    pub num_account_updates: u32,
+    pub num_nullifiers: u32,
+    pub num_notes: u32,
+}
+
+/// Data added to the DB after a given block, or all the data of the chain, from which the
+/// in-memory structures of the state are built.
+#[derive(Debug, PartialEq)]
+pub struct StateReplay {
+    pub block_headers: Vec<BlockHeader>,
+    pub nullifiers: Vec<(Nullifier, BlockNumber)>,
+    pub account_hashes: Vec<(AccountId, RpoDigest)>,
+}
+
 #[derive(Debug, PartialEq)]
 pub struct StateSyncUpdate {
     pub notes: Vec<Note>,
@@ -73,12 +212,446 @@ pub struct StateSyncUpdate {
     pub nullifiers: Vec<NullifierInfo>,
 }
 
+#[derive(Debug, PartialEq)]
+pub struct NoteSyncUpdate {
+    pub notes: Vec<Note>,
+    /// Headers of the blocks containing `notes`, ordered by block number.
+    pub block_headers: Vec<BlockHeader>,
+    /// Header of the last block covered by the sync.
+    pub block_header: BlockHeader,
+    pub chain_tip: BlockNumber,
+}
+
+/// Blocks up to which the data is pruned, nothing is pruned for the targets which are not set.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PruningTarget {
+    /// Notes created at or before this block are pruned.
+    pub notes: Option<BlockNumber>,
+    /// Notes consumed at or before this block are pruned.
+    pub consumed_notes: Option<BlockNumber>,
+    /// Account states replaced by a newer state at or before this block are pruned.
+    pub account_history: Option<BlockNumber>,
+}
+
+// LIST QUERIES
+// ================================================================================================
+
+/// Requested page of a list query, whose items are sorted by key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageRequest<K> {
+    /// Key of the last item of the previous page, the first page is returned if [None].
+    pub after: Option<K>,
+    /// Maximum number of items in the page.
+    pub size: usize,
+}
+
+/// Page of the items of a list query.
+#[derive(Debug, PartialEq)]
+pub struct Page<T, K> {
+    pub items: Vec<T>,
+    /// Key of the last item of the page, [None] if this is the last page.
+    pub next: Option<K>,
+}
+
+/// Key by which the notes are sorted in list queries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NoteKey {
+    pub block_num: BlockNumber,
+    pub batch_index: u32,
+    pub note_index: u32,
+}
+
+impl From<&Note> for NoteKey {
+    fn from(note: &Note) -> Self {
+        Self {
+            block_num: note.block_num,
+            batch_index: note.note_created.batch_index,
+            note_index: note.note_created.note_index,
+        }
+    }
+}
+
+impl Serializable for NoteKey {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.block_num);
+        target.write_u32(self.batch_index);
+        target.write_u32(self.note_index);
+    }
+}
+
+impl Deserializable for NoteKey {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            block_num: source.read_u32()?,
+            batch_index: source.read_u32()?,
+            note_index: source.read_u32()?,
+        })
+    }
+}
+
+/// Key by which the nullifiers are sorted in nullifier sync queries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NullifierSyncKey {
+    pub block_num: BlockNumber,
+    pub nullifier: Nullifier,
+}
+
+impl From<&NullifierInfo> for NullifierSyncKey {
+    fn from(info: &NullifierInfo) -> Self {
+        Self {
+            block_num: info.block_num,
+            nullifier: info.nullifier,
+        }
+    }
+}
+
+impl Serializable for NullifierSyncKey {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.block_num);
+        self.nullifier.write_into(target);
+    }
+}
+
+impl Deserializable for NullifierSyncKey {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            block_num: source.read_u32()?,
+            nullifier: Nullifier::read_from(source)?,
+        })
+    }
+}
+
+/// Filters of the nullifiers list query, all the bounds are inclusive.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NullifierFilter {
+    pub block_num_from: Option<BlockNumber>,
+    pub block_num_to: Option<BlockNumber>,
+}
+
+/// Filters of the accounts list query, all the bounds are inclusive.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountFilter {
+    /// Bounds of the block number of the last update of the account.
+    pub block_num_from: Option<BlockNumber>,
+    pub block_num_to: Option<BlockNumber>,
+    pub on_chain: Option<bool>,
+}
+
+/// Filters of the account metadata query, all the bounds are inclusive.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountMetadataFilter {
+    pub kind: Option<AccountKind>,
+    /// Storage mode of the account, as encoded in its ID.
+    pub on_chain: Option<bool>,
+    /// Bounds of the block number of the creation of the account.
+    pub created_block_num_from: Option<BlockNumber>,
+    pub created_block_num_to: Option<BlockNumber>,
+    /// Only the on-chain accounts with known details are indexed by code root.
+    pub code_root: Option<RpoDigest>,
+}
+
+/// Filters of the notes list query, all the bounds are inclusive.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NoteFilter {
+    pub block_num_from: Option<BlockNumber>,
+    pub block_num_to: Option<BlockNumber>,
+    pub sender: Option<AccountId>,
+    pub tag: Option<u32>,
+    pub note_type: Option<NoteType>,
+    /// Only the notes with public details are indexed by recipient, script root and faucet.
+    pub recipient: Option<RpoDigest>,
+    pub script_root: Option<RpoDigest>,
+    /// Faucet of one of the fungible assets of the note.
+    pub faucet_id: Option<AccountId>,
+}
+
+/// Page of the items of a list query, truncated to `page_size` items.
+///
+/// `items` must hold up to `page_size + 1` items, the extra item only signals that there is a next
+/// page.
+fn into_page<T, K>(mut items: Vec<T>, page_size: usize, key: impl Fn(&T) -> K) -> Page<T, K> {
+    let next = if items.len() > page_size {
+        items.truncate(page_size);
+        items.last().map(key)
+    } else {
+        None
+    };
+
+    Page { items, next }
+}
+
+// STORAGE
+// ================================================================================================
+
+/// Persistence of the data of the store.
+///
+/// [Db] is the default implementation, backed by SQLite. [MemoryDb] keeps all the data in memory,
+/// which is useful for tests and short-lived nodes.
+#[async_trait]
+pub trait Storage: Send + Sync + 'static {
+    /// Loads all the nullifiers.
+    async fn select_nullifiers(&self) -> Result<Vec<(Nullifier, BlockNumber)>>;
+
+    /// Loads all the notes.
+    async fn select_notes(&self) -> Result<Vec<Note>>;
+
+    /// Loads the notes created up to `block_num` which were not consumed as of `block_num`,
+    /// skipping pruned notes.
+    async fn select_unconsumed_notes(&self, block_num: BlockNumber) -> Result<Vec<Note>>;
+
+    /// Loads all the accounts.
+    async fn select_accounts(&self) -> Result<Vec<AccountInfo>>;
+
+    /// Loads a page of the nullifiers matching the `filter`, sorted by nullifier.
+    async fn select_nullifiers_page(
+        &self,
+        filter: NullifierFilter,
+        page: PageRequest<Nullifier>,
+    ) -> Result<Page<(Nullifier, BlockNumber), Nullifier>>;
+
+    /// Loads a page of the nullifiers created in the block range `(block_start, block_end]` which
+    /// match the `nullifier_prefixes`, sorted by block number and nullifier.
+    async fn select_nullifiers_by_block_range_page(
+        &self,
+        block_start: BlockNumber,
+        block_end: BlockNumber,
+        nullifier_prefixes: Vec<u32>,
+        page: PageRequest<NullifierSyncKey>,
+    ) -> Result<Page<NullifierInfo, NullifierSyncKey>>;
+
+    /// Loads a page of the accounts matching the `filter`, sorted by account ID.
+    async fn select_accounts_page(
+        &self,
+        filter: AccountFilter,
+        page: PageRequest<AccountId>,
+    ) -> Result<Page<AccountInfo, AccountId>>;
+
+    /// Loads a page of the metadata of the accounts matching the `filter`, sorted by account ID.
+    async fn select_account_metadata_page(
+        &self,
+        filter: AccountMetadataFilter,
+        page: PageRequest<AccountId>,
+    ) -> Result<Page<AccountMetadata, AccountId>>;
+
+    /// Loads a page of the notes matching the `filter`, sorted by block number and index in the
+    /// block.
+    async fn select_notes_page(
+        &self,
+        filter: NoteFilter,
+        page: PageRequest<NoteKey>,
+    ) -> Result<Page<Note, NoteKey>>;
+
+    /// Search for a [BlockHeader] by its `block_num`.
+    ///
+    /// When `block_number` is [None], the latest block header is returned.
+    async fn select_block_header_by_block_num(
+        &self,
+        block_number: Option<BlockNumber>,
+    ) -> Result<Option<BlockHeader>>;
+
+    /// Loads the data of the block with the given `block_num`.
+    async fn select_block_by_num(&self, block_num: BlockNumber) -> Result<Option<Vec<u8>>>;
+
+    /// Loads the data of the block with the given header hash.
+    async fn select_block_by_hash(&self, block_hash: RpoDigest) -> Result<Option<Vec<u8>>>;
+
+    /// Loads all the block headers.
+    async fn select_block_headers(&self) -> Result<Vec<BlockHeader>>;
+
+    /// Loads the block headers with the given block numbers, sorted by block number.
+    async fn select_block_headers_by_block_nums(
+        &self,
+        block_nums: Vec<BlockNumber>,
+    ) -> Result<Vec<BlockHeader>>;
+
+    /// Loads at most `limit` block headers starting at `block_num`, with the number of items
+    /// added by each block.
+    async fn select_block_summaries(
+        &self,
+        block_num: BlockNumber,
+        limit: usize,
+    ) -> Result<Vec<BlockSummary>>;
+
+    /// Loads all the account hashes.
+    async fn select_account_hashes(&self) -> Result<Vec<(AccountId, RpoDigest)>>;
+
+    /// Loads the hashes of all the account states from the account history.
+    async fn select_account_hash_history(&self)
+        -> Result<Vec<(AccountId, BlockNumber, RpoDigest)>>;
+
+    /// Loads the account updates after `block_num`, with the hash of each account prior to the
+    /// update, [None] if the account was created by the block.
+    async fn select_account_updates_since(
+        &self,
+        block_num: BlockNumber,
+    ) -> Result<Vec<(AccountId, BlockNumber, Option<RpoDigest>)>>;
+
+    /// Loads the block headers, nullifiers and account hashes added after `block_num`.
+    async fn select_state_since(&self, block_num: BlockNumber) -> Result<StateReplay>;
+
+    /// Loads public account details.
+    async fn select_account(&self, id: AccountId) -> Result<AccountInfo>;
+
+    /// Loads the latest account details of the given accounts.
+    async fn select_accounts_by_ids(&self, account_ids: Vec<AccountId>)
+        -> Result<Vec<AccountInfo>>;
+
+    /// Loads the state of the given accounts as of the given block.
+    async fn select_accounts_at_block(
+        &self,
+        account_ids: Vec<AccountId>,
+        block_num: BlockNumber,
+    ) -> Result<Vec<AccountInfo>>;
+
+    /// Loads the state of an account as of the given block.
+    async fn select_account_at_block(
+        &self,
+        id: AccountId,
+        block_num: BlockNumber,
+    ) -> Result<AccountInfo>;
+
+    /// Loads the updates of an on-chain account in the block range `(block_start, block_end]`.
+    async fn select_account_history(
+        &self,
+        id: AccountId,
+        block_start: BlockNumber,
+        block_end: BlockNumber,
+    ) -> Result<Vec<AccountHistoryRecord>>;
+
+    /// Loads the data of a state sync starting after `block_num`.
+    async fn get_state_sync(
+        &self,
+        block_num: BlockNumber,
+        account_ids: &[AccountId],
+        note_tag_prefixes: &[u32],
+        nullifier_prefixes: &[u32],
+    ) -> Result<StateSyncUpdate, StateSyncError>;
+
+    /// Loads the data of a note sync covering up to `max_blocks` blocks after `block_num`.
+    async fn get_note_sync(
+        &self,
+        block_num: BlockNumber,
+        note_tag_prefixes: &[u32],
+        account_ids: &[AccountId],
+        max_blocks: usize,
+    ) -> Result<NoteSyncUpdate, StateSyncError>;
+
+    /// Loads all the Note's matching a certain NoteId.
+    async fn select_notes_by_id(&self, note_ids: Vec<NoteId>) -> Result<Vec<Note>>;
+
+    /// Loads the inclusion and consumption status of the notes matching the given IDs.
+    async fn select_note_statuses_by_id(&self, note_ids: Vec<NoteId>) -> Result<Vec<NoteStatus>>;
+
+    /// Loads the inclusion records of the transactions matching the given IDs.
+    async fn select_transactions_by_id(
+        &self,
+        transaction_ids: Vec<TransactionId>,
+    ) -> Result<Vec<TransactionInclusion>>;
+
+    /// Inserts the data of a new block.
+    ///
+    /// The data must not be visible to readers before `allow_acquire` is signaled and
+    /// `acquire_done` is received, and the block must be discarded if `acquire_done` is closed.
+    /// This synchronizes writes to the storage with writes to the in-memory trees. Further details
+    /// available on [super::state::State::apply_block].
+    #[allow(clippy::too_many_arguments)]
+    async fn apply_block(
+        &self,
+        allow_acquire: oneshot::Sender<()>,
+        acquire_done: oneshot::Receiver<()>,
+        block_header: BlockHeader,
+        block_data: Vec<u8>,
+        notes: Vec<Note>,
+        nullifiers: Vec<Nullifier>,
+        accounts: Vec<AccountUpdateDetails>,
+        transactions: Vec<TransactionHeader>,
+    ) -> Result<()>;
+
+    /// Deletes the data of all the blocks after `block_num`, restoring the accounts to their
+    /// state at that block.
+    ///
+    /// The data of the rolled back chain is sent to `rolled_back` before the rollback is
+    /// committed, the rollback must only be committed once `commit` is received and discarded if
+    /// `commit` is closed. This lets the caller check the data before it becomes visible, see
+    /// [super::state::State::rollback_to].
+    async fn rollback_to(
+        &self,
+        block_num: BlockNumber,
+        rolled_back: oneshot::Sender<StateReplay>,
+        commit: oneshot::Receiver<()>,
+    ) -> Result<usize>;
+
+    /// Prunes the Merkle paths and details of old notes and the old account states up to the
+    /// `target`, returning the number of pruned items.
+    async fn prune(&self, target: PruningTarget) -> Result<usize>;
+}
+
 impl Db {
     /// Open a connection to the DB, apply any pending migrations, and ensure that the genesis block
     /// is as expected and present in the database.
     // TODO: This span is logged in a root span, we should connect it to the parent one.
     #[instrument(target = "miden-store", skip_all)]
     pub async fn setup(config: StoreConfig) -> Result<Self, DatabaseSetupError> {
+        let db = Self::connect(&config).await?;
+        db.ensure_genesis_block(&config.genesis_filepath.as_path().to_string_lossy())
+            .await?;
+
+        Ok(db)
+    }
+
+    /// Open a connection to an empty DB, apply the migrations, and import the state of the
+    /// `archive` in place of the genesis block.
+    ///
+    /// The first block header of the archive must match the genesis block of the genesis file. The
+    /// Merkle structures are not checked here, this is done when the state is loaded.
+    #[instrument(target = "miden-store", skip_all)]
+    pub async fn import(
+        config: StoreConfig,
+        archive: StateArchive,
+    ) -> Result<Self, DatabaseSetupError> {
+        let db = Self::connect(&config).await?;
+
+        if db.select_block_header_by_block_num(None).await?.is_some() {
+            return Err(DatabaseSetupError::DatabaseNotEmpty);
+        }
+
+        let (expected_genesis_header, ..) =
+            read_genesis_block(&config.genesis_filepath.as_path().to_string_lossy())?;
+        let (block_headers, accounts, notes, nullifiers) = archive.into_parts();
+        let archive_genesis_header = block_headers.first().ok_or(ArchiveError::EmptyArchive)?;
+        if expected_genesis_header != *archive_genesis_header {
+            return Err(GenesisError::GenesisBlockHeaderMismatch {
+                expected_genesis_header: Box::new(expected_genesis_header),
+                block_header_in_store: Box::new(*archive_genesis_header),
+            }
+            .into());
+        }
+
+        db.pool
+            .get()
+            .await
+            .map_err(DatabaseError::MissingDbConnection)?
+            .interact(move |conn| -> Result<usize> {
+                let transaction = conn.transaction()?;
+                let count = sql::import_state(
+                    &transaction,
+                    &block_headers,
+                    &accounts,
+                    &notes,
+                    &nullifiers,
+                )?;
+                transaction.commit()?;
+
+                Ok(count)
+            })
+            .await
+            .map_err(|err| DatabaseError::InteractError(format!("Import task failed: {err}")))??;
+
+        Ok(db)
+    }
+
+    /// Open a connection to the DB and apply any pending migrations.
+    async fn connect(config: &StoreConfig) -> Result<Self, DatabaseSetupError> {
         info!(target: COMPONENT, %config, "Connecting to the database");
 
         if let Some(p) = config.database_filepath.parent() {
@@ -103,123 +676,498 @@ impl Db {
                             // (see [State::apply_block])
                             conn.execute("PRAGMA journal_mode = WAL;", ())?;
 
-                            // Enable foreign key checks.
-                            conn.execute("PRAGMA foreign_keys = ON;", ())
-                        })
-                        .await
-                        .map_err(|e| {
-                            HookError::Message(format!("Loading carray module failed: {e}"))
-                        })?;
+                            // Enable foreign key checks.
+                            conn.execute("PRAGMA foreign_keys = ON;", ())
+                        })
+                        .await
+                        .map_err(|e| {
+                            HookError::Message(format!("Loading carray module failed: {e}"))
+                        })?;
+
+                    Ok(())
+                })
+            }))
+            .build()?;
+
+        info!(
+            target: COMPONENT,
+            sqlite = format!("{}", config.database_filepath.display()),
+            "Connected to the database"
+        );
+
+        let conn = pool.get().await.map_err(DatabaseError::MissingDbConnection)?;
+
+        conn.interact(|conn| migrations::MIGRATIONS.to_latest(conn))
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!("Migration task failed: {err}"))
+            })??;
+
+        conn.interact(|conn| -> Result<usize> {
+            let transaction = conn.transaction()?;
+            let count = sql::index_account_metadata(&transaction)?;
+            transaction.commit()?;
+
+            Ok(count)
+        })
+        .await
+        .map_err(|err| {
+            DatabaseError::InteractError(format!("Index account metadata task failed: {err}"))
+        })??;
+
+        conn.interact(|conn| -> Result<usize> {
+            let transaction = conn.transaction()?;
+            let count = sql::index_note_details(&transaction)?;
+            transaction.commit()?;
+
+            Ok(count)
+        })
+        .await
+        .map_err(|err| {
+            DatabaseError::InteractError(format!("Index note details task failed: {err}"))
+        })??;
+
+        Ok(Db {
+            pool,
+            account_cache: Arc::new(AccountCache::new(ACCOUNT_CACHE_CAPACITY)),
+            account_checkpoint_interval: config.account_checkpoint_interval,
+        })
+    }
+
+    // HELPERS
+    // ---------------------------------------------------------------------------------------------
+
+    /// If the database is empty, generates and stores the genesis block. Otherwise, it ensures that the
+    /// genesis block in the database is consistent with the genesis block data in the genesis JSON
+    /// file.
+    #[instrument(target = "miden-store", skip_all, err)]
+    async fn ensure_genesis_block(&self, genesis_filepath: &str) -> Result<(), GenesisError> {
+        let (expected_genesis_header, block_data, accounts) = read_genesis_block(genesis_filepath)?;
+        let account_cache = self.account_cache.clone();
+        let checkpoint_interval = self.account_checkpoint_interval;
+
+        let maybe_block_header_in_store = self
+            .select_block_header_by_block_num(Some(GENESIS_BLOCK))
+            .await
+            .map_err(|err| GenesisError::SelectBlockHeaderByBlockNumError(err.into()))?;
+
+        match maybe_block_header_in_store {
+            Some(block_header_in_store) => {
+                // ensure that expected header is what's also in the store
+                if expected_genesis_header != block_header_in_store {
+                    Err(GenesisError::GenesisBlockHeaderMismatch {
+                        expected_genesis_header: Box::new(expected_genesis_header),
+                        block_header_in_store: Box::new(block_header_in_store),
+                    })?;
+                }
+            },
+            None => {
+                // add genesis header to store
+                self.pool
+                    .get()
+                    .await
+                    .map_err(DatabaseError::MissingDbConnection)?
+                    .interact(move |conn| -> Result<()> {
+                        // TODO: This span is logged in a root span, we should connect it to the parent one.
+                        let span = info_span!(target: COMPONENT, "write_genesis_block_to_db");
+                        let guard = span.enter();
+
+                        let transaction = conn.transaction()?;
+                        sql::apply_block(
+                            &transaction,
+                            &account_cache,
+                            checkpoint_interval,
+                            &expected_genesis_header,
+                            &block_data,
+                            &[],
+                            &[],
+                            &accounts,
+                            &[],
+                        )?;
+
+                        transaction.commit()?;
+
+                        drop(guard);
+                        Ok(())
+                    })
+                    .await
+                    .map_err(|err| GenesisError::ApplyBlockFailed(err.to_string()))??;
+            },
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Storage for Db {
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_nullifiers(&self) -> Result<Vec<(Nullifier, BlockNumber)>> {
+        self.pool.get().await?.interact(sql::select_nullifiers).await.map_err(|err| {
+            DatabaseError::InteractError(format!("Select nullifiers task failed: {err}"))
+        })?
+    }
+
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_notes(&self) -> Result<Vec<Note>> {
+        self.pool.get().await?.interact(sql::select_notes).await.map_err(|err| {
+            DatabaseError::InteractError(format!("Select notes task failed: {err}"))
+        })?
+    }
+
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_unconsumed_notes(&self, block_num: BlockNumber) -> Result<Vec<Note>> {
+        self.pool
+            .get()
+            .await?
+            .interact(move |conn| sql::select_unconsumed_notes(conn, block_num))
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!("Select unconsumed notes task failed: {err}"))
+            })?
+    }
+
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_accounts(&self) -> Result<Vec<AccountInfo>> {
+        let account_cache = self.account_cache.clone();
+        self.pool
+            .get()
+            .await?
+            .interact(move |conn| sql::select_accounts(conn, &account_cache))
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!("Select accounts task failed: {err}"))
+            })?
+    }
+
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_nullifiers_page(
+        &self,
+        filter: NullifierFilter,
+        page: PageRequest<Nullifier>,
+    ) -> Result<Page<(Nullifier, BlockNumber), Nullifier>> {
+        self.pool
+            .get()
+            .await?
+            .interact(move |conn| sql::select_nullifiers_page(conn, &filter, page))
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!("Select nullifiers page task failed: {err}"))
+            })?
+    }
+
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_nullifiers_by_block_range_page(
+        &self,
+        block_start: BlockNumber,
+        block_end: BlockNumber,
+        nullifier_prefixes: Vec<u32>,
+        page: PageRequest<NullifierSyncKey>,
+    ) -> Result<Page<NullifierInfo, NullifierSyncKey>> {
+        self.pool
+            .get()
+            .await?
+            .interact(move |conn| {
+                sql::select_nullifiers_by_block_range_page(
+                    conn,
+                    block_start,
+                    block_end,
+                    &nullifier_prefixes,
+                    page,
+                )
+            })
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!(
+                    "Select nullifiers by block range task failed: {err}"
+                ))
+            })?
+    }
+
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_accounts_page(
+        &self,
+        filter: AccountFilter,
+        page: PageRequest<AccountId>,
+    ) -> Result<Page<AccountInfo, AccountId>> {
+        let account_cache = self.account_cache.clone();
+        self.pool
+            .get()
+            .await?
+            .interact(move |conn| sql::select_accounts_page(conn, &account_cache, &filter, page))
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!("Select accounts page task failed: {err}"))
+            })?
+    }
+
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_account_metadata_page(
+        &self,
+        filter: AccountMetadataFilter,
+        page: PageRequest<AccountId>,
+    ) -> Result<Page<AccountMetadata, AccountId>> {
+        self.pool
+            .get()
+            .await?
+            .interact(move |conn| sql::select_account_metadata_page(conn, &filter, page))
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!(
+                    "Select account metadata page task failed: {err}"
+                ))
+            })?
+    }
+
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_notes_page(
+        &self,
+        filter: NoteFilter,
+        page: PageRequest<NoteKey>,
+    ) -> Result<Page<Note, NoteKey>> {
+        self.pool
+            .get()
+            .await?
+            .interact(move |conn| sql::select_notes_page(conn, &filter, page))
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!("Select notes page task failed: {err}"))
+            })?
+    }
+
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_block_header_by_block_num(
+        &self,
+        block_number: Option<BlockNumber>,
+    ) -> Result<Option<BlockHeader>> {
+        self.pool
+            .get()
+            .await?
+            .interact(move |conn| sql::select_block_header_by_block_num(conn, block_number))
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!("Select block header task failed: {err}"))
+            })?
+    }
+
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_block_by_num(&self, block_num: BlockNumber) -> Result<Option<Vec<u8>>> {
+        self.pool
+            .get()
+            .await?
+            .interact(move |conn| sql::select_block_by_num(conn, block_num))
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!("Select block by number task failed: {err}"))
+            })?
+    }
 
-                    Ok(())
-                })
-            }))
-            .build()?;
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_block_by_hash(&self, block_hash: RpoDigest) -> Result<Option<Vec<u8>>> {
+        self.pool
+            .get()
+            .await?
+            .interact(move |conn| sql::select_block_by_hash(conn, &block_hash))
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!("Select block by hash task failed: {err}"))
+            })?
+    }
 
-        info!(
-            target: COMPONENT,
-            sqlite = format!("{}", config.database_filepath.display()),
-            "Connected to the database"
-        );
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_block_headers(&self) -> Result<Vec<BlockHeader>> {
+        self.pool
+            .get()
+            .await?
+            .interact(sql::select_block_headers)
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!("Select block headers task failed: {err}"))
+            })?
+    }
 
-        let conn = pool.get().await.map_err(DatabaseError::MissingDbConnection)?;
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_block_headers_by_block_nums(
+        &self,
+        block_nums: Vec<BlockNumber>,
+    ) -> Result<Vec<BlockHeader>> {
+        self.pool
+            .get()
+            .await?
+            .interact(move |conn| sql::select_block_headers_by_block_nums(conn, &block_nums))
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!(
+                    "Select block headers by block numbers task failed: {err}"
+                ))
+            })?
+    }
 
-        conn.interact(|conn| migrations::MIGRATIONS.to_latest(conn))
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_block_summaries(
+        &self,
+        block_num: BlockNumber,
+        limit: usize,
+    ) -> Result<Vec<BlockSummary>> {
+        self.pool
+            .get()
+            .await?
+            .interact(move |conn| sql::select_block_summaries(conn, block_num, limit))
             .await
             .map_err(|err| {
-                DatabaseError::InteractError(format!("Migration task failed: {err}"))
-            })??;
+                DatabaseError::InteractError(format!("Select block summaries task failed: {err}"))
+            })?
+    }
 
-        let db = Db { pool };
-        db.ensure_genesis_block(&config.genesis_filepath.as_path().to_string_lossy())
-            .await?;
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_account_hashes(&self) -> Result<Vec<(AccountId, RpoDigest)>> {
+        self.pool
+            .get()
+            .await?
+            .interact(sql::select_account_hashes)
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!("Select account hashes task failed: {err}"))
+            })?
+    }
 
-        Ok(db)
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_account_hash_history(
+        &self,
+    ) -> Result<Vec<(AccountId, BlockNumber, RpoDigest)>> {
+        self.pool
+            .get()
+            .await?
+            .interact(sql::select_account_hash_history)
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!(
+                    "Select account hash history task failed: {err}"
+                ))
+            })?
     }
 
-    /// Loads all the nullifiers from the DB.
     #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
-    pub async fn select_nullifiers(&self) -> Result<Vec<(Nullifier, BlockNumber)>> {
-        self.pool.get().await?.interact(sql::select_nullifiers).await.map_err(|err| {
-            DatabaseError::InteractError(format!("Select nullifiers task failed: {err}"))
-        })?
+    async fn select_account_updates_since(
+        &self,
+        block_num: BlockNumber,
+    ) -> Result<Vec<(AccountId, BlockNumber, Option<RpoDigest>)>> {
+        self.pool
+            .get()
+            .await?
+            .interact(move |conn| sql::select_account_updates_since(conn, block_num))
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!(
+                    "Select account updates since block task failed: {err}"
+                ))
+            })?
     }
 
-    /// Loads all the notes from the DB.
     #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
-    pub async fn select_notes(&self) -> Result<Vec<Note>> {
-        self.pool.get().await?.interact(sql::select_notes).await.map_err(|err| {
-            DatabaseError::InteractError(format!("Select notes task failed: {err}"))
-        })?
+    async fn select_state_since(&self, block_num: BlockNumber) -> Result<StateReplay> {
+        self.pool
+            .get()
+            .await?
+            .interact(move |conn| -> Result<StateReplay> {
+                let block_headers = sql::select_block_headers_since(conn, block_num)?;
+                let nullifiers = sql::select_nullifiers_since(conn, block_num)?;
+                let account_hashes = sql::select_account_hashes_since(conn, block_num)?;
+
+                Ok(StateReplay {
+                    block_headers,
+                    nullifiers,
+                    account_hashes,
+                })
+            })
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!("Select state since block task failed: {err}"))
+            })?
     }
 
-    /// Loads all the accounts from the DB.
     #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
-    pub async fn select_accounts(&self) -> Result<Vec<AccountInfo>> {
-        self.pool.get().await?.interact(sql::select_accounts).await.map_err(|err| {
-            DatabaseError::InteractError(format!("Select accounts task failed: {err}"))
-        })?
+    async fn select_account(&self, id: AccountId) -> Result<AccountInfo> {
+        let account_cache = self.account_cache.clone();
+        self.pool
+            .get()
+            .await?
+            .interact(move |conn| sql::select_account(conn, &account_cache, id))
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!("Get account details task failed: {err}"))
+            })?
     }
 
-    /// Search for a [BlockHeader] from the database by its `block_num`.
-    ///
-    /// When `block_number` is [None], the latest block header is returned.
     #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
-    pub async fn select_block_header_by_block_num(
+    async fn select_accounts_by_ids(
         &self,
-        block_number: Option<BlockNumber>,
-    ) -> Result<Option<BlockHeader>> {
+        account_ids: Vec<AccountId>,
+    ) -> Result<Vec<AccountInfo>> {
+        let account_cache = self.account_cache.clone();
         self.pool
             .get()
             .await?
-            .interact(move |conn| sql::select_block_header_by_block_num(conn, block_number))
+            .interact(move |conn| sql::select_accounts_by_ids(conn, &account_cache, &account_ids))
             .await
             .map_err(|err| {
-                DatabaseError::InteractError(format!("Select block header task failed: {err}"))
+                DatabaseError::InteractError(format!("Select accounts by ids task failed: {err}"))
             })?
     }
 
-    /// Loads all the block headers from the DB.
     #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
-    pub async fn select_block_headers(&self) -> Result<Vec<BlockHeader>> {
+    async fn select_accounts_at_block(
+        &self,
+        account_ids: Vec<AccountId>,
+        block_num: BlockNumber,
+    ) -> Result<Vec<AccountInfo>> {
         self.pool
             .get()
             .await?
-            .interact(sql::select_block_headers)
+            .interact(move |conn| sql::select_accounts_at_block(conn, &account_ids, block_num))
             .await
             .map_err(|err| {
-                DatabaseError::InteractError(format!("Select block headers task failed: {err}"))
+                DatabaseError::InteractError(format!("Get accounts at block task failed: {err}"))
             })?
     }
 
-    /// Loads all the account hashes from the DB.
     #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
-    pub async fn select_account_hashes(&self) -> Result<Vec<(AccountId, RpoDigest)>> {
+    async fn select_account_at_block(
+        &self,
+        id: AccountId,
+        block_num: BlockNumber,
+    ) -> Result<AccountInfo> {
         self.pool
             .get()
             .await?
-            .interact(sql::select_account_hashes)
+            .interact(move |conn| sql::select_account_at_block(conn, id, block_num))
             .await
             .map_err(|err| {
-                DatabaseError::InteractError(format!("Select account hashes task failed: {err}"))
+                DatabaseError::InteractError(format!(
+                    "Get account state at block task failed: {err}"
+                ))
             })?
     }
 
-    /// Loads public account details from the DB.
     #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
-    pub async fn select_account(&self, id: AccountId) -> Result<AccountInfo> {
+    async fn select_account_history(
+        &self,
+        id: AccountId,
+        block_start: BlockNumber,
+        block_end: BlockNumber,
+    ) -> Result<Vec<AccountHistoryRecord>> {
         self.pool
             .get()
             .await?
-            .interact(move |conn| sql::select_account(conn, id))
+            .interact(move |conn| sql::select_account_history(conn, id, block_start, block_end))
             .await
             .map_err(|err| {
-                DatabaseError::InteractError(format!("Get account details task failed: {err}"))
+                DatabaseError::InteractError(format!("Get account history task failed: {err}"))
             })?
     }
 
     #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
-    pub async fn get_state_sync(
+    async fn get_state_sync(
         &self,
         block_num: BlockNumber,
         account_ids: &[AccountId],
@@ -249,9 +1197,32 @@ impl Db {
             })?
     }
 
-    /// Loads all the Note's matching a certain NoteId from the database.
     #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
-    pub async fn select_notes_by_id(&self, note_ids: Vec<NoteId>) -> Result<Vec<Note>> {
+    async fn get_note_sync(
+        &self,
+        block_num: BlockNumber,
+        note_tag_prefixes: &[u32],
+        account_ids: &[AccountId],
+        max_blocks: usize,
+    ) -> Result<NoteSyncUpdate, StateSyncError> {
+        let note_tag_prefixes = note_tag_prefixes.to_vec();
+        let account_ids = account_ids.to_vec();
+
+        self.pool
+            .get()
+            .await
+            .map_err(DatabaseError::MissingDbConnection)?
+            .interact(move |conn| {
+                sql::get_note_sync(conn, block_num, &note_tag_prefixes, &account_ids, max_blocks)
+            })
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!("Get note sync task failed: {err}"))
+            })?
+    }
+
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_notes_by_id(&self, note_ids: Vec<NoteId>) -> Result<Vec<Note>> {
         self.pool
             .get()
             .await?
@@ -262,21 +1233,53 @@ impl Db {
             })?
     }
 
-    /// Inserts the data of a new block into the DB.
-    ///
-    /// `allow_acquire` and `acquire_done` are used to synchronize writes to the DB with writes to
-    /// the in-memory trees. Further details available on [super::state::State::apply_block].
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_note_statuses_by_id(&self, note_ids: Vec<NoteId>) -> Result<Vec<NoteStatus>> {
+        self.pool
+            .get()
+            .await?
+            .interact(move |conn| sql::select_note_statuses_by_id(conn, &note_ids))
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!(
+                    "Select note statuses by id task failed: {err}"
+                ))
+            })?
+    }
+
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn select_transactions_by_id(
+        &self,
+        transaction_ids: Vec<TransactionId>,
+    ) -> Result<Vec<TransactionInclusion>> {
+        self.pool
+            .get()
+            .await?
+            .interact(move |conn| sql::select_transactions_by_id(conn, &transaction_ids))
+            .await
+            .map_err(|err| {
+                DatabaseError::InteractError(format!(
+                    "Select transactions by id task failed: {err}"
+                ))
+            })?
+    }
+
     // TODO: This span is logged in a root span, we should connect it to the parent one.
+    #[allow(clippy::too_many_arguments)]
     #[instrument(target = "miden-store", skip_all, err)]
-    pub async fn apply_block(
+    async fn apply_block(
         &self,
         allow_acquire: oneshot::Sender<()>,
         acquire_done: oneshot::Receiver<()>,
         block_header: BlockHeader,
+        block_data: Vec<u8>,
         notes: Vec<Note>,
         nullifiers: Vec<Nullifier>,
         accounts: Vec<AccountUpdateDetails>,
+        transactions: Vec<TransactionHeader>,
     ) -> Result<()> {
+        let account_cache = self.account_cache.clone();
+        let checkpoint_interval = self.account_checkpoint_interval;
         self.pool
             .get()
             .await?
@@ -285,7 +1288,17 @@ impl Db {
                 let _span = info_span!(target: COMPONENT, "write_block_to_db").entered();
 
                 let transaction = conn.transaction()?;
-                sql::apply_block(&transaction, &block_header, &notes, &nullifiers, &accounts)?;
+                sql::apply_block(
+                    &transaction,
+                    &account_cache,
+                    checkpoint_interval,
+                    &block_header,
+                    &block_data,
+                    &notes,
+                    &nullifiers,
+                    &accounts,
+                    &transactions,
+                )?;
 
                 let _ = allow_acquire.send(());
                 acquire_done
@@ -304,83 +1317,97 @@ impl Db {
         Ok(())
     }
 
-    // HELPERS
-    // ---------------------------------------------------------------------------------------------
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn rollback_to(
+        &self,
+        block_num: BlockNumber,
+        rolled_back: oneshot::Sender<StateReplay>,
+        commit: oneshot::Receiver<()>,
+    ) -> Result<usize> {
+        self.pool
+            .get()
+            .await?
+            .interact(move |conn| -> Result<usize> {
+                let transaction = conn.transaction()?;
+                let count = sql::rollback_to(&transaction, block_num)?;
 
-    /// If the database is empty, generates and stores the genesis block. Otherwise, it ensures that the
-    /// genesis block in the database is consistent with the genesis block data in the genesis JSON
-    /// file.
-    #[instrument(target = "miden-store", skip_all, err)]
-    async fn ensure_genesis_block(&self, genesis_filepath: &str) -> Result<(), GenesisError> {
-        let (expected_genesis_header, account_smt) = {
-            let file_contents = fs::read(genesis_filepath).map_err(|error| {
-                GenesisError::FailedToReadGenesisFile {
-                    genesis_filepath: genesis_filepath.to_string(),
-                    error,
-                }
-            })?;
+                let _ = rolled_back.send(StateReplay {
+                    block_headers: sql::select_block_headers(&transaction)?,
+                    nullifiers: sql::select_nullifiers(&transaction)?,
+                    account_hashes: sql::select_account_hashes(&transaction)?,
+                });
+                commit.blocking_recv().map_err(DatabaseError::RollbackFailedClosedChannel)?;
 
-            let genesis_state = GenesisState::read_from_bytes(&file_contents)
-                .map_err(GenesisError::GenesisFileDeserializationError)?;
+                transaction.commit()?;
 
-            genesis_state.into_block_parts().map_err(GenesisError::MalformedGenesisState)?
-        };
+                Ok(count)
+            })
+            .await
+            .map_err(|err| DatabaseError::InteractError(format!("Rollback task failed: {err}")))?
+    }
 
-        let maybe_block_header_in_store = self
-            .select_block_header_by_block_num(Some(GENESIS_BLOCK))
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    async fn prune(&self, target: PruningTarget) -> Result<usize> {
+        self.pool
+            .get()
+            .await?
+            .interact(move |conn| -> Result<usize> {
+                let transaction = conn.transaction()?;
+                let count = sql::prune(&transaction, &target)?;
+                transaction.commit()?;
+
+                Ok(count)
+            })
             .await
-            .map_err(|err| GenesisError::SelectBlockHeaderByBlockNumError(err.into()))?;
+            .map_err(|err| DatabaseError::InteractError(format!("Prune task failed: {err}")))?
+    }
+}
 
-        match maybe_block_header_in_store {
-            Some(block_header_in_store) => {
-                // ensure that expected header is what's also in the store
-                if expected_genesis_header != block_header_in_store {
-                    Err(GenesisError::GenesisBlockHeaderMismatch {
-                        expected_genesis_header: Box::new(expected_genesis_header),
-                        block_header_in_store: Box::new(block_header_in_store),
-                    })?;
-                }
-            },
-            None => {
-                // add genesis header to store
-                self.pool
-                    .get()
-                    .await
-                    .map_err(DatabaseError::MissingDbConnection)?
-                    .interact(move |conn| -> Result<()> {
-                        // TODO: This span is logged in a root span, we should connect it to the parent one.
-                        let span = info_span!(target: COMPONENT, "write_genesis_block_to_db");
-                        let guard = span.enter();
+// HELPERS
+// ================================================================================================
 
-                        let transaction = conn.transaction()?;
-                        let accounts: Vec<_> = account_smt
-                            .leaves()
-                            .map(|(account_id, state_hash)| {
-                                Ok(AccountUpdateDetails {
-                                    account_id: account_id.try_into()?,
-                                    final_state_hash: state_hash.into(),
-                                    details: None,
-                                })
-                            })
-                            .collect::<Result<_, DatabaseError>>()?;
-                        sql::apply_block(
-                            &transaction,
-                            &expected_genesis_header,
-                            &[],
-                            &[],
-                            &accounts,
-                        )?;
+/// Reads the genesis file and builds the genesis block from it.
+fn read_genesis_block(
+    genesis_filepath: &str,
+) -> Result<(BlockHeader, Vec<u8>, Vec<AccountUpdateDetails>), GenesisError> {
+    let file_contents =
+        fs::read(genesis_filepath).map_err(|error| GenesisError::FailedToReadGenesisFile {
+            genesis_filepath: genesis_filepath.to_string(),
+            error,
+        })?;
 
-                        transaction.commit()?;
+    let genesis_state = GenesisState::read_from_bytes(&file_contents)
+        .map_err(GenesisError::GenesisFileDeserializationError)?;
 
-                        drop(guard);
-                        Ok(())
-                    })
-                    .await
-                    .map_err(|err| GenesisError::ApplyBlockFailed(err.to_string()))??;
-            },
-        }
+    genesis_block(genesis_state)
+}
 
-        Ok(())
+/// Returns the header, the data and the account updates of the genesis block.
+fn genesis_block(
+    genesis_state: GenesisState,
+) -> Result<(BlockHeader, Vec<u8>, Vec<AccountUpdateDetails>), GenesisError> {
+    let (block_header, account_smt) =
+        genesis_state.into_block_parts().map_err(GenesisError::MalformedGenesisState)?;
+
+    let accounts: Vec<_> = account_smt
+        .leaves()
+        .map(|(account_id, state_hash)| {
+            Ok(AccountUpdateDetails {
+                account_id: account_id.try_into()?,
+                final_state_hash: state_hash.into(),
+                details: None,
+            })
+        })
+        .collect::<Result<_, DatabaseError>>()?;
+
+    let block_data = ApplyBlockRequest {
+        block: Some((&block_header).into()),
+        accounts: convert(&accounts),
+        nullifiers: vec![],
+        notes: vec![],
+        transactions: vec![],
     }
+    .encode_to_vec();
+
+    Ok((block_header, block_data, accounts))
 }
@@ -1,23 +1,29 @@
 //! Wrapper functions for SQL statements.
 
-use std::{borrow::Cow, rc::Rc};
+use std::{borrow::Cow, collections::BTreeMap, rc::Rc};
 
-use miden_node_proto::domain::accounts::{AccountInfo, AccountSummary, AccountUpdateDetails};
+use miden_node_proto::domain::{
+    accounts::{AccountInfo, AccountSummary, AccountUpdateDetails},
+    transactions::TransactionHeader,
+};
 use miden_objects::{
     accounts::{Account, AccountDelta},
+    assets::Asset,
+    block::BlockNoteTree,
     crypto::{hash::rpo::RpoDigest, merkle::MerklePath},
-    notes::{NoteId, Nullifier},
-    transaction::AccountDetails,
+    notes::{Note as NoteData, NoteId, Nullifier},
+    transaction::{AccountDetails, TransactionId},
     utils::serde::{Deserializable, Serializable},
     BlockHeader,
 };
-use rusqlite::{
-    params,
-    types::{Value, ValueRef},
-    Connection, Transaction,
-};
+use rusqlite::{params, types::Value, Connection, OptionalExtension, Statement, Transaction};
 
-use super::{Note, NoteCreated, NullifierInfo, Result, StateSyncUpdate};
+use super::{
+    cache::AccountCache, into_page, AccountFilter, AccountHistoryRecord, AccountKind,
+    AccountMetadata, AccountMetadataFilter, BlockSummary, Note, NoteCreated, NoteFilter, NoteKey,
+    NoteStatus, NoteSyncUpdate, NullifierFilter, NullifierInfo, NullifierSyncKey, Page,
+    PageRequest, PruningTarget, Result, StateSyncUpdate, TransactionInclusion,
+};
 use crate::{
     errors::{DatabaseError, StateSyncError},
     types::{AccountId, BlockNumber},
@@ -31,14 +37,13 @@ use crate::{
 /// # Returns
 ///
 /// A vector with accounts, or an error.
-pub fn select_accounts(conn: &mut Connection) -> Result<Vec<AccountInfo>> {
+pub fn select_accounts(conn: &mut Connection, cache: &AccountCache) -> Result<Vec<AccountInfo>> {
     let mut stmt = conn.prepare(
         "
         SELECT
             account_id,
             account_hash,
-            block_num,
-            details
+            block_num
         FROM
             accounts
         ORDER BY
@@ -49,9 +54,109 @@ pub fn select_accounts(conn: &mut Connection) -> Result<Vec<AccountInfo>> {
 
     let mut accounts = vec![];
     while let Some(row) = rows.next()? {
-        accounts.push(account_info_from_row(row)?)
+        accounts.push(account_hash_update_from_row(row)?)
+    }
+    select_account_details(conn, CacheUse::Read(cache), accounts)
+}
+
+/// Select a page of the accounts matching the `filter` from the DB using the given [Connection].
+///
+/// # Returns
+///
+/// The page of accounts sorted by account ID, or an error.
+pub fn select_accounts_page(
+    conn: &mut Connection,
+    cache: &AccountCache,
+    filter: &AccountFilter,
+    page: PageRequest<AccountId>,
+) -> Result<Page<AccountInfo, AccountId>> {
+    let mut stmt = conn.prepare(
+        "
+        SELECT
+            account_id,
+            account_hash,
+            block_num
+        FROM
+            accounts
+        WHERE
+            (?1 IS NULL OR block_num >= ?1) AND
+            (?2 IS NULL OR block_num <= ?2) AND
+            (?3 IS NULL OR on_chain = ?3) AND
+            (?4 IS NULL OR account_id > ?4)
+        ORDER BY
+            account_id ASC
+        LIMIT
+            ?5;
+    ",
+    )?;
+    let mut rows = stmt.query(params![
+        filter.block_num_from,
+        filter.block_num_to,
+        filter.on_chain,
+        page.after.map(u64_to_value),
+        page_limit(page.size),
+    ])?;
+
+    let mut accounts = vec![];
+    while let Some(row) = rows.next()? {
+        accounts.push(account_hash_update_from_row(row)?)
+    }
+    let accounts = select_account_details(conn, CacheUse::Read(cache), accounts)?;
+    Ok(into_page(accounts, page.size, |account| account.summary.account_id.into()))
+}
+
+/// Select a page of the metadata of the accounts matching the `filter` from the DB using the
+/// given [Connection].
+///
+/// # Returns
+///
+/// The page of account metadata sorted by account ID, or an error.
+pub fn select_account_metadata_page(
+    conn: &mut Connection,
+    filter: &AccountMetadataFilter,
+    page: PageRequest<AccountId>,
+) -> Result<Page<AccountMetadata, AccountId>> {
+    let mut stmt = conn.prepare(
+        "
+        SELECT
+            account_id,
+            account_hash,
+            block_num,
+            account_type,
+            on_chain,
+            created_block_num,
+            num_updates,
+            code_root
+        FROM
+            accounts
+        WHERE
+            (?1 IS NULL OR account_type = ?1) AND
+            (?2 IS NULL OR on_chain = ?2) AND
+            (?3 IS NULL OR created_block_num >= ?3) AND
+            (?4 IS NULL OR created_block_num <= ?4) AND
+            (?5 IS NULL OR code_root = ?5) AND
+            (?6 IS NULL OR account_id > ?6)
+        ORDER BY
+            account_id ASC
+        LIMIT
+            ?7;
+    ",
+    )?;
+    let mut rows = stmt.query(params![
+        filter.kind.map(|kind| kind as u32),
+        filter.on_chain,
+        filter.created_block_num_from,
+        filter.created_block_num_to,
+        filter.code_root.map(|code_root| code_root.to_bytes()),
+        page.after.map(u64_to_value),
+        page_limit(page.size),
+    ])?;
+
+    let mut accounts = vec![];
+    while let Some(row) = rows.next()? {
+        accounts.push(account_metadata_from_row(row)?)
     }
-    Ok(accounts)
+    Ok(into_page(accounts, page.size, |account| account.summary.account_id.into()))
 }
 
 /// Select all account hashes from the DB using the given [Connection].
@@ -59,7 +164,7 @@ pub fn select_accounts(conn: &mut Connection) -> Result<Vec<AccountInfo>> {
 /// # Returns
 ///
 /// The vector with the account id and corresponding hash, or an error.
-pub fn select_account_hashes(conn: &mut Connection) -> Result<Vec<(AccountId, RpoDigest)>> {
+pub fn select_account_hashes(conn: &Connection) -> Result<Vec<(AccountId, RpoDigest)>> {
     let mut stmt =
         conn.prepare("SELECT account_id, account_hash FROM accounts ORDER BY block_num ASC;")?;
     let mut rows = stmt.query([])?;
@@ -76,6 +181,115 @@ pub fn select_account_hashes(conn: &mut Connection) -> Result<Vec<(AccountId, Rp
     Ok(result)
 }
 
+/// Select the hashes of the accounts updated after `block_num` from the DB using the given
+/// [Connection].
+///
+/// # Returns
+///
+/// The vector with the account id and corresponding latest hash, or an error.
+pub fn select_account_hashes_since(
+    conn: &mut Connection,
+    block_num: BlockNumber,
+) -> Result<Vec<(AccountId, RpoDigest)>> {
+    let mut stmt = conn.prepare(
+        "SELECT account_id, account_hash FROM accounts WHERE block_num > ?1 ORDER BY block_num ASC;",
+    )?;
+    let mut rows = stmt.query([block_num])?;
+
+    let mut result = Vec::new();
+    while let Some(row) = rows.next()? {
+        let account_id = column_value_as_u64(row, 0)?;
+        let account_hash_data = row.get_ref(1)?.as_blob()?;
+        let account_hash = RpoDigest::read_from_bytes(account_hash_data)?;
+
+        result.push((account_id, account_hash));
+    }
+
+    Ok(result)
+}
+
+/// Select the hashes of all the account states from the account history using the given
+/// [Connection].
+///
+/// # Returns
+///
+/// The vector with the account id, the block number and the hash of the account state after that
+/// block, ordered by block number, or an error.
+pub fn select_account_hash_history(
+    conn: &mut Connection,
+) -> Result<Vec<(AccountId, BlockNumber, RpoDigest)>> {
+    let mut stmt = conn.prepare(
+        "SELECT account_id, block_num, account_hash FROM account_history ORDER BY block_num ASC;",
+    )?;
+    let mut rows = stmt.query([])?;
+
+    let mut result = Vec::new();
+    while let Some(row) = rows.next()? {
+        let account_id = column_value_as_u64(row, 0)?;
+        let block_num = row.get(1)?;
+        let account_hash_data = row.get_ref(2)?.as_blob()?;
+        let account_hash = RpoDigest::read_from_bytes(account_hash_data)?;
+
+        result.push((account_id, block_num, account_hash));
+    }
+
+    Ok(result)
+}
+
+/// Select the account updates after `block_num` from the account history using the given
+/// [Connection].
+///
+/// # Returns
+///
+/// The vector with the account id, the block number of the update and the hash of the account
+/// state prior to the update, [None] if the account was created by the block, ordered by block
+/// number, or an error.
+pub fn select_account_updates_since(
+    conn: &mut Connection,
+    block_num: BlockNumber,
+) -> Result<Vec<(AccountId, BlockNumber, Option<RpoDigest>)>> {
+    let mut stmt = conn.prepare(
+        "
+        SELECT
+            account_id,
+            block_num,
+            (
+                SELECT
+                    account_hash
+                FROM
+                    account_history AS prev
+                WHERE
+                    prev.account_id = history.account_id AND
+                    prev.block_num < history.block_num
+                ORDER BY
+                    prev.block_num DESC
+                LIMIT
+                    1
+            )
+        FROM
+            account_history AS history
+        WHERE
+            block_num > ?1
+        ORDER BY
+            block_num ASC,
+            account_id ASC;
+    ",
+    )?;
+    let mut rows = stmt.query([block_num])?;
+
+    let mut result = Vec::new();
+    while let Some(row) = rows.next()? {
+        let account_id = column_value_as_u64(row, 0)?;
+        let block_num = row.get(1)?;
+        let prev_hash =
+            row.get_ref(2)?.as_blob_or_null()?.map(RpoDigest::read_from_bytes).transpose()?;
+
+        result.push((account_id, block_num, prev_hash));
+    }
+
+    Ok(result)
+}
+
 /// Select [AccountSummary] from the DB using the given [Connection], given that the account
 /// update was done between `(block_start, block_end]`.
 ///
@@ -122,29 +336,217 @@ pub fn select_accounts_by_block_range(
 /// # Returns
 ///
 /// The latest account details, or an error.
-pub fn select_account(conn: &mut Connection, account_id: AccountId) -> Result<AccountInfo> {
+pub fn select_account(
+    conn: &mut Connection,
+    cache: &AccountCache,
+    account_id: AccountId,
+) -> Result<AccountInfo> {
+    let summary = select_account_summary(conn, account_id)?;
+
+    let mut accounts = select_account_details(conn, CacheUse::ReadWrite(cache), vec![summary])?;
+    Ok(accounts.remove(0))
+}
+
+/// Select the latest account details of the given accounts from the DB using the given
+/// [Connection].
+///
+/// # Returns
+///
+/// The latest account details of the accounts found in the DB, sorted by account ID, or an error.
+pub fn select_accounts_by_ids(
+    conn: &mut Connection,
+    cache: &AccountCache,
+    account_ids: &[AccountId],
+) -> Result<Vec<AccountInfo>> {
+    let account_ids: Vec<Value> = account_ids.iter().copied().map(u64_to_value).collect();
+
     let mut stmt = conn.prepare(
         "
         SELECT
             account_id,
             account_hash,
-            block_num,
-            details
+            block_num
         FROM
             accounts
         WHERE
-            account_id = ?1;
+            account_id IN rarray(?1)
+        ORDER BY
+            account_id ASC;
     ",
     )?;
 
-    let mut rows = stmt.query(params![u64_to_value(account_id)])?;
+    let mut rows = stmt.query(params![Rc::new(account_ids)])?;
+
+    let mut result = Vec::new();
+    while let Some(row) = rows.next()? {
+        result.push(account_hash_update_from_row(row)?);
+    }
+
+    select_account_details(conn, CacheUse::Read(cache), result)
+}
+
+/// Select the state of an account as of the given block from the DB using the given [Connection].
+///
+/// # Returns
+///
+/// The state of the account after the last update at or before `block_num`, or an error if the
+/// account didn't exist at that block.
+pub fn select_account_at_block(
+    conn: &mut Connection,
+    account_id: AccountId,
+    block_num: BlockNumber,
+) -> Result<AccountInfo> {
+    let mut stmt = conn.prepare(
+        "
+        SELECT
+            account_id,
+            account_hash,
+            block_num,
+            pruned
+        FROM
+            account_history
+        WHERE
+            account_id = ?1 AND
+            block_num <= ?2
+        ORDER BY
+            block_num DESC
+        LIMIT 1
+        ",
+    )?;
+
+    let mut rows = stmt.query(params![u64_to_value(account_id), block_num])?;
     let row = rows.next()?.ok_or(DatabaseError::AccountNotFoundInDb(account_id))?;
+    if row.get(3)? {
+        return Err(DatabaseError::AccountStatePruned { account_id, block_num });
+    }
+    let summary = account_hash_update_from_row(row)?;
+
+    let mut accounts = select_account_details(conn, CacheUse::None, vec![summary])?;
+    Ok(accounts.remove(0))
+}
+
+/// Select the state of the given accounts as of the given block from the DB using the given
+/// [Connection].
+///
+/// # Returns
+///
+/// The state of each account after its last update at or before `block_num`, sorted by account ID.
+/// Accounts which didn't exist at that block are skipped.
+pub fn select_accounts_at_block(
+    conn: &mut Connection,
+    account_ids: &[AccountId],
+    block_num: BlockNumber,
+) -> Result<Vec<AccountInfo>> {
+    let account_ids: Vec<Value> = account_ids.iter().copied().map(u64_to_value).collect();
+
+    let mut stmt = conn.prepare(
+        "
+        SELECT
+            account_id,
+            account_hash,
+            block_num,
+            pruned
+        FROM
+            account_history AS history
+        WHERE
+            account_id IN rarray(?1) AND
+            block_num = (
+                SELECT
+                    MAX(block_num)
+                FROM
+                    account_history
+                WHERE
+                    account_id = history.account_id AND
+                    block_num <= ?2
+            )
+        ORDER BY
+            account_id ASC
+        ",
+    )?;
+
+    let mut rows = stmt.query(params![Rc::new(account_ids), block_num])?;
+
+    let mut result = Vec::new();
+    while let Some(row) = rows.next()? {
+        if row.get(3)? {
+            let account_id = column_value_as_u64(row, 0)?;
+            return Err(DatabaseError::AccountStatePruned { account_id, block_num });
+        }
+        result.push(account_hash_update_from_row(row)?);
+    }
+
+    select_account_details(conn, CacheUse::None, result)
+}
+
+/// Select the updates of an on-chain account from the DB using the given [Connection], given that
+/// the account update was done between `(block_start, block_end]`.
+///
+/// # Returns
+///
+/// The vector of [AccountHistoryRecord] ordered by block number, or an error if the account is not
+/// on-chain.
+pub fn select_account_history(
+    conn: &mut Connection,
+    account_id: AccountId,
+    block_start: BlockNumber,
+    block_end: BlockNumber,
+) -> Result<Vec<AccountHistoryRecord>> {
+    let mut stmt = conn.prepare(
+        "
+        SELECT
+            block_num,
+            account_hash,
+            details,
+            delta,
+            pruned
+        FROM
+            account_history
+        WHERE
+            account_id = ?1 AND
+            block_num > ?2 AND
+            block_num <= ?3
+        ORDER BY
+            block_num ASC
+        ",
+    )?;
+
+    let mut rows = stmt.query(params![u64_to_value(account_id), block_start, block_end])?;
+
+    let mut result = Vec::new();
+    while let Some(row) = rows.next()? {
+        if row.get(4)? {
+            let block_num = row.get(0)?;
+            return Err(DatabaseError::AccountStatePruned { account_id, block_num });
+        }
+
+        let account_hash_data = row.get_ref(1)?.as_blob()?;
+        let account_hash = RpoDigest::read_from_bytes(account_hash_data)?;
+
+        let details_data = row.get_ref(2)?.as_blob_or_null()?;
+        let delta_data = row.get_ref(3)?.as_blob_or_null()?;
+        let details = match (delta_data, details_data) {
+            (Some(delta), _) => AccountDetails::Delta(AccountDelta::read_from_bytes(delta)?),
+            (None, Some(account)) => AccountDetails::Full(Account::read_from_bytes(account)?),
+            (None, None) => return Err(DatabaseError::AccountNotOnChain(account_id)),
+        };
+
+        result.push(AccountHistoryRecord {
+            block_num: row.get(0)?,
+            account_hash,
+            details,
+        });
+    }
 
-    account_info_from_row(row)
+    Ok(result)
 }
 
 /// Inserts or updates accounts to the DB using the given [Transaction].
 ///
+/// Besides the latest hash of each account, the update is also recorded in the account history.
+/// The state of on-chain accounts is stored as the delta of each update, with a full checkpoint of
+/// the state once `checkpoint_interval` deltas were stored since the previous one. Updates which
+/// provide the full state are always stored as checkpoints.
+///
 /// # Returns
 ///
 /// The number of affected rows.
@@ -155,20 +557,61 @@ pub fn select_account(conn: &mut Connection, account_id: AccountId) -> Result<Ac
 /// transaction.
 pub fn upsert_accounts(
     transaction: &Transaction,
+    cache: &AccountCache,
     accounts: &[AccountUpdateDetails],
     block_num: BlockNumber,
+    checkpoint_interval: u32,
 ) -> Result<usize> {
+    // The creation block is kept on updates, and the number of updates only counts the blocks.
     let mut upsert_stmt = transaction.prepare(
-        "INSERT OR REPLACE INTO accounts (account_id, account_hash, block_num, details) VALUES (?1, ?2, ?3, ?4);",
+        "
+        INSERT INTO
+            accounts (account_id, account_hash, block_num, account_type, on_chain, created_block_num, num_updates, code_root)
+        VALUES
+            (?1, ?2, ?3, ?4, ?5, ?3, 1, ?6)
+        ON CONFLICT (account_id) DO UPDATE SET
+            account_hash = excluded.account_hash,
+            block_num = excluded.block_num,
+            num_updates = num_updates + (block_num != excluded.block_num),
+            code_root = excluded.code_root;
+        ",
+    )?;
+    let mut count_deltas_stmt = transaction.prepare(
+        "
+        SELECT
+            COUNT(*)
+        FROM
+            account_history
+        WHERE
+            account_id = ?1 AND
+            block_num > COALESCE(
+                (SELECT MAX(block_num) FROM account_history WHERE account_id = ?1 AND details IS NOT NULL),
+                -1
+            );
+        ",
+    )?;
+    // An account updated more than once in the same block keeps a single history record with its
+    // final state as a checkpoint, and the delta is dropped since it only covers the last of the
+    // updates.
+    let mut insert_history_stmt = transaction.prepare(
+        "
+        INSERT INTO
+            account_history (account_id, block_num, account_hash, details, delta)
+        VALUES
+            (?1, ?2, ?3, ?4, ?5)
+        ON CONFLICT (account_id, block_num) DO UPDATE SET
+            account_hash = excluded.account_hash,
+            details = excluded.details,
+            delta = NULL;
+        ",
     )?;
-    let mut select_details_stmt =
-        transaction.prepare("SELECT details FROM accounts WHERE account_id = ?1;")?;
 
     let mut count = 0;
     for update in accounts.iter() {
         let account_id = update.account_id.into();
-        let full_account = match &update.details {
-            None => None,
+
+        let (full_account, checkpoint) = match &update.details {
+            None => (None, false),
             Some(AccountDetails::Full(account)) => {
                 debug_assert_eq!(account_id, u64::from(account.id()));
 
@@ -179,41 +622,136 @@ pub fn upsert_accounts(
                     });
                 }
 
-                Some(Cow::Borrowed(account))
+                (Some(Cow::Borrowed(account)), true)
             },
             Some(AccountDetails::Delta(delta)) => {
-                let mut rows = select_details_stmt.query(params![u64_to_value(account_id)])?;
-                let Some(row) = rows.next()? else {
-                    return Err(DatabaseError::AccountNotFoundInDb(account_id));
-                };
-
+                let summary = select_account_summary(transaction, account_id)?;
+                let repeated_update = summary.block_num == block_num;
                 let account =
-                    apply_delta(account_id, &row.get_ref(0)?, delta, &update.final_state_hash)?;
+                    select_account_details(transaction, CacheUse::Read(cache), vec![summary])?
+                        .remove(0)
+                        .details
+                        .ok_or(DatabaseError::AccountNotOnChain(account_id))?;
+                let account = apply_delta(account, delta, &update.final_state_hash)?;
+
+                let num_deltas: u32 = count_deltas_stmt
+                    .query_row(params![u64_to_value(account_id)], |row| row.get(0))?;
 
-                Some(Cow::Owned(account))
+                (Some(Cow::Owned(account)), repeated_update || num_deltas >= checkpoint_interval)
             },
         };
 
+        let details =
+            full_account.as_ref().filter(|_| checkpoint).map(|account| account.to_bytes());
+        let delta = match &update.details {
+            Some(AccountDetails::Delta(delta)) => Some(delta.to_bytes()),
+            _ => None,
+        };
+
+        let code_root = full_account.as_ref().map(|account| account.code().root().to_bytes());
+
         let inserted = upsert_stmt.execute(params![
             u64_to_value(account_id),
             update.final_state_hash.to_bytes(),
             block_num,
-            full_account.as_ref().map(|account| account.to_bytes()),
+            AccountKind::from(update.account_id.account_type()) as u32,
+            update.account_id.is_on_chain(),
+            code_root,
         ])?;
 
         debug_assert_eq!(inserted, 1);
 
-        count += inserted;
-    }
-
-    Ok(count)
-}
+        insert_history_stmt.execute(params![
+            u64_to_value(account_id),
+            block_num,
+            update.final_state_hash.to_bytes(),
+            details,
+            delta,
+        ])?;
+
+        if let Some(account) = full_account {
+            cache.insert(account.into_owned());
+        }
+
+        count += inserted;
+    }
+
+    Ok(count)
+}
+
+/// Indexes the metadata of the accounts which don't have it yet, using the given [Transaction].
+///
+/// This covers the accounts stored before the metadata was indexed, and the accounts restored
+/// from the account history or imported from an archive. The creation block and the number of
+/// updates are derived from the account history.
+///
+/// # Returns
+///
+/// The number of indexed accounts.
+///
+/// # Note
+///
+/// The [Transaction] object is not consumed. It's up to the caller to commit or rollback the
+/// transaction.
+pub fn index_account_metadata(transaction: &Transaction) -> Result<usize> {
+    let mut select_stmt = transaction.prepare(
+        "
+        SELECT
+            account_id,
+            account_hash,
+            block_num
+        FROM
+            accounts
+        WHERE
+            account_type IS NULL;
+        ",
+    )?;
+    let mut rows = select_stmt.query([])?;
+    let mut accounts = vec![];
+    while let Some(row) = rows.next()? {
+        accounts.push(account_hash_update_from_row(row)?);
+    }
+    let accounts = select_account_details(transaction, CacheUse::None, accounts)?;
+
+    let mut update_stmt = transaction.prepare(
+        "
+        UPDATE
+            accounts
+        SET
+            account_type = ?2,
+            on_chain = ?3,
+            created_block_num = COALESCE(
+                (SELECT MIN(block_num) FROM account_history WHERE account_id = ?1),
+                block_num
+            ),
+            num_updates = MAX((SELECT COUNT(*) FROM account_history WHERE account_id = ?1), 1),
+            code_root = ?4
+        WHERE
+            account_id = ?1;
+        ",
+    )?;
+
+    let mut count = 0;
+    for AccountInfo { summary, details } in accounts {
+        let account_id = summary.account_id;
+        count += update_stmt.execute(params![
+            u64_to_value(account_id.into()),
+            AccountKind::from(account_id.account_type()) as u32,
+            account_id.is_on_chain(),
+            details.map(|account| account.code().root().to_bytes()),
+        ])?;
+    }
+
+    Ok(count)
+}
 
 // NULLIFIER QUERIES
 // ================================================================================================
 
 /// Insert nullifiers to the DB using the given [Transaction].
 ///
+/// Notes with a matching nullifier are marked as consumed in the block `block_num`.
+///
 /// # Returns
 ///
 /// The number of affected rows.
@@ -230,11 +768,17 @@ pub fn insert_nullifiers_for_block(
     let mut stmt = transaction.prepare(
         "INSERT INTO nullifiers (nullifier, nullifier_prefix, block_num) VALUES (?1, ?2, ?3);",
     )?;
+    let mut consume_stmt =
+        transaction.prepare("UPDATE notes SET consumed_block_num = ?2 WHERE nullifier = ?1;")?;
 
     let mut count = 0;
     for nullifier in nullifiers.iter() {
-        count +=
-            stmt.execute(params![nullifier.to_bytes(), get_nullifier_prefix(nullifier), block_num])?
+        count += stmt.execute(params![
+            nullifier.to_bytes(),
+            get_nullifier_prefix(nullifier),
+            block_num
+        ])?;
+        consume_stmt.execute(params![nullifier.to_bytes(), block_num])?;
     }
     Ok(count)
 }
@@ -244,7 +788,7 @@ pub fn insert_nullifiers_for_block(
 /// # Returns
 ///
 /// A vector with nullifiers and the block height at which they were created, or an error.
-pub fn select_nullifiers(conn: &mut Connection) -> Result<Vec<(Nullifier, BlockNumber)>> {
+pub fn select_nullifiers(conn: &Connection) -> Result<Vec<(Nullifier, BlockNumber)>> {
     let mut stmt =
         conn.prepare("SELECT nullifier, block_num FROM nullifiers ORDER BY block_num ASC;")?;
     let mut rows = stmt.query([])?;
@@ -259,6 +803,76 @@ pub fn select_nullifiers(conn: &mut Connection) -> Result<Vec<(Nullifier, BlockN
     Ok(result)
 }
 
+/// Select a page of the nullifiers matching the `filter` from the DB using the given
+/// [Connection].
+///
+/// # Returns
+///
+/// The page of nullifiers and the block height at which they were created, sorted by nullifier,
+/// or an error.
+pub fn select_nullifiers_page(
+    conn: &mut Connection,
+    filter: &NullifierFilter,
+    page: PageRequest<Nullifier>,
+) -> Result<Page<(Nullifier, BlockNumber), Nullifier>> {
+    let mut stmt = conn.prepare(
+        "
+        SELECT
+            nullifier,
+            block_num
+        FROM
+            nullifiers
+        WHERE
+            (?1 IS NULL OR block_num >= ?1) AND
+            (?2 IS NULL OR block_num <= ?2) AND
+            (?3 IS NULL OR nullifier > ?3)
+        ORDER BY
+            nullifier ASC
+        LIMIT
+            ?4;
+        ",
+    )?;
+    let mut rows = stmt.query(params![
+        filter.block_num_from,
+        filter.block_num_to,
+        page.after.map(|nullifier| nullifier.to_bytes()),
+        page_limit(page.size),
+    ])?;
+
+    let mut result = vec![];
+    while let Some(row) = rows.next()? {
+        let nullifier_data = row.get_ref(0)?.as_blob()?;
+        let nullifier = Nullifier::read_from_bytes(nullifier_data)?;
+        let block_number = row.get(1)?;
+        result.push((nullifier, block_number));
+    }
+    Ok(into_page(result, page.size, |(nullifier, _)| *nullifier))
+}
+
+/// Select the nullifiers created after `block_num` from the DB using the given [Connection].
+///
+/// # Returns
+///
+/// A vector with nullifiers and the block height at which they were created, or an error.
+pub fn select_nullifiers_since(
+    conn: &mut Connection,
+    block_num: BlockNumber,
+) -> Result<Vec<(Nullifier, BlockNumber)>> {
+    let mut stmt = conn.prepare(
+        "SELECT nullifier, block_num FROM nullifiers WHERE block_num > ?1 ORDER BY block_num ASC;",
+    )?;
+    let mut rows = stmt.query([block_num])?;
+
+    let mut result = vec![];
+    while let Some(row) = rows.next()? {
+        let nullifier_data = row.get_ref(0)?.as_blob()?;
+        let nullifier = Nullifier::read_from_bytes(nullifier_data)?;
+        let block_number = row.get(1)?;
+        result.push((nullifier, block_number));
+    }
+    Ok(result)
+}
+
 /// Select nullifiers created between `(block_start, block_end]` that also match the
 /// `nullifier_prefixes` filter using the given [Connection].
 ///
@@ -275,6 +889,25 @@ pub fn select_nullifiers_by_block_range(
     block_end: BlockNumber,
     nullifier_prefixes: &[u32],
 ) -> Result<Vec<NullifierInfo>> {
+    let page = PageRequest { after: None, size: usize::MAX };
+    select_nullifiers_by_block_range_page(conn, block_start, block_end, nullifier_prefixes, page)
+        .map(|page| page.items)
+}
+
+/// Select a page of the nullifiers created between `(block_start, block_end]` that also match the
+/// `nullifier_prefixes` filter using the given [Connection].
+///
+/// # Returns
+///
+/// The page of [NullifierInfo] with the nullifiers and the block height at which they were
+/// created, sorted by block number and nullifier, or an error.
+pub fn select_nullifiers_by_block_range_page(
+    conn: &mut Connection,
+    block_start: BlockNumber,
+    block_end: BlockNumber,
+    nullifier_prefixes: &[u32],
+    page: PageRequest<NullifierSyncKey>,
+) -> Result<Page<NullifierInfo, NullifierSyncKey>> {
     let nullifier_prefixes: Vec<Value> =
         nullifier_prefixes.iter().copied().map(u32_to_value).collect();
 
@@ -288,13 +921,24 @@ pub fn select_nullifiers_by_block_range(
         WHERE
             block_num > ?1 AND
             block_num <= ?2 AND
-            nullifier_prefix IN rarray(?3)
+            nullifier_prefix IN rarray(?3) AND
+            (?4 IS NULL OR (block_num, nullifier) > (?4, ?5))
         ORDER BY
-            block_num ASC
+            block_num ASC,
+            nullifier ASC
+        LIMIT
+            ?6;
     ",
     )?;
 
-    let mut rows = stmt.query(params![block_start, block_end, Rc::new(nullifier_prefixes)])?;
+    let mut rows = stmt.query(params![
+        block_start,
+        block_end,
+        Rc::new(nullifier_prefixes),
+        page.after.map(|key| key.block_num),
+        page.after.map(|key| key.nullifier.to_bytes()),
+        page_limit(page.size),
+    ])?;
 
     let mut result = Vec::new();
     while let Some(row) = rows.next()? {
@@ -303,7 +947,7 @@ pub fn select_nullifiers_by_block_range(
         let block_num = row.get(1)?;
         result.push(NullifierInfo { nullifier, block_num });
     }
-    Ok(result)
+    Ok(into_page(result, page.size, NullifierSyncKey::from))
 }
 
 // NOTE QUERIES
@@ -338,34 +982,133 @@ pub fn select_notes(conn: &mut Connection) -> Result<Vec<Note>> {
 
     let mut notes = vec![];
     while let Some(row) = rows.next()? {
-        let note_id_data = row.get_ref(3)?.as_blob()?;
-        let note_id = RpoDigest::read_from_bytes(note_id_data)?;
+        notes.push(note_from_row(row)?)
+    }
+    Ok(notes)
+}
 
-        let merkle_path_data = row.get_ref(7)?.as_blob()?;
-        let merkle_path = MerklePath::read_from_bytes(merkle_path_data)?;
+/// Select the notes created up to `block_num` which were not consumed as of `block_num` from the
+/// DB using the given [Connection]. Pruned notes are skipped.
+///
+/// # Returns
+///
+/// The notes sorted by block number and index in the block, or an error.
+pub fn select_unconsumed_notes(conn: &mut Connection, block_num: BlockNumber) -> Result<Vec<Note>> {
+    let mut stmt = conn.prepare(
+        "
+        SELECT
+            block_num,
+            batch_index,
+            note_index,
+            note_hash,
+            note_type,
+            sender,
+            tag,
+            merkle_path,
+            details
+        FROM
+            notes
+        WHERE
+            block_num <= ?1 AND
+            (consumed_block_num IS NULL OR consumed_block_num > ?1) AND
+            pruned = 0
+        ORDER BY
+            block_num ASC,
+            batch_index ASC,
+            note_index ASC;
+        ",
+    )?;
+    let mut rows = stmt.query(params![block_num])?;
 
-        let details_data = row.get_ref(8)?.as_blob_or_null()?;
-        let details = details_data.map(<Vec<u8>>::read_from_bytes).transpose()?;
+    let mut notes = vec![];
+    while let Some(row) = rows.next()? {
+        notes.push(note_from_row(row)?)
+    }
+    Ok(notes)
+}
 
-        notes.push(Note {
-            block_num: row.get(0)?,
-            note_created: NoteCreated {
-                batch_index: row.get(1)?,
-                note_index: row.get(2)?,
-                note_id,
-                note_type: row.get::<_, u8>(4)?.try_into()?,
-                sender: column_value_as_u64(row, 5)?,
-                tag: row.get(6)?,
-                details,
-            },
+/// Select a page of the notes matching the `filter` from the DB using the given [Connection].
+///
+/// # Returns
+///
+/// The page of notes sorted by block number and index in the block, or an error.
+pub fn select_notes_page(
+    conn: &mut Connection,
+    filter: &NoteFilter,
+    page: PageRequest<NoteKey>,
+) -> Result<Page<Note, NoteKey>> {
+    let mut stmt = conn.prepare(
+        "
+        SELECT
+            block_num,
+            batch_index,
+            note_index,
+            note_hash,
+            note_type,
+            sender,
+            tag,
             merkle_path,
-        })
+            details,
+            pruned
+        FROM
+            notes
+        WHERE
+            (?1 IS NULL OR block_num >= ?1) AND
+            (?2 IS NULL OR block_num <= ?2) AND
+            (?3 IS NULL OR sender = ?3) AND
+            (?4 IS NULL OR tag = ?4) AND
+            (?5 IS NULL OR note_type = ?5) AND
+            (?6 IS NULL OR (block_num, batch_index, note_index) > (?6, ?7, ?8)) AND
+            (?10 IS NULL OR recipient = ?10) AND
+            (?11 IS NULL OR script_root = ?11) AND
+            (?12 IS NULL OR EXISTS (
+                SELECT
+                    1
+                FROM
+                    note_assets AS assets
+                WHERE
+                    assets.faucet_id = ?12 AND
+                    assets.block_num = notes.block_num AND
+                    assets.batch_index = notes.batch_index AND
+                    assets.note_index = notes.note_index
+            ))
+        ORDER BY
+            block_num ASC,
+            batch_index ASC,
+            note_index ASC
+        LIMIT
+            ?9;
+        ",
+    )?;
+    let mut rows = stmt.query(params![
+        filter.block_num_from,
+        filter.block_num_to,
+        filter.sender.map(u64_to_value),
+        filter.tag,
+        filter.note_type.map(|note_type| note_type as u8),
+        page.after.map(|key| key.block_num),
+        page.after.map(|key| key.batch_index),
+        page.after.map(|key| key.note_index),
+        page_limit(page.size),
+        filter.recipient.map(|recipient| recipient.to_bytes()),
+        filter.script_root.map(|script_root| script_root.to_bytes()),
+        filter.faucet_id.map(u64_to_value),
+    ])?;
+
+    let mut notes = vec![];
+    while let Some(row) = rows.next()? {
+        notes.push(unpruned_note_from_row(row)?)
     }
-    Ok(notes)
+    Ok(into_page(notes, page.size, NoteKey::from))
 }
 
 /// Insert notes to the DB using the given [Transaction].
 ///
+/// The public details of the notes are parsed to index their nullifier, recipient, script root
+/// and the faucets of their fungible assets. The nullifier allows to mark the notes as consumed
+/// once it is added to the DB. Details which can't be deserialized into a note are stored as is,
+/// without being indexed.
+///
 /// # Returns
 ///
 /// The number of affected rows.
@@ -388,17 +1131,36 @@ pub fn insert_notes(transaction: &Transaction, notes: &[Note]) -> Result<usize>
             sender,
             tag,
             merkle_path,
-            details
+            details,
+            nullifier,
+            consumed_block_num,
+            recipient,
+            script_root
         )
         VALUES
         (
-            ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9
+            ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10,
+            (SELECT block_num FROM nullifiers WHERE nullifier = ?10),
+            ?11, ?12
         );",
     )?;
+    let mut asset_stmt = transaction.prepare(
+        "
+        INSERT OR IGNORE INTO
+            note_assets (block_num, batch_index, note_index, faucet_id)
+        VALUES
+            (?1, ?2, ?3, ?4);
+        ",
+    )?;
 
     let mut count = 0;
     for note in notes.iter() {
         let details = note.note_created.details.as_ref().map(|details| details.to_bytes());
+        let note_data = note
+            .note_created
+            .details
+            .as_deref()
+            .and_then(|details| NoteData::read_from_bytes(details).ok());
 
         count += stmt.execute(params![
             note.block_num,
@@ -409,8 +1171,109 @@ pub fn insert_notes(transaction: &Transaction, notes: &[Note]) -> Result<usize>
             u64_to_value(note.note_created.sender),
             note.note_created.tag,
             note.merkle_path.to_bytes(),
+            details,
+            note_data.as_ref().map(|data| data.nullifier().to_bytes()),
+            note_data.as_ref().map(|data| data.recipient().to_bytes()),
+            note_data.as_ref().map(|data| data.script().hash().to_bytes()),
+        ])?;
+
+        for faucet_id in note_data.iter().flat_map(fungible_faucet_ids) {
+            asset_stmt.execute(params![
+                note.block_num,
+                note.note_created.batch_index,
+                note.note_created.note_index,
+                u64_to_value(faucet_id),
+            ])?;
+        }
+    }
+
+    Ok(count)
+}
+
+/// Indexes the public details of the notes which aren't indexed yet, using the given
+/// [Transaction].
+///
+/// This covers the notes stored before their nullifier, recipient, script root and assets were
+/// indexed, see [insert_notes]. Notes whose nullifier was already added to the DB are marked as
+/// consumed in the block of the nullifier.
+///
+/// # Returns
+///
+/// The number of indexed notes.
+///
+/// # Note
+///
+/// The [Transaction] object is not consumed. It's up to the caller to commit or rollback the
+/// transaction.
+pub fn index_note_details(transaction: &Transaction) -> Result<usize> {
+    let mut select_stmt = transaction.prepare(
+        "
+        SELECT
+            block_num,
+            batch_index,
+            note_index,
             details
+        FROM
+            notes
+        WHERE
+            details IS NOT NULL AND
+            recipient IS NULL;
+        ",
+    )?;
+    let mut rows = select_stmt.query([])?;
+    let mut notes: Vec<(BlockNumber, u32, u32, Vec<u8>)> = vec![];
+    while let Some(row) = rows.next()? {
+        notes.push((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?));
+    }
+
+    let mut update_stmt = transaction.prepare(
+        "
+        UPDATE
+            notes
+        SET
+            nullifier = ?4,
+            consumed_block_num = (SELECT block_num FROM nullifiers WHERE nullifier = ?4),
+            recipient = ?5,
+            script_root = ?6
+        WHERE
+            block_num = ?1 AND
+            batch_index = ?2 AND
+            note_index = ?3;
+        ",
+    )?;
+    let mut asset_stmt = transaction.prepare(
+        "
+        INSERT OR IGNORE INTO
+            note_assets (block_num, batch_index, note_index, faucet_id)
+        VALUES
+            (?1, ?2, ?3, ?4);
+        ",
+    )?;
+
+    let mut count = 0;
+    for (block_num, batch_index, note_index, details) in notes {
+        // details which can't be deserialized into a note are left as is, like in `insert_notes`
+        let Ok(note_data) = NoteData::read_from_bytes(&details) else {
+            continue;
+        };
+
+        count += update_stmt.execute(params![
+            block_num,
+            batch_index,
+            note_index,
+            note_data.nullifier().to_bytes(),
+            note_data.recipient().to_bytes(),
+            note_data.script().hash().to_bytes(),
         ])?;
+
+        for faucet_id in fungible_faucet_ids(&note_data) {
+            asset_stmt.execute(params![
+                block_num,
+                batch_index,
+                note_index,
+                u64_to_value(faucet_id),
+            ])?;
+        }
     }
 
     Ok(count)
@@ -427,7 +1290,8 @@ pub fn insert_notes(transaction: &Transaction, notes: &[Note]) -> Result<usize>
 /// # Note
 ///
 /// This method returns notes from a single block. To fetch all notes up to the chain tip,
-/// multiple requests are necessary.
+/// multiple requests are necessary. Pruned notes are returned with an empty Merkle path and
+/// without details, so that the sync can move past them.
 pub fn select_notes_since_block_by_tag_and_sender(
     conn: &mut Connection,
     tags: &[u32],
@@ -505,14 +1369,25 @@ pub fn select_notes_since_block_by_tag_and_sender(
     Ok(res)
 }
 
-/// Select Note's matching the NoteId using the given [Connection].
+/// Select notes matching the tags or senders in the first `max_blocks` blocks after `block_num`
+/// which contain such notes, using the given [Connection].
 ///
 /// # Returns
 ///
-/// - Empty vector if no matching `note`.
-/// - Otherwise, notes which `note_hash` matches the `NoteId` as bytes.
-pub fn select_notes_by_id(conn: &mut Connection, note_ids: &[NoteId]) -> Result<Vec<Note>> {
-    let note_ids: Vec<Value> = note_ids.iter().map(|id| id.to_bytes().into()).collect();
+/// - Empty vector if no note created after `block_num` match `tags` or `account_ids`.
+/// - Otherwise, notes which the 16 high bits match `tags`, or the `sender` is one of the
+///   `account_ids`, ordered by block number and index in the block. Pruned notes are returned with
+///   an empty Merkle path and without details.
+pub fn select_notes_in_blocks_by_tag_and_sender(
+    conn: &mut Connection,
+    tags: &[u32],
+    account_ids: &[AccountId],
+    block_num: BlockNumber,
+    max_blocks: usize,
+) -> Result<Vec<Note>> {
+    let tags: Vec<Value> = tags.iter().copied().map(u32_to_value).collect();
+    let account_ids: Vec<Value> = account_ids.iter().copied().map(u64_to_value).collect();
+    let max_blocks: i64 = max_blocks.try_into().unwrap_or(i64::MAX);
 
     let mut stmt = conn.prepare(
         "
@@ -525,19 +1400,80 @@ pub fn select_notes_by_id(conn: &mut Connection, note_ids: &[NoteId]) -> Result<
             sender,
             tag,
             merkle_path,
-            details
+            details,
+            pruned
         FROM
             notes
         WHERE
-            note_hash IN rarray(?1)
-        ",
-    )?;
+            -- find the next blocks which contain at least one note with a matching tag
+            block_num IN (
+                SELECT DISTINCT
+                    block_num
+                FROM
+                    notes
+                WHERE
+                    (tag IN rarray(?1) OR sender IN rarray(?2)) AND
+                    block_num > ?3
+                ORDER BY
+                    block_num ASC
+                LIMIT
+                    ?4
+            ) AND
+            -- filter the blocks' notes and return only the ones matching the requested tags
+            (tag IN rarray(?1) OR sender IN rarray(?2))
+        ORDER BY
+            block_num ASC,
+            batch_index ASC,
+            note_index ASC;
+    ",
+    )?;
+    let mut rows =
+        stmt.query(params![Rc::new(tags), Rc::new(account_ids), block_num, max_blocks])?;
+
+    let mut res = Vec::new();
+    while let Some(row) = rows.next()? {
+        res.push(unpruned_note_from_row(row)?);
+    }
+    Ok(res)
+}
+
+/// Select Note's matching the NoteId using the given [Connection].
+///
+/// # Returns
+///
+/// - Empty vector if no matching `note`.
+/// - Otherwise, notes which `note_hash` matches the `NoteId` as bytes.
+pub fn select_notes_by_id(conn: &mut Connection, note_ids: &[NoteId]) -> Result<Vec<Note>> {
+    let note_ids: Vec<Value> = note_ids.iter().map(|id| id.to_bytes().into()).collect();
+
+    let mut stmt = conn.prepare(
+        "
+        SELECT
+            block_num,
+            batch_index,
+            note_index,
+            note_hash,
+            note_type,
+            sender,
+            tag,
+            merkle_path,
+            details,
+            pruned
+        FROM
+            notes
+        WHERE
+            note_hash IN rarray(?1)
+        ",
+    )?;
     let mut rows = stmt.query(params![Rc::new(note_ids)])?;
 
     let mut notes = Vec::new();
     while let Some(row) = rows.next()? {
         let note_id_data = row.get_ref(3)?.as_blob()?;
         let note_id = NoteId::read_from_bytes(note_id_data)?;
+        if row.get(9)? {
+            return Err(DatabaseError::NotePruned(note_id.into()));
+        }
 
         let merkle_path_data = row.get_ref(7)?.as_blob()?;
         let merkle_path = MerklePath::read_from_bytes(merkle_path_data)?;
@@ -562,6 +1498,63 @@ pub fn select_notes_by_id(conn: &mut Connection, note_ids: &[NoteId]) -> Result<
     Ok(notes)
 }
 
+/// Select the inclusion and consumption status of the notes matching the NoteId using the given
+/// [Connection].
+///
+/// # Returns
+///
+/// - Empty vector if no matching `note`.
+/// - Otherwise, the status of the notes which `note_hash` matches the `NoteId` as bytes.
+pub fn select_note_statuses_by_id(
+    conn: &mut Connection,
+    note_ids: &[NoteId],
+) -> Result<Vec<NoteStatus>> {
+    let note_ids: Vec<Value> = note_ids.iter().map(|id| id.to_bytes().into()).collect();
+
+    let mut stmt = conn.prepare(
+        "
+        SELECT
+            note_hash,
+            block_num,
+            batch_index,
+            note_index,
+            merkle_path,
+            consumed_block_num,
+            pruned
+        FROM
+            notes
+        WHERE
+            note_hash IN rarray(?1)
+        ",
+    )?;
+    let mut rows = stmt.query(params![Rc::new(note_ids)])?;
+
+    let mut statuses = Vec::new();
+    while let Some(row) = rows.next()? {
+        let note_id_data = row.get_ref(0)?.as_blob()?;
+        let note_id = RpoDigest::read_from_bytes(note_id_data)?;
+        if row.get(6)? {
+            return Err(DatabaseError::NotePruned(note_id));
+        }
+
+        let batch_index: u32 = row.get(2)?;
+        let note_index: u32 = row.get(3)?;
+
+        let merkle_path_data = row.get_ref(4)?.as_blob()?;
+        let merkle_path = MerklePath::read_from_bytes(merkle_path_data)?;
+
+        statuses.push(NoteStatus {
+            note_id,
+            block_num: row.get(1)?,
+            note_index: BlockNoteTree::note_index(batch_index as usize, note_index as usize) as u32,
+            merkle_path,
+            consumed_block_num: row.get(5)?,
+        });
+    }
+
+    Ok(statuses)
+}
+
 // BLOCK CHAIN QUERIES
 // ================================================================================================
 
@@ -614,12 +1607,74 @@ pub fn select_block_header_by_block_num(
     }
 }
 
+/// Insert the data of a block to the DB using the given [Transaction].
+///
+/// `block_data` is kept as-is, so that the block can later be returned exactly as it was applied.
+///
+/// # Returns
+///
+/// The number of affected rows.
+///
+/// # Note
+///
+/// The [Transaction] object is not consumed. It's up to the caller to commit or rollback the
+/// transaction.
+pub fn insert_block(
+    transaction: &Transaction,
+    block_header: &BlockHeader,
+    block_data: &[u8],
+) -> Result<usize> {
+    let mut stmt = transaction
+        .prepare("INSERT INTO blocks (block_num, block_hash, block) VALUES (?1, ?2, ?3);")?;
+    Ok(stmt.execute(params![
+        block_header.block_num(),
+        block_header.hash().to_bytes(),
+        block_data
+    ])?)
+}
+
+/// Select the data of a block from the DB by its `block_num` using the given [Connection].
+///
+/// # Returns
+///
+/// The block data as it was applied, or [None] if the block is not in the DB.
+pub fn select_block_by_num(
+    conn: &mut Connection,
+    block_num: BlockNumber,
+) -> Result<Option<Vec<u8>>> {
+    let mut stmt = conn.prepare("SELECT block FROM blocks WHERE block_num = ?1")?;
+    let mut rows = stmt.query([block_num])?;
+
+    match rows.next()? {
+        Some(row) => Ok(Some(row.get(0)?)),
+        None => Ok(None),
+    }
+}
+
+/// Select the data of a block from the DB by the hash of its header using the given [Connection].
+///
+/// # Returns
+///
+/// The block data as it was applied, or [None] if the block is not in the DB.
+pub fn select_block_by_hash(
+    conn: &mut Connection,
+    block_hash: &RpoDigest,
+) -> Result<Option<Vec<u8>>> {
+    let mut stmt = conn.prepare("SELECT block FROM blocks WHERE block_hash = ?1")?;
+    let mut rows = stmt.query(params![block_hash.to_bytes()])?;
+
+    match rows.next()? {
+        Some(row) => Ok(Some(row.get(0)?)),
+        None => Ok(None),
+    }
+}
+
 /// Select all block headers from the DB using the given [Connection].
 ///
 /// # Returns
 ///
 /// A vector of [BlockHeader] or an error.
-pub fn select_block_headers(conn: &mut Connection) -> Result<Vec<BlockHeader>> {
+pub fn select_block_headers(conn: &Connection) -> Result<Vec<BlockHeader>> {
     let mut stmt =
         conn.prepare("SELECT block_header FROM block_headers ORDER BY block_num ASC;")?;
     let mut rows = stmt.query([])?;
@@ -633,6 +1688,198 @@ pub fn select_block_headers(conn: &mut Connection) -> Result<Vec<BlockHeader>> {
     Ok(result)
 }
 
+/// Select the block headers after `block_num` from the DB using the given [Connection].
+///
+/// # Returns
+///
+/// A vector of [BlockHeader] ordered by block number, or an error.
+pub fn select_block_headers_since(
+    conn: &mut Connection,
+    block_num: BlockNumber,
+) -> Result<Vec<BlockHeader>> {
+    let mut stmt = conn.prepare(
+        "SELECT block_header FROM block_headers WHERE block_num > ?1 ORDER BY block_num ASC;",
+    )?;
+    let mut rows = stmt.query([block_num])?;
+    let mut result = vec![];
+    while let Some(row) = rows.next()? {
+        let block_header_data = row.get_ref(0)?.as_blob()?;
+        let block_header = BlockHeader::read_from_bytes(block_header_data)?;
+        result.push(block_header);
+    }
+
+    Ok(result)
+}
+
+/// Select the block headers with the given block numbers from the DB using the given
+/// [Connection].
+///
+/// # Returns
+///
+/// A vector of [BlockHeader] ordered by block number, unknown block numbers are skipped.
+pub fn select_block_headers_by_block_nums(
+    conn: &mut Connection,
+    block_nums: &[BlockNumber],
+) -> Result<Vec<BlockHeader>> {
+    let block_nums: Vec<Value> = block_nums.iter().copied().map(u32_to_value).collect();
+
+    let mut stmt = conn.prepare(
+        "SELECT block_header FROM block_headers WHERE block_num IN rarray(?1) ORDER BY block_num ASC;",
+    )?;
+    let mut rows = stmt.query(params![Rc::new(block_nums)])?;
+    let mut result = vec![];
+    while let Some(row) = rows.next()? {
+        let block_header_data = row.get_ref(0)?.as_blob()?;
+        let block_header = BlockHeader::read_from_bytes(block_header_data)?;
+        result.push(block_header);
+    }
+
+    Ok(result)
+}
+
+/// Select the block headers starting at `block_num` from the DB using the given [Connection],
+/// together with the number of account updates, nullifiers and notes of each block.
+///
+/// # Returns
+///
+/// A vector of at most `limit` [BlockSummary] ordered by block number, or an error.
+pub fn select_block_summaries(
+    conn: &mut Connection,
+    block_num: BlockNumber,
+    limit: usize,
+) -> Result<Vec<BlockSummary>> {
+    let mut stmt = conn.prepare(
+        "
+        SELECT
+            block_header,
+            (SELECT COUNT(*) FROM account_history WHERE block_num = block_headers.block_num),
+            (SELECT COUNT(*) FROM nullifiers WHERE block_num = block_headers.block_num),
+            (SELECT COUNT(*) FROM notes WHERE block_num = block_headers.block_num)
+        FROM
+            block_headers
+        WHERE
+            block_num >= ?1
+        ORDER BY
+            block_num ASC
+        LIMIT
+            ?2;
+        ",
+    )?;
+    let mut rows = stmt.query(params![block_num, limit])?;
+    let mut result = vec![];
+    while let Some(row) = rows.next()? {
+        let block_header_data = row.get_ref(0)?.as_blob()?;
+        let block_header = BlockHeader::read_from_bytes(block_header_data)?;
+        result.push(BlockSummary {
+            block_header,
+            num_account_updates: row.get(1)?,
+            num_nullifiers: row.get(2)?,
+            num_notes: row.get(3)?,
+        });
+    }
+
+    Ok(result)
+}
+
+// TRANSACTION QUERIES
+// ================================================================================================
+
+/// Insert the headers of the transactions included in a block to the DB using the given
+/// [Transaction].
+///
+/// The position of each transaction within the block is given by its index in `transactions`.
+///
+/// # Returns
+///
+/// The number of affected rows.
+///
+/// # Note
+///
+/// The [Transaction] object is not consumed. It's up to the caller to commit or rollback the
+/// transaction.
+pub fn insert_transactions(
+    transaction: &Transaction,
+    transactions: &[TransactionHeader],
+    block_num: BlockNumber,
+) -> Result<usize> {
+    let mut stmt = transaction.prepare(
+        "
+        INSERT INTO
+            transactions
+        (
+            transaction_id,
+            block_num,
+            position,
+            account_id,
+            initial_account_hash,
+            final_account_hash,
+            input_notes,
+            output_notes
+        )
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8);",
+    )?;
+
+    let mut count = 0;
+    for (position, header) in transactions.iter().enumerate() {
+        count += stmt.execute(params![
+            header.id.inner().to_bytes(),
+            block_num,
+            position as u32,
+            u64_to_value(header.account_id.into()),
+            header.initial_account_hash.to_bytes(),
+            header.final_account_hash.to_bytes(),
+            header.input_notes.to_bytes(),
+            header.output_notes.to_bytes(),
+        ])?;
+    }
+    Ok(count)
+}
+
+/// Select the inclusion records of the transactions matching the given IDs using the given
+/// [Connection].
+///
+/// # Returns
+///
+/// A vector with the block and position of each of the requested transactions found in the DB.
+/// Transactions which were not included in any block are omitted.
+pub fn select_transactions_by_id(
+    conn: &mut Connection,
+    transaction_ids: &[TransactionId],
+) -> Result<Vec<TransactionInclusion>> {
+    let transaction_ids: Vec<Value> =
+        transaction_ids.iter().map(|id| id.inner().to_bytes().into()).collect();
+
+    let mut stmt = conn.prepare(
+        "
+        SELECT
+            transaction_id,
+            block_num,
+            position
+        FROM
+            transactions
+        WHERE
+            transaction_id IN rarray(?1)
+        ORDER BY
+            block_num ASC,
+            position ASC
+        ",
+    )?;
+    let mut rows = stmt.query(params![Rc::new(transaction_ids)])?;
+
+    let mut result = Vec::new();
+    while let Some(row) = rows.next()? {
+        let transaction_id_data = row.get_ref(0)?.as_blob()?;
+        let transaction_id = RpoDigest::read_from_bytes(transaction_id_data)?;
+
+        result.push(TransactionInclusion {
+            transaction_id: transaction_id.into(),
+            block_num: row.get(1)?,
+            position: row.get(2)?,
+        });
+    }
+    Ok(result)
+}
+
 // STATE SYNC
 // ================================================================================================
 
@@ -685,6 +1932,46 @@ pub fn get_state_sync(
     })
 }
 
+/// Loads the notes of a note sync covering up to `max_blocks` blocks with matching notes.
+///
+/// The sync ends at the last of these blocks if there are `max_blocks` of them, otherwise it
+/// covers all the blocks up to the chain tip.
+pub fn get_note_sync(
+    conn: &mut Connection,
+    block_num: BlockNumber,
+    note_tag_prefixes: &[u32],
+    account_ids: &[AccountId],
+    max_blocks: usize,
+) -> Result<NoteSyncUpdate, StateSyncError> {
+    let notes = select_notes_in_blocks_by_tag_and_sender(
+        conn,
+        note_tag_prefixes,
+        account_ids,
+        block_num,
+        max_blocks,
+    )?;
+
+    let mut block_nums: Vec<BlockNumber> = notes.iter().map(|note| note.block_num).collect();
+    block_nums.dedup();
+    let block_headers = select_block_headers_by_block_nums(conn, &block_nums)?;
+
+    let tip = select_block_header_by_block_num(conn, None)?
+        .ok_or(StateSyncError::EmptyBlockHeadersTable)?;
+    let chain_tip = tip.block_num();
+
+    let block_header = match block_headers.last() {
+        Some(last) if block_headers.len() >= max_blocks => *last,
+        _ => tip,
+    };
+
+    Ok(NoteSyncUpdate {
+        notes,
+        block_headers,
+        block_header,
+        chain_tip,
+    })
+}
+
 // APPLY BLOCK
 // ================================================================================================
 
@@ -693,18 +1980,259 @@ pub fn get_state_sync(
 /// # Returns
 ///
 /// The number of affected rows in the DB.
+#[allow(clippy::too_many_arguments)]
 pub fn apply_block(
     transaction: &Transaction,
+    cache: &AccountCache,
+    checkpoint_interval: u32,
     block_header: &BlockHeader,
+    block_data: &[u8],
     notes: &[Note],
     nullifiers: &[Nullifier],
     accounts: &[AccountUpdateDetails],
+    transactions: &[TransactionHeader],
 ) -> Result<usize> {
     let mut count = 0;
     count += insert_block_header(transaction, block_header)?;
+    count += insert_block(transaction, block_header, block_data)?;
     count += insert_notes(transaction, notes)?;
-    count += upsert_accounts(transaction, accounts, block_header.block_num())?;
+    count += upsert_accounts(
+        transaction,
+        cache,
+        accounts,
+        block_header.block_num(),
+        checkpoint_interval,
+    )?;
     count += insert_nullifiers_for_block(transaction, nullifiers, block_header.block_num())?;
+    count += insert_transactions(transaction, transactions, block_header.block_num())?;
+    Ok(count)
+}
+
+// IMPORT
+// ================================================================================================
+
+/// Inserts the state of an exported archive into an empty DB using the given [Transaction].
+///
+/// Each account is inserted with a single history record, holding its state as of the block of its
+/// latest update as a checkpoint. The archive doesn't contain the data of the blocks nor their
+/// transactions, so the `blocks` and `transactions` tables are left empty.
+///
+/// # Returns
+///
+/// The number of affected rows in the DB.
+///
+/// # Note
+///
+/// The [Transaction] object is not consumed. It's up to the caller to commit or rollback the
+/// transaction.
+pub fn import_state(
+    transaction: &Transaction,
+    block_headers: &[BlockHeader],
+    accounts: &[AccountInfo],
+    notes: &[Note],
+    nullifiers: &[(Nullifier, BlockNumber)],
+) -> Result<usize> {
+    let mut count = 0;
+    for block_header in block_headers.iter() {
+        count += insert_block_header(transaction, block_header)?;
+    }
+
+    let mut insert_account_stmt = transaction.prepare(
+        "INSERT INTO accounts (account_id, account_hash, block_num) VALUES (?1, ?2, ?3);",
+    )?;
+    let mut insert_history_stmt = transaction.prepare(
+        "INSERT INTO account_history (account_id, block_num, account_hash, details) VALUES (?1, ?2, ?3, ?4);",
+    )?;
+    for AccountInfo { summary, details } in accounts.iter() {
+        let account_id = u64_to_value(summary.account_id.into());
+        let account_hash = summary.account_hash.to_bytes();
+        let details = details.as_ref().map(|account| account.to_bytes());
+
+        count +=
+            insert_account_stmt.execute(params![account_id, account_hash, summary.block_num])?;
+        insert_history_stmt.execute(params![
+            account_id,
+            summary.block_num,
+            account_hash,
+            details,
+        ])?;
+    }
+
+    index_account_metadata(transaction)?;
+
+    count += insert_notes(transaction, notes)?;
+
+    let mut nullifiers_by_block = BTreeMap::<BlockNumber, Vec<Nullifier>>::new();
+    for (nullifier, block_num) in nullifiers.iter() {
+        nullifiers_by_block.entry(*block_num).or_default().push(*nullifier);
+    }
+    for (block_num, nullifiers) in nullifiers_by_block {
+        count += insert_nullifiers_for_block(transaction, &nullifiers, block_num)?;
+    }
+
+    Ok(count)
+}
+
+// ROLLBACK
+// ================================================================================================
+
+/// Deletes the data of all the blocks after `block_num` from the DB using the given
+/// [Transaction].
+///
+/// Accounts updated after `block_num` are restored to their latest state at or before that block
+/// from the account history, and accounts created after it are removed. Fails if one of the states
+/// to restore was pruned.
+///
+/// # Returns
+///
+/// The number of affected rows in the DB.
+///
+/// # Note
+///
+/// The [Transaction] object is not consumed. It's up to the caller to commit or rollback the
+/// transaction.
+pub fn rollback_to(transaction: &Transaction, block_num: BlockNumber) -> Result<usize> {
+    let pruned_account_id = transaction
+        .query_row(
+            "
+            SELECT
+                history.account_id
+            FROM
+                account_history AS history
+            WHERE
+                history.pruned = 1 AND
+                history.account_id IN (SELECT account_id FROM accounts WHERE block_num > ?1) AND
+                history.block_num = (
+                    SELECT
+                        MAX(block_num)
+                    FROM
+                        account_history
+                    WHERE
+                        account_id = history.account_id AND
+                        block_num <= ?1
+                )
+            LIMIT 1
+            ",
+            [block_num],
+            |row| column_value_as_u64(row, 0),
+        )
+        .optional()?;
+    if let Some(account_id) = pruned_account_id {
+        return Err(DatabaseError::AccountStatePruned { account_id, block_num });
+    }
+
+    let mut count = transaction.execute(
+        "
+        INSERT OR REPLACE INTO
+            accounts (account_id, account_hash, block_num)
+        SELECT
+            history.account_id,
+            history.account_hash,
+            history.block_num
+        FROM
+            account_history AS history
+        WHERE
+            history.account_id IN (SELECT account_id FROM accounts WHERE block_num > ?1) AND
+            history.block_num = (
+                SELECT
+                    MAX(block_num)
+                FROM
+                    account_history
+                WHERE
+                    account_id = history.account_id AND
+                    block_num <= ?1
+            )
+        ",
+        [block_num],
+    )?;
+
+    count += transaction.execute(
+        "UPDATE notes SET consumed_block_num = NULL WHERE consumed_block_num > ?1;",
+        [block_num],
+    )?;
+
+    // Tables referencing `block_headers` must be cleaned up first to satisfy the foreign keys.
+    for table in [
+        "accounts",
+        "account_history",
+        "transactions",
+        "nullifiers",
+        "note_assets",
+        "notes",
+        "blocks",
+        "block_headers",
+    ] {
+        count += transaction
+            .execute(&format!("DELETE FROM {table} WHERE block_num > ?1;"), [block_num])?;
+    }
+
+    // the restored accounts are reinserted without metadata
+    index_account_metadata(transaction)?;
+
+    Ok(count)
+}
+
+// PRUNING
+// ================================================================================================
+
+/// Prunes the data of old blocks up to the `target` from the DB using the given [Transaction].
+///
+/// The Merkle paths and details of the pruned notes are dropped, while the note IDs and metadata
+/// needed to rebuild the note roots are kept. The details and deltas of the pruned account states
+/// are dropped, keeping the account hashes and the states which can be rebuilt without them, i.e.
+/// the states from the latest checkpoint of each account at or before `target.account_history`.
+/// The pruned rows are flagged, so that queries fail instead of returning incomplete data.
+///
+/// # Returns
+///
+/// The number of pruned rows.
+///
+/// # Note
+///
+/// The [Transaction] object is not consumed. It's up to the caller to commit or rollback the
+/// transaction.
+pub fn prune(transaction: &Transaction, target: &PruningTarget) -> Result<usize> {
+    let pruned_merkle_path = MerklePath::new(Vec::new()).to_bytes();
+    let mut count = transaction.execute(
+        "
+        UPDATE
+            notes
+        SET
+            merkle_path = ?3,
+            details = NULL,
+            pruned = 1
+        WHERE
+            pruned = 0 AND
+            (block_num <= ?1 OR consumed_block_num <= ?2)
+        ",
+        params![target.notes, target.consumed_notes, pruned_merkle_path],
+    )?;
+
+    count += transaction.execute(
+        "
+        UPDATE
+            account_history
+        SET
+            details = NULL,
+            delta = NULL,
+            pruned = 1
+        WHERE
+            pruned = 0 AND
+            block_num < (
+                SELECT
+                    MAX(block_num)
+                FROM
+                    account_history AS base
+                WHERE
+                    base.account_id = account_history.account_id AND
+                    base.block_num <= ?1 AND
+                    base.pruned = 0 AND
+                    (base.details IS NOT NULL OR base.delta IS NULL)
+            )
+        ",
+        [target.account_history],
+    )?;
+
     Ok(count)
 }
 
@@ -744,6 +2272,60 @@ fn column_value_as_u64<I: rusqlite::RowIndex>(
     Ok(value as u64)
 }
 
+/// Returns the `LIMIT` of a page query, which selects one more row than the page size to tell
+/// whether there is a next page.
+fn page_limit(page_size: usize) -> i64 {
+    page_size.saturating_add(1).try_into().unwrap_or(i64::MAX)
+}
+
+/// Returns the faucets of the fungible assets of the note.
+fn fungible_faucet_ids(note_data: &NoteData) -> impl Iterator<Item = AccountId> + '_ {
+    note_data.assets().iter().filter_map(|asset| match asset {
+        Asset::Fungible(asset) => Some(asset.faucet_id().into()),
+        Asset::NonFungible(_) => None,
+    })
+}
+
+/// Constructs `Note` from the row of `notes` table.
+///
+/// Note: field ordering must be the same, as in `notes` table!
+fn note_from_row(row: &rusqlite::Row<'_>) -> Result<Note> {
+    let note_id_data = row.get_ref(3)?.as_blob()?;
+    let note_id = RpoDigest::read_from_bytes(note_id_data)?;
+
+    let merkle_path_data = row.get_ref(7)?.as_blob()?;
+    let merkle_path = MerklePath::read_from_bytes(merkle_path_data)?;
+
+    let details_data = row.get_ref(8)?.as_blob_or_null()?;
+    let details = details_data.map(<Vec<u8>>::read_from_bytes).transpose()?;
+
+    Ok(Note {
+        block_num: row.get(0)?,
+        note_created: NoteCreated {
+            batch_index: row.get(1)?,
+            note_index: row.get(2)?,
+            note_id,
+            note_type: row.get::<_, u8>(4)?.try_into()?,
+            sender: column_value_as_u64(row, 5)?,
+            tag: row.get(6)?,
+            details,
+        },
+        merkle_path,
+    })
+}
+
+/// Constructs `Note` from the row of `notes` table, followed by the `pruned` column.
+///
+/// Fails with [DatabaseError::NotePruned] if the Merkle path and details of the note were pruned.
+fn unpruned_note_from_row(row: &rusqlite::Row<'_>) -> Result<Note> {
+    let note = note_from_row(row)?;
+    if row.get(9)? {
+        return Err(DatabaseError::NotePruned(note.note_created.note_id));
+    }
+
+    Ok(note)
+}
+
 /// Constructs `AccountSummary` from the row of `accounts` table.
 ///
 /// Note: field ordering must be the same, as in `accounts` table!
@@ -760,32 +2342,183 @@ fn account_hash_update_from_row(row: &rusqlite::Row<'_>) -> Result<AccountSummar
     })
 }
 
-/// Constructs `AccountInfo` from the row of `accounts` table.
+/// Constructs [AccountMetadata] from a row of the `accounts` table selecting the account ID,
+/// hash, block number and then the metadata columns.
+fn account_metadata_from_row(row: &rusqlite::Row<'_>) -> Result<AccountMetadata> {
+    let summary = account_hash_update_from_row(row)?;
+
+    let kind = row.get::<_, u32>(3)?;
+    let kind = AccountKind::try_from(kind)
+        .map_err(|kind| DatabaseError::CorruptedData(format!("Invalid account type {kind}")))?;
+    let code_root = row.get_ref(7)?.as_blob_or_null()?;
+    let code_root = code_root.map(RpoDigest::read_from_bytes).transpose()?;
+
+    Ok(AccountMetadata {
+        summary,
+        kind,
+        on_chain: row.get(4)?,
+        created_block_num: row.get(5)?,
+        num_updates: row.get(6)?,
+        code_root,
+    })
+}
+
+/// Select the latest hash of an account from the DB using the given [Connection].
+fn select_account_summary(conn: &Connection, account_id: AccountId) -> Result<AccountSummary> {
+    let mut stmt = conn.prepare_cached(
+        "SELECT account_id, account_hash, block_num FROM accounts WHERE account_id = ?1;",
+    )?;
+
+    let mut rows = stmt.query(params![u64_to_value(account_id)])?;
+    let row = rows.next()?.ok_or(DatabaseError::AccountNotFoundInDb(account_id))?;
+
+    account_hash_update_from_row(row)
+}
+
+/// How [select_account_details] uses the [AccountCache].
+#[derive(Clone, Copy)]
+enum CacheUse<'a> {
+    /// The cache isn't used.
+    None,
+    /// Cached states are used, rebuilt states are not cached. Used by the queries listing many
+    /// accounts, which would otherwise evict the frequently used states.
+    Read(&'a AccountCache),
+    /// Cached states are used and rebuilt states are cached.
+    ReadWrite(&'a AccountCache),
+}
+
+impl CacheUse<'_> {
+    fn get(self, account_id: AccountId, account_hash: RpoDigest) -> Option<Account> {
+        match self {
+            CacheUse::None => None,
+            CacheUse::Read(cache) | CacheUse::ReadWrite(cache) => {
+                cache.get(account_id, account_hash)
+            },
+        }
+    }
+
+    fn insert(self, account: &Account) {
+        if let CacheUse::ReadWrite(cache) = self {
+            cache.insert(account.clone());
+        }
+    }
+}
+
+/// Completes the account `summaries` with the state of the on-chain accounts as of the block of
+/// each summary, using the given [Connection].
 ///
-/// Note: field ordering must be the same, as in `accounts` table!
-fn account_info_from_row(row: &rusqlite::Row<'_>) -> Result<AccountInfo> {
-    let update = account_hash_update_from_row(row)?;
+/// The state is taken from the `cache` if it holds the state with the summary's hash, otherwise it
+/// is rebuilt from the latest checkpoint at or before that block and the deltas applied after it.
+fn select_account_details(
+    conn: &Connection,
+    cache: CacheUse<'_>,
+    summaries: Vec<AccountSummary>,
+) -> Result<Vec<AccountInfo>> {
+    let mut stmt = conn.prepare_cached(
+        "
+        SELECT
+            block_num,
+            account_hash,
+            details,
+            delta,
+            pruned
+        FROM
+            account_history
+        WHERE
+            account_id = ?1 AND
+            block_num <= ?2 AND
+            block_num >= COALESCE(
+                (
+                    SELECT
+                        MAX(block_num)
+                    FROM
+                        account_history
+                    WHERE
+                        account_id = ?1 AND
+                        block_num <= ?2 AND
+                        details IS NOT NULL
+                ),
+                0
+            )
+        ORDER BY
+            block_num ASC
+        ",
+    )?;
+
+    summaries
+        .into_iter()
+        .map(|summary| {
+            if !summary.account_id.is_on_chain() {
+                return Ok(AccountInfo { summary, details: None });
+            }
+
+            let account_id = summary.account_id.into();
+            let details = match cache.get(account_id, summary.account_hash) {
+                Some(account) => Some(account),
+                None => {
+                    let account = rebuild_account(&mut stmt, account_id, summary.block_num)?;
+                    if let Some(account) = &account {
+                        cache.insert(account);
+                    }
+                    account
+                },
+            };
+
+            Ok(AccountInfo { summary, details })
+        })
+        .collect()
+}
+
+/// Rebuilds the state of an account after its latest update at or before `block_num`, applying
+/// the deltas selected by `stmt` to the checkpoint they start with.
+///
+/// # Returns
+///
+/// The state of the account, or [None] if it has no stored state.
+fn rebuild_account(
+    stmt: &mut Statement<'_>,
+    account_id: AccountId,
+    block_num: BlockNumber,
+) -> Result<Option<Account>> {
+    let mut rows = stmt.query(params![u64_to_value(account_id), block_num])?;
 
-    let details = row.get_ref(3)?.as_blob_or_null()?;
-    let details = details.map(Account::read_from_bytes).transpose()?;
+    let mut account = None;
+    while let Some(row) = rows.next()? {
+        if row.get(4)? {
+            let block_num = row.get(0)?;
+            return Err(DatabaseError::AccountStatePruned { account_id, block_num });
+        }
 
-    Ok(AccountInfo { summary: update, details })
+        let account_hash_data = row.get_ref(1)?.as_blob()?;
+        let account_hash = RpoDigest::read_from_bytes(account_hash_data)?;
+        let details_data = row.get_ref(2)?.as_blob_or_null()?;
+        let delta_data = row.get_ref(3)?.as_blob_or_null()?;
+
+        account = match (details_data, delta_data, account) {
+            (Some(details), ..) => Some(Account::read_from_bytes(details)?),
+            (None, Some(delta), Some(account)) => {
+                let delta = AccountDelta::read_from_bytes(delta)?;
+                Some(apply_delta(account, &delta, &account_hash)?)
+            },
+            (None, Some(_), None) => {
+                return Err(DatabaseError::CorruptedData(format!(
+                    "Delta of account {account_id} at block {} has no checkpoint",
+                    row.get::<_, BlockNumber>(0)?
+                )));
+            },
+            (None, None, _) => None,
+        };
+    }
+
+    Ok(account)
 }
 
-/// Deserializes account and applies account delta.
+/// Applies an account delta, checking the hash of the resulting state.
 fn apply_delta(
-    account_id: u64,
-    value: &ValueRef<'_>,
+    mut account: Account,
     delta: &AccountDelta,
     final_state_hash: &RpoDigest,
 ) -> Result<Account, DatabaseError> {
-    let account = value.as_blob_or_null()?;
-    let account = account.map(Account::read_from_bytes).transpose()?;
-
-    let Some(mut account) = account else {
-        return Err(DatabaseError::AccountNotOnChain(account_id));
-    };
-
     account.apply_delta(delta)?;
 
     let actual_hash = account.hash();
@@ -1,5 +1,9 @@
 use miden_lib::transaction::TransactionKernel;
-use miden_node_proto::domain::accounts::{AccountSummary, AccountUpdateDetails};
+use miden_node_proto::domain::{
+    accounts::{AccountSummary, AccountUpdateDetails},
+    transactions::TransactionHeader,
+};
+use miden_node_utils::config::Endpoint;
 use miden_objects::{
     accounts::{
         Account, AccountCode, AccountDelta, AccountId, AccountStorage, AccountStorageDelta,
@@ -8,18 +12,33 @@ use miden_objects::{
         ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
         ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
     },
-    assembly::{Assembler, ModuleAst},
+    assembly::{Assembler, ModuleAst, ProgramAst},
     assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
     block::BlockNoteTree,
     crypto::{hash::rpo::RpoDigest, merkle::MerklePath},
-    notes::{NoteId, NoteMetadata, NoteType, Nullifier},
-    transaction::AccountDetails,
+    notes::{Note as NoteData, NoteId, NoteMetadata, NoteScript, NoteType, Nullifier},
+    transaction::{AccountDetails, TransactionId},
+    utils::Serializable,
     BlockHeader, Felt, FieldElement, Word, ONE, ZERO,
 };
-use rusqlite::{vtab::array, Connection};
+use rusqlite::{params, vtab::array, Connection};
+use tokio::sync::oneshot;
+
+use super::{
+    cache::AccountCache, sql, AccountFilter, AccountHistoryRecord, AccountInfo, AccountKind,
+    AccountMetadata, AccountMetadataFilter, Db, MemoryDb, Note, NoteCreated, NoteFilter,
+    NoteStatus, NullifierFilter, NullifierInfo, NullifierSyncKey, PageRequest, PruningTarget,
+    Storage, TransactionInclusion,
+};
+use crate::{
+    config::{StoreConfig, DEFAULT_SNAPSHOT_INTERVAL},
+    db::migrations,
+    errors::DatabaseError,
+    genesis::GenesisState,
+};
 
-use super::{sql, AccountInfo, Note, NoteCreated, NullifierInfo};
-use crate::db::migrations;
+/// Number of deltas stored between two account checkpoints in the tests.
+const CHECKPOINT_INTERVAL: u32 = 2;
 
 fn create_db() -> Connection {
     let mut conn = Connection::open_in_memory().unwrap();
@@ -119,6 +138,43 @@ fn test_sql_select_nullifiers() {
     }
 }
 
+#[test]
+fn test_sql_select_nullifiers_page() {
+    let mut conn = create_db();
+
+    let mut nullifiers = vec![];
+    for block_num in 1..=3 {
+        create_block(&mut conn, block_num);
+
+        let block_nullifiers: Vec<_> =
+            (0..4).map(|i| num_to_nullifier((block_num * 10 + i) as u64)).collect();
+        let transaction = conn.transaction().unwrap();
+        sql::insert_nullifiers_for_block(&transaction, &block_nullifiers, block_num).unwrap();
+        transaction.commit().unwrap();
+
+        nullifiers.extend(block_nullifiers.into_iter().map(|nullifier| (nullifier, block_num)));
+    }
+
+    // the nullifiers of blocks 2 and 3, sorted by nullifier
+    let filter = NullifierFilter {
+        block_num_from: Some(2),
+        block_num_to: None,
+    };
+    let mut expected: Vec<_> =
+        nullifiers.iter().filter(|(_, block_num)| *block_num >= 2).cloned().collect();
+    expected.sort_by_key(|(nullifier, _)| nullifier.to_bytes());
+
+    let mut page = PageRequest { after: None, size: 5 };
+    let res = sql::select_nullifiers_page(&mut conn, &filter, page).unwrap();
+    assert_eq!(res.items, expected[..5]);
+    assert_eq!(res.next, Some(expected[4].0));
+
+    page.after = res.next;
+    let res = sql::select_nullifiers_page(&mut conn, &filter, page).unwrap();
+    assert_eq!(res.items, expected[5..]);
+    assert_eq!(res.next, None);
+}
+
 #[test]
 fn test_sql_select_notes() {
     let mut conn = create_db();
@@ -157,6 +213,269 @@ fn test_sql_select_notes() {
     }
 }
 
+#[test]
+fn test_sql_select_notes_page() {
+    let mut conn = create_db();
+
+    // the contents of two public notes are indexed, one of them holding a fungible asset
+    let assembler = TransactionKernel::assembler();
+    let faucet_id = ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN;
+    let asset = FungibleAsset::new(faucet_id.try_into().unwrap(), 10).unwrap();
+    let note_data = mock_note(&assembler, 1, &[]);
+    let asset_note_data = mock_note(&assembler, 2, &[Asset::Fungible(asset)]);
+
+    let mut notes = vec![];
+    for block_num in 1..=2 {
+        create_block(&mut conn, block_num);
+
+        for i in 0..5 {
+            let details = match (block_num, i) {
+                (1, 2) => Some(note_data.to_bytes()),
+                (2, 4) => Some(asset_note_data.to_bytes()),
+                _ => None,
+            };
+            notes.push(Note {
+                block_num,
+                note_created: NoteCreated {
+                    batch_index: 0,
+                    note_index: i,
+                    note_id: num_to_rpo_digest((block_num * 10 + i) as u64),
+                    note_type: if i % 2 == 0 {
+                        NoteType::Public
+                    } else {
+                        NoteType::OffChain
+                    },
+                    sender: i as u64,
+                    tag: i,
+                    details,
+                },
+                merkle_path: MerklePath::new(vec![]),
+            });
+        }
+    }
+    let transaction = conn.transaction().unwrap();
+    sql::insert_notes(&transaction, &notes).unwrap();
+    transaction.commit().unwrap();
+
+    // walk all the pages
+    let mut page = PageRequest { after: None, size: 3 };
+    let mut listed = vec![];
+    loop {
+        let res = sql::select_notes_page(&mut conn, &NoteFilter::default(), page).unwrap();
+        assert!(res.items.len() <= page.size);
+        listed.extend(res.items);
+
+        match res.next {
+            Some(after) => page.after = Some(after),
+            None => break,
+        }
+    }
+    assert_eq!(listed, notes);
+
+    // an exactly full last page has no next page
+    let page = PageRequest { after: None, size: notes.len() };
+    let res = sql::select_notes_page(&mut conn, &NoteFilter::default(), page).unwrap();
+    assert_eq!(res.items.len(), notes.len());
+    assert_eq!(res.next, None);
+
+    // filters
+    let page = PageRequest { after: None, size: 100 };
+    let filter = NoteFilter {
+        block_num_from: Some(2),
+        note_type: Some(NoteType::Public),
+        ..Default::default()
+    };
+    let res = sql::select_notes_page(&mut conn, &filter, page).unwrap();
+    assert_eq!(
+        res.items,
+        notes
+            .iter()
+            .filter(|note| note.block_num == 2 && note.note_created.note_type == NoteType::Public)
+            .cloned()
+            .collect::<Vec<_>>()
+    );
+
+    let filter = NoteFilter {
+        block_num_to: Some(1),
+        sender: Some(3),
+        tag: Some(3),
+        ..Default::default()
+    };
+    let res = sql::select_notes_page(&mut conn, &filter, page).unwrap();
+    assert_eq!(res.items, vec![notes[3].clone()]);
+
+    // the indexed contents of public notes
+    let assert_indexed_contents = |conn: &mut Connection| {
+        let filter = NoteFilter {
+            recipient: Some(note_data.recipient()),
+            ..Default::default()
+        };
+        let res = sql::select_notes_page(conn, &filter, page).unwrap();
+        assert_eq!(res.items, vec![notes[2].clone()]);
+
+        // both notes have the same script
+        let filter = NoteFilter {
+            script_root: Some(note_data.script().hash()),
+            ..Default::default()
+        };
+        let res = sql::select_notes_page(conn, &filter, page).unwrap();
+        assert_eq!(res.items, vec![notes[2].clone(), notes[9].clone()]);
+
+        let filter = NoteFilter {
+            faucet_id: Some(faucet_id),
+            ..Default::default()
+        };
+        let res = sql::select_notes_page(conn, &filter, page).unwrap();
+        assert_eq!(res.items, vec![notes[9].clone()]);
+    };
+    assert_indexed_contents(&mut conn);
+
+    // notes stored before their contents were indexed are backfilled
+    conn.execute("DELETE FROM note_assets", []).unwrap();
+    conn.execute("UPDATE notes SET nullifier = NULL, recipient = NULL, script_root = NULL", [])
+        .unwrap();
+    let transaction = conn.transaction().unwrap();
+    assert_eq!(sql::index_note_details(&transaction).unwrap(), 2);
+    transaction.commit().unwrap();
+
+    assert_indexed_contents(&mut conn);
+}
+
+#[test]
+fn test_sql_get_note_sync() {
+    let mut conn = create_db();
+
+    // blocks 1 to 5, with a note matching tag 1 in the odd blocks
+    let mut notes = vec![];
+    for block_num in 1..=5 {
+        create_block(&mut conn, block_num);
+
+        for i in 0..2 {
+            notes.push(Note {
+                block_num,
+                note_created: NoteCreated {
+                    batch_index: 0,
+                    note_index: i,
+                    note_id: num_to_rpo_digest((block_num * 10 + i) as u64),
+                    note_type: NoteType::OffChain,
+                    sender: 100,
+                    tag: if block_num % 2 == 1 && i == 1 { 1 } else { 2 },
+                    details: None,
+                },
+                merkle_path: MerklePath::new(vec![]),
+            });
+        }
+    }
+    let transaction = conn.transaction().unwrap();
+    sql::insert_notes(&transaction, &notes).unwrap();
+    transaction.commit().unwrap();
+
+    let matching: Vec<_> =
+        notes.iter().filter(|note| note.note_created.tag == 1).cloned().collect();
+
+    // the sync stops at the last of the requested number of blocks
+    let res = sql::get_note_sync(&mut conn, 0, &[1], &[], 2).unwrap();
+    assert_eq!(res.notes, matching[..2]);
+    assert_eq!(
+        res.block_headers.iter().map(BlockHeader::block_num).collect::<Vec<_>>(),
+        vec![1, 3]
+    );
+    assert_eq!(res.block_header.block_num(), 3);
+    assert_eq!(res.chain_tip, 5);
+
+    // with fewer matching blocks, the sync goes up to the chain tip
+    let res = sql::get_note_sync(&mut conn, 3, &[1], &[], 2).unwrap();
+    assert_eq!(res.notes, matching[2..]);
+    assert_eq!(res.block_header.block_num(), 5);
+
+    let res = sql::get_note_sync(&mut conn, 1, &[3], &[], 2).unwrap();
+    assert!(res.notes.is_empty());
+    assert!(res.block_headers.is_empty());
+    assert_eq!(res.block_header.block_num(), 5);
+
+    // notes are also matched by sender
+    let res = sql::get_note_sync(&mut conn, 0, &[], &[100], 3).unwrap();
+    assert_eq!(res.notes, notes[..6]);
+    assert_eq!(res.block_header.block_num(), 3);
+}
+
+#[test]
+fn test_sql_note_status() {
+    let mut conn = create_db();
+
+    for block_num in 1..=3 {
+        create_block(&mut conn, block_num);
+    }
+
+    let note_data = mock_note(&TransactionKernel::assembler(), 1, &[]);
+    let notes: Vec<_> = (0..2)
+        .map(|i| Note {
+            block_num: 1,
+            note_created: NoteCreated {
+                batch_index: 1,
+                note_index: i,
+                note_id: num_to_rpo_digest(i as u64),
+                note_type: NoteType::Public,
+                sender: i as u64,
+                tag: i,
+                // details which can't be deserialized into a note aren't indexed
+                details: Some(if i == 0 { note_data.to_bytes() } else { vec![1, 2, 3] }),
+            },
+            merkle_path: MerklePath::new(vec![]),
+        })
+        .collect();
+    let transaction = conn.transaction().unwrap();
+    sql::insert_notes(&transaction, &notes).unwrap();
+    transaction.commit().unwrap();
+
+    let nullifiers: Vec<Option<Vec<u8>>> = conn
+        .prepare("SELECT nullifier FROM notes ORDER BY note_index")
+        .unwrap()
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(nullifiers, vec![Some(note_data.nullifier().to_bytes()), None]);
+
+    let note_ids: Vec<NoteId> = notes.iter().map(|note| note.note_created.note_id.into()).collect();
+    let statuses = sql::select_note_statuses_by_id(&mut conn, &note_ids).unwrap();
+    assert_eq!(statuses.len(), 2);
+    assert_eq!(statuses[0].block_num, 1);
+    assert_eq!(statuses[0].note_index, notes[0].note_created.absolute_note_index());
+    assert!(statuses.iter().all(|status| status.consumed_block_num.is_none()));
+
+    // the note is marked as consumed once its nullifier is added
+    let transaction = conn.transaction().unwrap();
+    sql::insert_nullifiers_for_block(&transaction, &[note_data.nullifier()], 3).unwrap();
+    transaction.commit().unwrap();
+
+    let statuses = sql::select_note_statuses_by_id(&mut conn, &note_ids).unwrap();
+    assert_eq!(statuses[0].consumed_block_num, Some(3));
+    assert_eq!(statuses[1].consumed_block_num, None);
+
+    // notes stored before their details were indexed are backfilled, with their consumption
+    conn.execute(
+        "UPDATE notes SET nullifier = NULL, consumed_block_num = NULL, recipient = NULL",
+        [],
+    )
+    .unwrap();
+    let transaction = conn.transaction().unwrap();
+    assert_eq!(sql::index_note_details(&transaction).unwrap(), 1);
+    transaction.commit().unwrap();
+
+    let statuses = sql::select_note_statuses_by_id(&mut conn, &note_ids).unwrap();
+    assert_eq!(statuses[0].consumed_block_num, Some(3));
+    assert_eq!(statuses[1].consumed_block_num, None);
+
+    // rolling back the consuming block makes the note unconsumed again
+    let transaction = conn.transaction().unwrap();
+    sql::rollback_to(&transaction, 2).unwrap();
+    transaction.commit().unwrap();
+
+    let statuses = sql::select_note_statuses_by_id(&mut conn, &note_ids).unwrap();
+    assert_eq!(statuses[0].consumed_block_num, None);
+}
+
 #[test]
 fn test_sql_select_accounts() {
     let mut conn = create_db();
@@ -165,7 +484,7 @@ fn test_sql_select_accounts() {
     create_block(&mut conn, block_num);
 
     // test querying empty table
-    let accounts = sql::select_accounts(&mut conn).unwrap();
+    let accounts = sql::select_accounts(&mut conn, &AccountCache::new(0)).unwrap();
     assert!(accounts.is_empty());
     // test multiple entries
     let mut state = vec![];
@@ -185,18 +504,30 @@ fn test_sql_select_accounts() {
         let transaction = conn.transaction().unwrap();
         let res = sql::upsert_accounts(
             &transaction,
+            &AccountCache::new(0),
             &[AccountUpdateDetails {
                 account_id: account_id.try_into().unwrap(),
                 final_state_hash: account_hash,
                 details: None,
             }],
             block_num,
+            CHECKPOINT_INTERVAL,
         );
         assert_eq!(res.unwrap(), 1, "One element must have been inserted");
         transaction.commit().unwrap();
-        let accounts = sql::select_accounts(&mut conn).unwrap();
+        let accounts = sql::select_accounts(&mut conn, &AccountCache::new(0)).unwrap();
         assert_eq!(accounts, state);
     }
+
+    // test querying by ids, unknown accounts are skipped
+    let account_ids: Vec<u64> = [7, 2]
+        .iter()
+        .map(|&i: &usize| state[i].summary.account_id.into())
+        .chain([ACCOUNT_ID_OFF_CHAIN_SENDER])
+        .collect();
+    let accounts =
+        sql::select_accounts_by_ids(&mut conn, &AccountCache::new(0), &account_ids).unwrap();
+    assert_eq!(accounts.iter().collect::<Vec<_>>(), vec![&state[2], &state[7]]);
 }
 
 #[test]
@@ -237,18 +568,20 @@ fn test_sql_public_account_details() {
     );
 
     // test querying empty table
-    let accounts_in_db = sql::select_accounts(&mut conn).unwrap();
+    let accounts_in_db = sql::select_accounts(&mut conn, &AccountCache::new(0)).unwrap();
     assert!(accounts_in_db.is_empty());
 
     let transaction = conn.transaction().unwrap();
     let inserted = sql::upsert_accounts(
         &transaction,
+        &AccountCache::new(0),
         &[AccountUpdateDetails {
             account_id,
             final_state_hash: account.hash(),
             details: Some(AccountDetails::Full(account.clone())),
         }],
         block_num,
+        CHECKPOINT_INTERVAL,
     )
     .unwrap();
 
@@ -256,7 +589,7 @@ fn test_sql_public_account_details() {
 
     transaction.commit().unwrap();
 
-    let mut accounts_in_db = sql::select_accounts(&mut conn).unwrap();
+    let mut accounts_in_db = sql::select_accounts(&mut conn, &AccountCache::new(0)).unwrap();
 
     assert_eq!(accounts_in_db.len(), 1, "One element must have been inserted");
 
@@ -287,12 +620,14 @@ fn test_sql_public_account_details() {
     let transaction = conn.transaction().unwrap();
     let inserted = sql::upsert_accounts(
         &transaction,
+        &AccountCache::new(0),
         &[AccountUpdateDetails {
             account_id,
             final_state_hash: account.hash(),
             details: Some(AccountDetails::Delta(delta.clone())),
         }],
         block_num,
+        CHECKPOINT_INTERVAL,
     )
     .unwrap();
 
@@ -300,7 +635,7 @@ fn test_sql_public_account_details() {
 
     transaction.commit().unwrap();
 
-    let mut accounts_in_db = sql::select_accounts(&mut conn).unwrap();
+    let mut accounts_in_db = sql::select_accounts(&mut conn, &AccountCache::new(0)).unwrap();
 
     assert_eq!(accounts_in_db.len(), 1, "One element must have been inserted");
 
@@ -327,6 +662,534 @@ fn test_sql_public_account_details() {
     assert_eq!(account_read.storage(), account.storage());
 }
 
+#[test]
+fn test_sql_account_history() {
+    let mut conn = create_db();
+
+    for block_num in 1..=3 {
+        create_block(&mut conn, block_num);
+    }
+
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let mut account = Account::new(
+        account_id,
+        AssetVault::new(&[]).unwrap(),
+        AccountStorage::new(vec![]).unwrap(),
+        mock_account_code(&TransactionKernel::assembler()),
+        ZERO,
+    );
+    let initial_account = account.clone();
+
+    let delta = AccountDelta::new(
+        AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(1, num_to_word(1))],
+        },
+        AccountVaultDelta::default(),
+        Some(ONE),
+    )
+    .unwrap();
+    account.apply_delta(&delta).unwrap();
+
+    let updates = [
+        (1, initial_account.hash(), AccountDetails::Full(initial_account.clone())),
+        (3, account.hash(), AccountDetails::Delta(delta.clone())),
+    ];
+    for (block_num, final_state_hash, details) in updates.iter().cloned() {
+        let transaction = conn.transaction().unwrap();
+        sql::upsert_accounts(
+            &transaction,
+            &AccountCache::new(0),
+            &[AccountUpdateDetails {
+                account_id,
+                final_state_hash,
+                details: Some(details),
+            }],
+            block_num,
+            CHECKPOINT_INTERVAL,
+        )
+        .unwrap();
+        transaction.commit().unwrap();
+    }
+
+    // the account doesn't exist before its creation
+    let res = sql::select_account_at_block(&mut conn, account_id.into(), 0);
+    assert!(matches!(res, Err(DatabaseError::AccountNotFoundInDb(_))));
+
+    // the state at a block is the one of the latest update at or before it
+    let res = sql::select_account_at_block(&mut conn, account_id.into(), 2).unwrap();
+    assert_eq!(res.summary.block_num, 1);
+    assert_eq!(res.summary.account_hash, initial_account.hash());
+    assert_eq!(res.details, Some(initial_account.clone()));
+
+    let res = sql::select_account_at_block(&mut conn, account_id.into(), 3).unwrap();
+    assert_eq!(res.summary.block_num, 3);
+    assert_eq!(res.summary.account_hash, account.hash());
+
+    // the same state is returned when querying several accounts
+    let account_ids = [account_id.into(), ACCOUNT_ID_OFF_CHAIN_SENDER];
+    let res = sql::select_accounts_at_block(&mut conn, &account_ids, 2).unwrap();
+    assert_eq!(res.len(), 1);
+    assert_eq!(res[0].summary.block_num, 1);
+    assert_eq!(res[0].details, Some(initial_account.clone()));
+
+    let res = sql::select_accounts_at_block(&mut conn, &account_ids, 0).unwrap();
+    assert!(res.is_empty());
+
+    // the latest state is still available
+    let res = sql::select_account(&mut conn, &AccountCache::new(0), account_id.into()).unwrap();
+    assert_eq!(res.summary.account_hash, account.hash());
+
+    let res = sql::select_account_hash_history(&mut conn).unwrap();
+    assert_eq!(
+        res,
+        vec![
+            (account_id.into(), 1, initial_account.hash()),
+            (account_id.into(), 3, account.hash())
+        ]
+    );
+
+    // test the updates are returned for the range `(block_start, block_end]`
+    let res = sql::select_account_history(&mut conn, account_id.into(), 0, 3).unwrap();
+    assert_eq!(
+        res,
+        updates
+            .iter()
+            .cloned()
+            .map(|(block_num, account_hash, details)| AccountHistoryRecord {
+                block_num,
+                account_hash,
+                details,
+            })
+            .collect::<Vec<_>>()
+    );
+
+    let res = sql::select_account_history(&mut conn, account_id.into(), 1, 2).unwrap();
+    assert!(res.is_empty());
+
+    // off-chain accounts don't have a history of their details
+    let off_chain_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let transaction = conn.transaction().unwrap();
+    sql::upsert_accounts(
+        &transaction,
+        &AccountCache::new(0),
+        &[AccountUpdateDetails {
+            account_id: off_chain_account_id,
+            final_state_hash: num_to_rpo_digest(1),
+            details: None,
+        }],
+        2,
+        CHECKPOINT_INTERVAL,
+    )
+    .unwrap();
+    transaction.commit().unwrap();
+
+    let res = sql::select_account_history(&mut conn, off_chain_account_id.into(), 0, 3);
+    assert!(matches!(res, Err(DatabaseError::AccountNotOnChain(_))));
+}
+
+#[test]
+fn test_sql_account_checkpoints() {
+    let mut conn = create_db();
+
+    for block_num in 1..=5 {
+        create_block(&mut conn, block_num);
+    }
+
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let mut account = Account::new(
+        account_id,
+        AssetVault::new(&[]).unwrap(),
+        AccountStorage::new(vec![]).unwrap(),
+        mock_account_code(&TransactionKernel::assembler()),
+        ZERO,
+    );
+
+    // the account is created in block 1 and updated with a delta in each of the next blocks
+    let mut states = vec![account.clone()];
+    let mut updates = vec![AccountDetails::Full(account.clone())];
+    for block_num in 2..=5 {
+        let delta = AccountDelta::new(
+            AccountStorageDelta {
+                cleared_items: vec![],
+                updated_items: vec![(1, num_to_word(block_num))],
+            },
+            AccountVaultDelta::default(),
+            Some(Felt::new(block_num)),
+        )
+        .unwrap();
+        account.apply_delta(&delta).unwrap();
+
+        states.push(account.clone());
+        updates.push(AccountDetails::Delta(delta));
+    }
+
+    let cache = AccountCache::new(1);
+    for (block_num, (state, details)) in (1..).zip(states.iter().zip(updates)) {
+        let transaction = conn.transaction().unwrap();
+        sql::upsert_accounts(
+            &transaction,
+            &cache,
+            &[AccountUpdateDetails {
+                account_id,
+                final_state_hash: state.hash(),
+                details: Some(details),
+            }],
+            block_num,
+            CHECKPOINT_INTERVAL,
+        )
+        .unwrap();
+        transaction.commit().unwrap();
+    }
+
+    // a checkpoint is written once `CHECKPOINT_INTERVAL` deltas were stored since the previous one
+    let mut stmt = conn
+        .prepare(
+            "SELECT block_num FROM account_history WHERE details IS NOT NULL ORDER BY block_num",
+        )
+        .unwrap();
+    let checkpoints: Vec<u32> =
+        stmt.query_map([], |row| row.get(0)).unwrap().map(Result::unwrap).collect();
+    drop(stmt);
+    assert_eq!(checkpoints, vec![1, 4]);
+
+    // the states are rebuilt from the checkpoints and deltas
+    for (block_num, state) in (1..).zip(states.iter()) {
+        let res = sql::select_account_at_block(&mut conn, account_id.into(), block_num).unwrap();
+        assert_eq!(res.details.as_ref(), Some(state));
+    }
+
+    // the latest state is served from the cache, which is checked against the account hash
+    assert_eq!(cache.get(account_id.into(), account.hash()), Some(account.clone()));
+    assert_eq!(cache.get(account_id.into(), states[3].hash()), None);
+    let res = sql::select_account(&mut conn, &cache, account_id.into()).unwrap();
+    assert_eq!(res.details, Some(account.clone()));
+
+    // states rebuilt for list queries are not cached, unlike the ones of point lookups
+    let cache = AccountCache::new(1);
+    let res = sql::select_accounts_by_ids(&mut conn, &cache, &[account_id.into()]).unwrap();
+    assert_eq!(res[0].details, Some(account.clone()));
+    let page = PageRequest { after: None, size: 10 };
+    let res =
+        sql::select_accounts_page(&mut conn, &cache, &AccountFilter::default(), page).unwrap();
+    assert_eq!(res.items[0].details, Some(account.clone()));
+    assert_eq!(cache.get(account_id.into(), account.hash()), None);
+
+    sql::select_account(&mut conn, &cache, account_id.into()).unwrap();
+    assert_eq!(cache.get(account_id.into(), account.hash()), Some(account.clone()));
+
+    // the states before the latest checkpoint at or before the target are pruned
+    let target = PruningTarget {
+        notes: None,
+        consumed_notes: None,
+        account_history: Some(5),
+    };
+    let transaction = conn.transaction().unwrap();
+    assert_eq!(sql::prune(&transaction, &target).unwrap(), 3);
+    transaction.commit().unwrap();
+
+    let res = sql::select_account_at_block(&mut conn, account_id.into(), 3);
+    assert!(matches!(res, Err(DatabaseError::AccountStatePruned { block_num: 3, .. })));
+
+    let res = sql::select_account(&mut conn, &AccountCache::new(0), account_id.into()).unwrap();
+    assert_eq!(res.details, Some(account));
+}
+
+#[test]
+fn test_sql_rollback() {
+    let mut conn = create_db();
+
+    for block_num in 1..=3 {
+        create_block(&mut conn, block_num);
+    }
+
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let new_account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    let updates = [
+        (1, account_id, num_to_rpo_digest(1)),
+        (2, new_account_id, num_to_rpo_digest(2)),
+        (3, account_id, num_to_rpo_digest(3)),
+    ];
+    for (block_num, account_id, final_state_hash) in updates {
+        let transaction = conn.transaction().unwrap();
+        sql::upsert_accounts(
+            &transaction,
+            &AccountCache::new(0),
+            &[AccountUpdateDetails {
+                account_id,
+                final_state_hash,
+                details: None,
+            }],
+            block_num,
+            CHECKPOINT_INTERVAL,
+        )
+        .unwrap();
+        transaction.commit().unwrap();
+    }
+
+    let transaction = conn.transaction().unwrap();
+    sql::insert_nullifiers_for_block(&transaction, &[num_to_nullifier(1 << 48)], 3).unwrap();
+    transaction.commit().unwrap();
+
+    let transaction = conn.transaction().unwrap();
+    let count = sql::rollback_to(&transaction, 1).unwrap();
+    transaction.commit().unwrap();
+    assert!(count > 0);
+
+    // the updated account is restored to its state at the target block, the new one is removed
+    let accounts = sql::select_account_hashes(&mut conn).unwrap();
+    assert_eq!(accounts, vec![(account_id.into(), num_to_rpo_digest(1))]);
+
+    let res = sql::select_account_at_block(&mut conn, account_id.into(), 3).unwrap();
+    assert_eq!(res.summary.block_num, 1);
+
+    assert!(sql::select_nullifiers(&mut conn).unwrap().is_empty());
+
+    let block_nums: Vec<_> = sql::select_block_headers(&mut conn)
+        .unwrap()
+        .iter()
+        .map(BlockHeader::block_num)
+        .collect();
+    assert_eq!(block_nums, vec![1]);
+
+    // rolling back to the chain tip is a no-op
+    let transaction = conn.transaction().unwrap();
+    assert_eq!(sql::rollback_to(&transaction, 1).unwrap(), 0);
+}
+
+#[test]
+fn test_sql_prune() {
+    let mut conn = create_db();
+
+    for block_num in 1..=3 {
+        create_block(&mut conn, block_num);
+    }
+
+    let notes: Vec<_> = (1..=2)
+        .map(|block_num| Note {
+            block_num,
+            note_created: NoteCreated {
+                batch_index: 0,
+                note_index: 0,
+                note_id: num_to_rpo_digest(block_num as u64),
+                note_type: NoteType::Public,
+                sender: block_num as u64,
+                tag: block_num,
+                details: Some(vec![1, 2, 3]),
+            },
+            merkle_path: MerklePath::new(vec![]),
+        })
+        .collect();
+    let transaction = conn.transaction().unwrap();
+    sql::insert_notes(&transaction, &notes).unwrap();
+    transaction.commit().unwrap();
+    conn.execute(
+        "UPDATE notes SET consumed_block_num = 2 WHERE note_hash = ?1",
+        params![notes[1].note_created.note_id.to_bytes()],
+    )
+    .unwrap();
+
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    for (block_num, final_state_hash) in [(1, num_to_rpo_digest(1)), (3, num_to_rpo_digest(3))] {
+        let transaction = conn.transaction().unwrap();
+        sql::upsert_accounts(
+            &transaction,
+            &AccountCache::new(0),
+            &[AccountUpdateDetails {
+                account_id,
+                final_state_hash,
+                details: None,
+            }],
+            block_num,
+            CHECKPOINT_INTERVAL,
+        )
+        .unwrap();
+        transaction.commit().unwrap();
+    }
+
+    // the consumed note and the account state replaced at block 3 are pruned
+    let target = PruningTarget {
+        notes: None,
+        consumed_notes: Some(2),
+        account_history: Some(3),
+    };
+    let transaction = conn.transaction().unwrap();
+    assert_eq!(sql::prune(&transaction, &target).unwrap(), 2);
+    transaction.commit().unwrap();
+
+    let res = sql::select_notes_by_id(&mut conn, &[notes[0].note_created.note_id.into()]).unwrap();
+    assert_eq!(res, vec![notes[0].clone()]);
+
+    let res = sql::select_notes_by_id(&mut conn, &[notes[1].note_created.note_id.into()]);
+    assert!(matches!(res, Err(DatabaseError::NotePruned(_))));
+
+    // the note IDs are kept to rebuild the note roots
+    let res = sql::select_notes(&mut conn).unwrap();
+    assert_eq!(res.len(), 2);
+    assert_eq!(res[1].note_created.details, None);
+
+    let res = sql::select_account_at_block(&mut conn, account_id.into(), 2);
+    assert!(matches!(res, Err(DatabaseError::AccountStatePruned { block_num: 2, .. })));
+
+    let res = sql::select_account_at_block(&mut conn, account_id.into(), 3).unwrap();
+    assert_eq!(res.summary.account_hash, num_to_rpo_digest(3));
+
+    // the pruned account state can't be restored
+    let transaction = conn.transaction().unwrap();
+    let res = sql::rollback_to(&transaction, 2);
+    assert!(matches!(res, Err(DatabaseError::AccountStatePruned { .. })));
+
+    // the data is only pruned once
+    assert_eq!(sql::prune(&transaction, &target).unwrap(), 0);
+}
+
+#[test]
+fn test_sql_account_metadata() {
+    let mut conn = create_db();
+
+    for block_num in 1..=3 {
+        create_block(&mut conn, block_num);
+    }
+
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let wallet_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    for (block_num, account_id) in [(1, faucet_id), (2, faucet_id), (3, wallet_id)] {
+        let transaction = conn.transaction().unwrap();
+        sql::upsert_accounts(
+            &transaction,
+            &AccountCache::new(0),
+            &[AccountUpdateDetails {
+                account_id,
+                final_state_hash: num_to_rpo_digest(block_num as u64),
+                details: None,
+            }],
+            block_num,
+            CHECKPOINT_INTERVAL,
+        )
+        .unwrap();
+        transaction.commit().unwrap();
+    }
+
+    let page = PageRequest { after: None, size: 10 };
+    let filter = AccountMetadataFilter {
+        kind: Some(AccountKind::FungibleFaucet),
+        ..Default::default()
+    };
+    let res = sql::select_account_metadata_page(&mut conn, &filter, page).unwrap();
+    assert_eq!(
+        res.items,
+        vec![AccountMetadata {
+            summary: AccountSummary {
+                account_id: faucet_id,
+                account_hash: num_to_rpo_digest(2),
+                block_num: 2,
+            },
+            kind: AccountKind::FungibleFaucet,
+            on_chain: true,
+            created_block_num: 1,
+            num_updates: 2,
+            code_root: None,
+        }]
+    );
+
+    let filter = AccountMetadataFilter {
+        created_block_num_from: Some(3),
+        ..Default::default()
+    };
+    let res = sql::select_account_metadata_page(&mut conn, &filter, page).unwrap();
+    assert_eq!(res.items.len(), 1);
+    assert_eq!(res.items[0].summary.account_id, wallet_id);
+    assert_eq!(res.items[0].kind, AccountKind::Wallet);
+    assert!(!res.items[0].on_chain);
+
+    // the metadata of the restored accounts is indexed again
+    let transaction = conn.transaction().unwrap();
+    sql::rollback_to(&transaction, 1).unwrap();
+    transaction.commit().unwrap();
+
+    let res = sql::select_account_metadata_page(&mut conn, &Default::default(), page).unwrap();
+    assert_eq!(res.items.len(), 1);
+    assert_eq!(res.items[0].summary.account_id, faucet_id);
+    assert_eq!(res.items[0].created_block_num, 1);
+    assert_eq!(res.items[0].num_updates, 1);
+}
+
+#[test]
+fn test_sql_import_state() {
+    let mut conn = create_db();
+
+    let block_headers: Vec<_> = (0..3)
+        .map(|block_num| {
+            BlockHeader::new(
+                num_to_rpo_digest(1),
+                block_num,
+                num_to_rpo_digest(3),
+                num_to_rpo_digest(4),
+                num_to_rpo_digest(5),
+                num_to_rpo_digest(6),
+                num_to_rpo_digest(7),
+                num_to_rpo_digest(8),
+                9_u8.into(),
+                10_u8.into(),
+            )
+        })
+        .collect();
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let accounts = vec![AccountInfo {
+        summary: AccountSummary {
+            account_id,
+            account_hash: num_to_rpo_digest(1),
+            block_num: 1,
+        },
+        details: None,
+    }];
+    let notes = vec![Note {
+        block_num: 2,
+        note_created: NoteCreated {
+            batch_index: 0,
+            note_index: 0,
+            note_id: num_to_rpo_digest(2),
+            note_type: NoteType::OffChain,
+            sender: account_id.into(),
+            tag: 2,
+            details: None,
+        },
+        merkle_path: MerklePath::new(vec![]),
+    }];
+    let nullifiers = vec![(num_to_nullifier(1), 1), (num_to_nullifier(2), 2)];
+
+    let transaction = conn.transaction().unwrap();
+    let count =
+        sql::import_state(&transaction, &block_headers, &accounts, &notes, &nullifiers).unwrap();
+    transaction.commit().unwrap();
+    assert_eq!(count, 7);
+
+    assert_eq!(sql::select_block_headers(&mut conn).unwrap(), block_headers);
+    assert_eq!(sql::select_accounts(&mut conn, &AccountCache::new(0)).unwrap(), accounts);
+    assert_eq!(
+        sql::select_account_at_block(&mut conn, account_id.into(), 2).unwrap(),
+        accounts[0]
+    );
+    assert_eq!(sql::select_nullifiers(&mut conn).unwrap(), nullifiers);
+
+    // the imported notes are exported again up to their consumption
+    assert_eq!(sql::select_unconsumed_notes(&mut conn, 1).unwrap(), vec![]);
+    assert_eq!(sql::select_unconsumed_notes(&mut conn, 2).unwrap(), notes);
+    conn.execute(
+        "UPDATE notes SET consumed_block_num = 2 WHERE note_hash = ?1",
+        params![notes[0].note_created.note_id.to_bytes()],
+    )
+    .unwrap();
+    assert_eq!(sql::select_unconsumed_notes(&mut conn, 2).unwrap(), vec![]);
+}
+
 #[test]
 fn test_sql_select_nullifiers_by_block_range() {
     let mut conn = create_db();
@@ -445,6 +1308,46 @@ fn test_sql_select_nullifiers_by_block_range() {
     assert!(nullifiers.is_empty());
 }
 
+#[test]
+fn test_sql_select_nullifiers_by_block_range_page() {
+    let mut conn = create_db();
+
+    let mut nullifiers = vec![];
+    for block_num in 1..=3 {
+        create_block(&mut conn, block_num);
+
+        let block_nullifiers: Vec<_> =
+            (0..3).map(|i| num_to_nullifier((block_num * 10 + i) as u64)).collect();
+        let transaction = conn.transaction().unwrap();
+        sql::insert_nullifiers_for_block(&transaction, &block_nullifiers, block_num).unwrap();
+        transaction.commit().unwrap();
+
+        nullifiers.extend(
+            block_nullifiers
+                .into_iter()
+                .map(|nullifier| NullifierInfo { nullifier, block_num }),
+        );
+    }
+    let prefixes = [sql::get_nullifier_prefix(&nullifiers[0].nullifier)];
+
+    // the nullifiers of blocks 2 and 3, with pages spanning several blocks
+    let mut page = PageRequest { after: None, size: 4 };
+    let res = sql::select_nullifiers_by_block_range_page(&mut conn, 1, 3, &prefixes, page).unwrap();
+    assert_eq!(res.items, nullifiers[3..7]);
+    assert_eq!(res.next, Some(NullifierSyncKey::from(&nullifiers[6])));
+
+    page.after = res.next;
+    let res = sql::select_nullifiers_by_block_range_page(&mut conn, 1, 3, &prefixes, page).unwrap();
+    assert_eq!(res.items, nullifiers[7..]);
+    assert_eq!(res.next, None);
+
+    // nullifiers with other prefixes are not returned
+    let page = PageRequest { after: None, size: 4 };
+    let res = sql::select_nullifiers_by_block_range_page(&mut conn, 0, 3, &[prefixes[0] + 1], page)
+        .unwrap();
+    assert!(res.items.is_empty());
+}
+
 #[test]
 fn test_db_block_header() {
     let mut conn = create_db();
@@ -514,6 +1417,171 @@ fn test_db_block_header() {
 
     let res = sql::select_block_headers(&mut conn).unwrap();
     assert_eq!(res, [block_header, block_header2]);
+
+    // selecting headers by block numbers skips the unknown blocks
+    let block_nums = [block_header2.block_num(), 100, block_header.block_num()];
+    let res = sql::select_block_headers_by_block_nums(&mut conn, &block_nums).unwrap();
+    assert_eq!(res, [block_header, block_header2]);
+}
+
+#[test]
+fn test_db_block() {
+    let mut conn = create_db();
+
+    let block_header = BlockHeader::new(
+        num_to_rpo_digest(1),
+        2,
+        num_to_rpo_digest(3),
+        num_to_rpo_digest(4),
+        num_to_rpo_digest(5),
+        num_to_rpo_digest(6),
+        num_to_rpo_digest(7),
+        num_to_rpo_digest(8),
+        9_u8.into(),
+        10_u8.into(),
+    );
+    let block_data = vec![1, 2, 3];
+
+    // test querying empty table
+    let res = sql::select_block_by_num(&mut conn, block_header.block_num()).unwrap();
+    assert!(res.is_none());
+
+    let res = sql::select_block_by_hash(&mut conn, &block_header.hash()).unwrap();
+    assert!(res.is_none());
+
+    // test insertion
+    let transaction = conn.transaction().unwrap();
+    sql::insert_block_header(&transaction, &block_header).unwrap();
+    sql::insert_block(&transaction, &block_header, &block_data).unwrap();
+    transaction.commit().unwrap();
+
+    // test fetch block by number and by hash
+    let res = sql::select_block_by_num(&mut conn, block_header.block_num()).unwrap();
+    assert_eq!(res.unwrap(), block_data);
+
+    let res = sql::select_block_by_hash(&mut conn, &block_header.hash()).unwrap();
+    assert_eq!(res.unwrap(), block_data);
+
+    // test fetch unknown block
+    let res = sql::select_block_by_num(&mut conn, block_header.block_num() + 1).unwrap();
+    assert!(res.is_none());
+
+    let res = sql::select_block_by_hash(&mut conn, &num_to_rpo_digest(1)).unwrap();
+    assert!(res.is_none());
+
+    // inserting the same block twice is an error
+    let transaction = conn.transaction().unwrap();
+    let res = sql::insert_block(&transaction, &block_header, &block_data);
+    assert!(res.is_err(), "Inserting the same block twice is an error");
+}
+
+#[test]
+fn test_sql_select_block_summaries() {
+    let mut conn = create_db();
+
+    for block_num in 1..=3 {
+        create_block(&mut conn, block_num);
+    }
+
+    let transaction = conn.transaction().unwrap();
+    sql::insert_nullifiers_for_block(
+        &transaction,
+        &[num_to_nullifier(1 << 48), num_to_nullifier(2 << 48)],
+        2,
+    )
+    .unwrap();
+    sql::upsert_accounts(
+        &transaction,
+        &AccountCache::new(0),
+        &[AccountUpdateDetails {
+            account_id: AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN)
+                .unwrap(),
+            final_state_hash: num_to_rpo_digest(1),
+            details: None,
+        }],
+        3,
+        CHECKPOINT_INTERVAL,
+    )
+    .unwrap();
+    transaction.commit().unwrap();
+
+    let res = sql::select_block_summaries(&mut conn, 2, 10).unwrap();
+    let counts: Vec<_> = res
+        .iter()
+        .map(|summary| {
+            (
+                summary.block_header.block_num(),
+                summary.num_account_updates,
+                summary.num_nullifiers,
+                summary.num_notes,
+            )
+        })
+        .collect();
+    assert_eq!(counts, vec![(2, 0, 2, 0), (3, 1, 0, 0)]);
+
+    let res = sql::select_block_summaries(&mut conn, 1, 1).unwrap();
+    assert_eq!(res.len(), 1);
+    assert_eq!(res[0].block_header.block_num(), 1);
+}
+
+#[test]
+fn test_db_transactions() {
+    let mut conn = create_db();
+
+    let block_num = 1;
+    create_block(&mut conn, block_num);
+
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let transactions: Vec<_> = (0..3)
+        .map(|i| TransactionHeader {
+            id: TransactionId::from(num_to_rpo_digest(10 + i)),
+            account_id,
+            initial_account_hash: num_to_rpo_digest(20 + i),
+            final_account_hash: num_to_rpo_digest(21 + i),
+            input_notes: vec![num_to_nullifier(30 + i)],
+            output_notes: vec![NoteId::from(num_to_rpo_digest(40 + i))],
+        })
+        .collect();
+    let transaction_ids: Vec<_> = transactions.iter().map(|tx| tx.id).collect();
+
+    // test querying empty table
+    let res = sql::select_transactions_by_id(&mut conn, &transaction_ids).unwrap();
+    assert!(res.is_empty());
+
+    // test insertion
+    let transaction = conn.transaction().unwrap();
+    let res = sql::insert_transactions(&transaction, &transactions, block_num).unwrap();
+    assert_eq!(res, transactions.len(), "One element must have been inserted per transaction");
+    transaction.commit().unwrap();
+
+    // test the position of each transaction in the block is returned, unknown ids are ignored
+    let unknown_id = TransactionId::from(num_to_rpo_digest(99));
+    let res = sql::select_transactions_by_id(
+        &mut conn,
+        &[transaction_ids[2], unknown_id, transaction_ids[0]],
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        vec![
+            TransactionInclusion {
+                transaction_id: transaction_ids[0],
+                block_num,
+                position: 0,
+            },
+            TransactionInclusion {
+                transaction_id: transaction_ids[2],
+                block_num,
+                position: 2,
+            },
+        ]
+    );
+
+    // a transaction can only be included once
+    let transaction = conn.transaction().unwrap();
+    let res = sql::insert_transactions(&transaction, &transactions[..1], block_num);
+    assert!(res.is_err(), "Inserting the same transaction twice is an error");
 }
 
 #[test]
@@ -535,12 +1603,14 @@ fn test_db_account() {
     let transaction = conn.transaction().unwrap();
     let row_count = sql::upsert_accounts(
         &transaction,
+        &AccountCache::new(0),
         &[AccountUpdateDetails {
             account_id: account_id.try_into().unwrap(),
             final_state_hash: account_hash,
             details: None,
         }],
         block_num,
+        CHECKPOINT_INTERVAL,
     )
     .unwrap();
     transaction.commit().unwrap();
@@ -679,6 +1749,256 @@ fn test_notes() {
     assert_eq!(note_1.note_created.details, None)
 }
 
+#[tokio::test]
+async fn test_memory_db_apply_block_and_rollback() {
+    let db = MemoryDb::new(GenesisState::new(vec![], 1, 1)).unwrap();
+    assert_eq!(db.select_block_headers().await.unwrap().len(), 1);
+
+    let block_header = |block_num| {
+        BlockHeader::new(
+            num_to_rpo_digest(1),
+            block_num,
+            num_to_rpo_digest(3),
+            num_to_rpo_digest(4),
+            num_to_rpo_digest(5),
+            num_to_rpo_digest(6),
+            num_to_rpo_digest(7),
+            num_to_rpo_digest(8),
+            9_u8.into(),
+            10_u8.into(),
+        )
+    };
+    let apply_block = |block_num, nullifiers| {
+        let (allow_acquire, _acquired_allowed) = oneshot::channel();
+        let (inform_acquire_done, acquire_done) = oneshot::channel();
+        inform_acquire_done.send(()).unwrap();
+        db.apply_block(
+            allow_acquire,
+            acquire_done,
+            block_header(block_num),
+            vec![],
+            vec![],
+            nullifiers,
+            vec![],
+            vec![],
+        )
+    };
+
+    let nullifier = num_to_nullifier(1);
+    apply_block(1, vec![nullifier]).await.unwrap();
+    assert_eq!(db.select_nullifiers().await.unwrap(), vec![(nullifier, 1)]);
+
+    // the same block can't be applied twice
+    let res = apply_block(1, vec![]).await;
+    assert!(matches!(res, Err(DatabaseError::DuplicateEntry(_))));
+
+    // a nullifier can't be consumed twice
+    let res = apply_block(2, vec![nullifier]).await;
+    assert!(matches!(res, Err(DatabaseError::DuplicateEntry(_))));
+    assert_eq!(db.select_block_headers().await.unwrap().len(), 2);
+
+    // a rollback which isn't committed is discarded
+    let (rolled_back, rolled_back_state) = oneshot::channel();
+    let (_, commit) = oneshot::channel();
+    let res = db.rollback_to(0, rolled_back, commit).await;
+    assert!(matches!(res, Err(DatabaseError::RollbackFailedClosedChannel(_))));
+    assert_eq!(rolled_back_state.await.unwrap().block_headers.len(), 1);
+    assert_eq!(db.select_block_headers().await.unwrap().len(), 2);
+
+    // rolling back removes the block and its nullifiers
+    let (rolled_back, _rolled_back_state) = oneshot::channel();
+    let (commit_rollback, commit) = oneshot::channel();
+    commit_rollback.send(()).unwrap();
+    db.rollback_to(0, rolled_back, commit).await.unwrap();
+    assert_eq!(db.select_block_headers().await.unwrap().len(), 1);
+    assert!(db.select_nullifiers().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_storage_backends() {
+    check_storage(&MemoryDb::new(GenesisState::new(vec![], 1, 1)).unwrap()).await;
+    check_storage(&create_sqlite_db("storage-backends").await).await;
+}
+
+/// Checks the note consumption and the rollback of a [Storage] backend, so that the SQLite DB and
+/// the [MemoryDb] behave the same.
+async fn check_storage(db: &dyn Storage) {
+    let assembler = TransactionKernel::assembler();
+    let consumed_note = mock_note(&assembler, 1, &[]);
+    let early_note = mock_note(&assembler, 2, &[]);
+
+    let note = |block_num, note_index, details: Option<&NoteData>| Note {
+        block_num,
+        note_created: NoteCreated {
+            batch_index: 0,
+            note_index,
+            note_id: details.map_or(num_to_rpo_digest(note_index as u64), |data| data.id().inner()),
+            note_type: NoteType::Public,
+            sender: ACCOUNT_ID_OFF_CHAIN_SENDER,
+            tag: 0,
+            details: details.map(|data| data.to_bytes()),
+        },
+        merkle_path: MerklePath::new(vec![]),
+    };
+    let apply_block = |block_num, notes, nullifiers, accounts| {
+        let (allow_acquire, _acquired_allowed) = oneshot::channel();
+        let (inform_acquire_done, acquire_done) = oneshot::channel();
+        inform_acquire_done.send(()).unwrap();
+        let block_header = BlockHeader::new(
+            num_to_rpo_digest(1),
+            block_num,
+            num_to_rpo_digest(3),
+            num_to_rpo_digest(4),
+            num_to_rpo_digest(5),
+            num_to_rpo_digest(6),
+            num_to_rpo_digest(7),
+            num_to_rpo_digest(8),
+            9_u8.into(),
+            10_u8.into(),
+        );
+        db.apply_block(
+            allow_acquire,
+            acquire_done,
+            block_header,
+            vec![],
+            notes,
+            nullifiers,
+            accounts,
+            vec![],
+        )
+    };
+
+    // block 1 creates a note, block 2 consumes it and the note of block 3 before its creation
+    let notes = [
+        note(1, 0, Some(&consumed_note)),
+        note(1, 1, None),
+        note(3, 0, Some(&early_note)),
+    ];
+    apply_block(1, notes[..2].to_vec(), vec![num_to_nullifier(1 << 48)], vec![])
+        .await
+        .unwrap();
+    apply_block(2, vec![], vec![consumed_note.nullifier(), early_note.nullifier()], vec![])
+        .await
+        .unwrap();
+    apply_block(3, notes[2..].to_vec(), vec![], vec![]).await.unwrap();
+
+    // a nullifier can't be consumed twice
+    assert!(apply_block(4, vec![], vec![consumed_note.nullifier()], vec![]).await.is_err());
+
+    let note_ids: Vec<NoteId> = notes.iter().map(|note| note.note_created.note_id.into()).collect();
+    let consumed_block_nums = |mut statuses: Vec<NoteStatus>| {
+        statuses.sort_by_key(|status| (status.block_num, status.note_index));
+        statuses.into_iter().map(|status| status.consumed_block_num).collect::<Vec<_>>()
+    };
+    let statuses = db.select_note_statuses_by_id(note_ids.clone()).await.unwrap();
+    assert_eq!(consumed_block_nums(statuses), vec![Some(2), None, Some(2)]);
+    assert_eq!(db.select_unconsumed_notes(3).await.unwrap(), vec![notes[1].clone()]);
+
+    let filter = NoteFilter {
+        recipient: Some(early_note.recipient()),
+        ..Default::default()
+    };
+    let page = PageRequest { after: None, size: 10 };
+    let res = db.select_notes_page(filter, page).await.unwrap();
+    assert_eq!(res.items, vec![notes[2].clone()]);
+
+    // rolling back unconsumes the notes and deletes the notes created after the target block
+    let (rolled_back, _rolled_back_state) = oneshot::channel();
+    let (commit_rollback, commit) = oneshot::channel();
+    commit_rollback.send(()).unwrap();
+    db.rollback_to(1, rolled_back, commit).await.unwrap();
+
+    let statuses = db.select_note_statuses_by_id(note_ids.clone()).await.unwrap();
+    assert_eq!(consumed_block_nums(statuses), vec![None, None]);
+    assert_eq!(db.select_unconsumed_notes(1).await.unwrap(), notes[..2].to_vec());
+    assert_eq!(db.select_nullifiers().await.unwrap(), vec![(num_to_nullifier(1 << 48), 1)]);
+    assert_eq!(db.select_block_headers().await.unwrap().len(), 2);
+
+    // the note created again is consumed by its nullifier once it is added back
+    apply_block(2, notes[2..].to_vec(), vec![], vec![]).await.unwrap();
+    apply_block(3, vec![], vec![early_note.nullifier()], vec![]).await.unwrap();
+    let statuses = db.select_note_statuses_by_id(note_ids[2..].to_vec()).await.unwrap();
+    assert_eq!(consumed_block_nums(statuses), vec![Some(3)]);
+
+    // accounts are listed by the storage mode of their ID, also when their details are unknown as
+    // for the genesis accounts
+    let on_chain_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let off_chain_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let accounts = [on_chain_id, off_chain_id]
+        .into_iter()
+        .map(|account_id| AccountUpdateDetails {
+            account_id,
+            final_state_hash: num_to_rpo_digest(account_id.into()),
+            details: None,
+        })
+        .collect();
+    apply_block(4, vec![], vec![], accounts).await.unwrap();
+
+    for (on_chain, account_id) in [(true, on_chain_id), (false, off_chain_id)] {
+        let filter = AccountFilter {
+            on_chain: Some(on_chain),
+            ..Default::default()
+        };
+        let page = PageRequest { after: None, size: 10 };
+        let res = db.select_accounts_page(filter, page).await.unwrap();
+        let account_ids: Vec<_> = res.items.iter().map(|info| info.summary.account_id).collect();
+        assert_eq!(account_ids, vec![account_id]);
+    }
+
+    // a client behind the retention window syncs across the pruned notes of block 1
+    let target = PruningTarget { notes: Some(1), ..Default::default() };
+    assert_eq!(db.prune(target).await.unwrap(), 2);
+
+    let sync = db.get_state_sync(0, &[], &[0], &[]).await.unwrap();
+    assert_eq!(sync.block_header.block_num(), 1);
+    assert_eq!(sync.notes.len(), 2);
+    assert!(sync
+        .notes
+        .iter()
+        .all(|note| note.is_pruned() && note.note_created.details.is_none()));
+
+    let sync = db.get_state_sync(sync.block_header.block_num(), &[], &[0], &[]).await.unwrap();
+    assert_eq!(sync.notes.len(), 1);
+    assert_eq!(sync.notes[0].note_created.note_id, early_note.id().inner());
+    assert!(!sync.notes[0].is_pruned());
+
+    let sync = db.get_note_sync(0, &[0], &[], 10).await.unwrap();
+    let pruned: Vec<_> = sync.notes.iter().map(Note::is_pruned).collect();
+    assert_eq!(pruned, vec![true, true, false]);
+
+    // point lookups of pruned notes still fail
+    let res = db.select_notes_by_id(vec![consumed_note.id()]).await;
+    assert!(matches!(res, Err(DatabaseError::NotePruned(_))));
+}
+
+/// Returns a SQLite [Db] set up with an empty genesis block, in a fresh directory named after
+/// the test.
+async fn create_sqlite_db(name: &str) -> Db {
+    let dir = std::env::temp_dir().join(format!("miden-store-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let genesis_filepath = dir.join("genesis.dat");
+    std::fs::write(&genesis_filepath, GenesisState::new(vec![], 1, 1).to_bytes()).unwrap();
+
+    Db::setup(StoreConfig {
+        endpoint: Endpoint {
+            host: "localhost".to_string(),
+            port: 28943,
+        },
+        database_filepath: dir.join("store.sqlite3"),
+        genesis_filepath,
+        snapshot_filepath: None,
+        snapshot_interval: DEFAULT_SNAPSHOT_INTERVAL,
+        account_checkpoint_interval: CHECKPOINT_INTERVAL,
+        retention: None,
+    })
+    .await
+    .unwrap()
+}
+
 // UTILITIES
 // -------------------------------------------------------------------------------------------
 fn num_to_rpo_digest(n: u64) -> RpoDigest {
@@ -693,6 +2013,16 @@ fn num_to_nullifier(n: u64) -> Nullifier {
     Nullifier::from(num_to_rpo_digest(n))
 }
 
+/// Returns a public note with the given `assets`, whose serial number is derived from `n`.
+fn mock_note(assembler: &Assembler, n: u64, assets: &[Asset]) -> NoteData {
+    let program_ast = ProgramAst::parse("begin push.1 drop end").unwrap();
+    let (script, _) = NoteScript::new(program_ast, assembler).unwrap();
+    let sender = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    let metadata = NoteMetadata::new(sender, NoteType::Public, 0.into(), ZERO).unwrap();
+
+    NoteData::new(script, &[], assets, num_to_word(n), metadata).unwrap()
+}
+
 pub fn mock_account_code(assembler: &Assembler) -> AccountCode {
     let account_code = "\
             export.account_procedure_1
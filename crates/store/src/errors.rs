@@ -53,12 +53,23 @@ pub enum DatabaseError {
     DeserializationError(DeserializationError),
     #[error("Corrupted data: {0}")]
     CorruptedData(String),
+    #[error("Duplicate entry: {0}")]
+    DuplicateEntry(String),
     #[error("Block applying was broken because of closed channel on state side: {0}")]
     ApplyBlockFailedClosedChannel(RecvError),
+    #[error("Rollback was discarded because of closed channel on state side: {0}")]
+    RollbackFailedClosedChannel(RecvError),
     #[error("Account {0} not found in the database")]
     AccountNotFoundInDb(AccountId),
     #[error("Account {0} is not on the chain")]
     AccountNotOnChain(AccountId),
+    #[error("Merkle path and details of note {0} were pruned")]
+    NotePruned(RpoDigest),
+    #[error("State of account {account_id} at block {block_num} was pruned")]
+    AccountStatePruned {
+        account_id: AccountId,
+        block_num: BlockNumber,
+    },
     #[error("Failed to apply block because of on-chain account final hashes mismatch (expected {expected}, \
         but calculated is {calculated}")]
     ApplyBlockFailedAccountHashesMismatch {
@@ -76,6 +87,13 @@ impl From<DeserializationError> for DatabaseError {
 // INITIALIZATION ERRORS
 // =================================================================================================
 
+#[derive(Debug, Error)]
+#[error("In-memory state doesn't match block header {block_num}: {reason}")]
+pub struct StateMismatchError {
+    pub block_num: BlockNumber,
+    pub reason: &'static str,
+}
+
 #[derive(Error, Debug)]
 pub enum StateInitializationError {
     #[error("Database error: {0}")]
@@ -86,6 +104,98 @@ pub enum StateInitializationError {
     FailedToCreateAccountsTree(MerkleError),
 }
 
+#[derive(Debug, Error)]
+pub enum SnapshotError {
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] DatabaseError),
+    #[error("I/O error: {0}")]
+    IoError(#[from] io::Error),
+    #[error("Deserialization of the snapshot failed: {0}")]
+    DeserializationError(DeserializationError),
+    #[error("Merkle error: {0}")]
+    MerkleError(#[from] MerkleError),
+    #[error("MMR error: {0}")]
+    MmrError(#[from] MmrError),
+    #[error("Nullifier tree error: {0}")]
+    NullifierTreeError(#[from] NullifierTreeError),
+    #[error("Corrupted snapshot: {0}")]
+    CorruptedSnapshot(&'static str),
+    #[error("Chain MMR is empty")]
+    EmptyChainMmr,
+    #[error("Block header {0} not found in the database")]
+    BlockHeaderNotFound(BlockNumber),
+    #[error("State mismatch: {0}")]
+    StateMismatch(#[from] StateMismatchError),
+}
+
+#[derive(Debug, Error)]
+pub enum ArchiveError {
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] DatabaseError),
+    #[error("I/O error: {0}")]
+    IoError(#[from] io::Error),
+    #[error("Deserialization of the archive failed: {0}")]
+    DeserializationError(DeserializationError),
+    #[error("Archive checksum mismatch, the archive is truncated or corrupted")]
+    ChecksumMismatch,
+    #[error("Archive doesn't have any block header")]
+    EmptyArchive,
+    #[error("Database doesn't have any block header data")]
+    DbBlockHeaderEmpty,
+    #[error("Block {block_num} is after the chain tip {chain_tip}")]
+    BlockAfterChainTip {
+        block_num: BlockNumber,
+        chain_tip: BlockNumber,
+    },
+}
+
+#[derive(Debug, Error)]
+pub enum RollbackError {
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] DatabaseError),
+    #[error("State initialization error: {0}")]
+    StateInitializationError(#[from] StateInitializationError),
+    #[error("Database doesn't have any block header data")]
+    DbBlockHeaderEmpty,
+    #[error("Target block {target} is after the chain tip {chain_tip}")]
+    TargetAfterChainTip {
+        target: BlockNumber,
+        chain_tip: BlockNumber,
+    },
+    #[error("State mismatch: {0}")]
+    StateMismatch(#[from] StateMismatchError),
+    #[error("Rollback task failed: {0}")]
+    RollbackTaskFailed(String),
+}
+
+#[derive(Debug, Error)]
+pub enum VerifyError {
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] DatabaseError),
+    #[error("State initialization error: {0}")]
+    StateInitializationError(#[from] StateInitializationError),
+    #[error("Merkle error: {0}")]
+    MerkleError(#[from] MerkleError),
+    #[error("Nullifier tree error: {0}")]
+    NullifierTreeError(#[from] NullifierTreeError),
+    #[error("Database doesn't have any block header data")]
+    DbBlockHeaderEmpty,
+    #[error("Block header {0} not found in the database")]
+    BlockHeaderNotFound(BlockNumber),
+    #[error("Failed to update nullifier tree at block {block_num}: {error}")]
+    FailedToUpdateNullifierTree {
+        block_num: BlockNumber,
+        error: NullifierTreeError,
+    },
+    #[error("Failed to build note tree of block {block_num}: {error}")]
+    FailedToBuildNoteTree {
+        block_num: BlockNumber,
+        error: Box<ApplyBlockError>,
+    },
+    #[error("{0}")]
+    StateMismatch(#[from] StateMismatchError),
+}
+
 #[derive(Debug, Error)]
 pub enum DatabaseSetupError {
     #[error("Database error: {0}")]
@@ -96,6 +206,12 @@ pub enum DatabaseSetupError {
     PoolBuildError(#[from] deadpool_sqlite::BuildError),
     #[error("SQLite migration error: {0}")]
     SqliteMigrationError(#[from] rusqlite_migration::Error),
+    #[error("Archive error: {0}")]
+    ArchiveError(#[from] ArchiveError),
+    #[error(
+        "Database already contains blocks, an archive can only be imported into an empty database"
+    )]
+    DatabaseNotEmpty,
 }
 
 #[derive(Debug, Error)]
@@ -133,10 +249,26 @@ pub enum ApplyBlockError {
     AccountError(#[from] AccountError),
     #[error("Note error: {0}")]
     NoteError(#[from] NoteError),
-    #[error("Concurrent write detected")]
-    ConcurrentWrite,
+    #[error("Write queue is full, at most {capacity} blocks can be queued")]
+    WriteQueueFull { capacity: usize },
+    #[error("Block {block_num} timed out waiting in the write queue, next block to apply is {next_block}")]
+    WriteQueueTimeout {
+        block_num: BlockNumber,
+        next_block: BlockNumber,
+    },
+    #[error("Block {block_num} was aborted in the write queue, the request for block {next_block} failed or the chain was rolled back")]
+    WriteQueueAborted {
+        block_num: BlockNumber,
+        next_block: BlockNumber,
+    },
     #[error("New block number must be 1 greater than the current block number")]
     NewBlockInvalidBlockNum,
+    #[error("Block {block_num} conflicts with the stored block, stored hash {stored_hash}, got {block_hash}")]
+    ConflictingBlock {
+        block_num: BlockNumber,
+        stored_hash: RpoDigest,
+        block_hash: RpoDigest,
+    },
     #[error("New block `prev_hash` must match the chain's tip")]
     NewBlockInvalidPrevHash,
     #[error("New block chain root is not consistent with chain MMR")]
@@ -153,6 +285,8 @@ pub enum ApplyBlockError {
     UnableToCreateProofForNote(MerkleError),
     #[error("Block applying was broken because of closed channel on database side: {0}")]
     BlockApplyingBrokenBecauseOfClosedChannel(RecvError),
+    #[error("Apply block task failed: {0}")]
+    ApplyBlockTaskFailed(String),
     #[error("Failed to create notes tree: {0}")]
     FailedToCreateNoteTree(MerkleError),
     #[error("Database doesn't have any block header data")]
@@ -177,6 +311,60 @@ pub enum GetBlockInputsError {
     IncorrectChainMmrForestNumber { forest: usize, block_num: u32 },
 }
 
+#[derive(Error, Debug)]
+pub enum HistoricalStateError {
+    #[error("Block {block_num} is after the chain tip {chain_tip}")]
+    BlockAfterChainTip {
+        block_num: BlockNumber,
+        chain_tip: BlockNumber,
+    },
+    #[error("State of block {block_num} is not available, the oldest available block is {oldest_block_num}")]
+    BlockNotInHistory {
+        block_num: BlockNumber,
+        oldest_block_num: BlockNumber,
+    },
+}
+
+#[derive(Error, Debug)]
+pub enum GetAccountProofsError {
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] DatabaseError),
+    #[error("Database doesn't have any block header data")]
+    DbBlockHeaderEmpty,
+    #[error("Historical state error: {0}")]
+    HistoricalStateError(#[from] HistoricalStateError),
+}
+
+#[derive(Error, Debug)]
+pub enum AccountStateQueryError {
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] DatabaseError),
+    #[error("Storage slot index {0} is out of bounds")]
+    InvalidSlotIndex(u32),
+    #[error("Account {0} is not a fungible faucet")]
+    NotFungibleFaucet(AccountId),
+}
+
+#[derive(Error, Debug)]
+pub enum GetBlockInclusionProofsError {
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] DatabaseError),
+    #[error("Block {block_num} is after the chain tip {chain_tip}")]
+    BlockAfterChainTip {
+        block_num: BlockNumber,
+        chain_tip: BlockNumber,
+    },
+    #[error("Block {block_num} is not part of the chain MMR of block {chain_block_num}")]
+    BlockNotInChainMmr {
+        block_num: BlockNumber,
+        chain_block_num: BlockNumber,
+    },
+    #[error("Block header {0} not found in the database")]
+    BlockHeaderNotFound(BlockNumber),
+    #[error("Failed to open chain MMR: {0}")]
+    FailedToOpenMmr(MmrError),
+}
+
 #[derive(Error, Debug)]
 pub enum StateSyncError {
     #[error("Database error: {0}")]
@@ -185,4 +373,6 @@ pub enum StateSyncError {
     EmptyBlockHeadersTable,
     #[error("Failed to build MMR delta: {0}")]
     FailedToBuildMmrDelta(MmrError),
+    #[error("Failed to open chain MMR: {0}")]
+    FailedToOpenMmr(MmrError),
 }
@@ -1,9 +1,12 @@
+pub mod archive;
 pub mod config;
 pub mod db;
 pub mod errors;
 pub mod genesis;
 mod nullifier_tree;
+mod partial_tree;
 pub mod server;
+pub mod snapshot;
 pub mod state;
 pub mod types;
 
@@ -1,13 +1,15 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use miden_objects::{
     crypto::{
         hash::rpo::RpoDigest,
-        merkle::{Smt, SmtProof},
+        merkle::{Smt, SmtLeaf, SmtProof},
     },
     notes::Nullifier,
     Felt, FieldElement, Word,
 };
 
-use crate::{errors::NullifierTreeError, types::BlockNumber};
+use crate::{errors::NullifierTreeError, partial_tree::PartialTree, types::BlockNumber};
 
 /// Nullifier SMT.
 #[derive(Debug, Clone)]
@@ -57,6 +59,39 @@ impl NullifierTree {
         Ok(())
     }
 
+    /// Returns openings of the leaves associated with the given nullifiers in the tree as it was
+    /// before the `removed` nullifiers were inserted, used to open the tree of a previous block.
+    ///
+    /// Only the leaves of the `removed` nullifiers are reverted, the tree itself isn't copied.
+    pub fn open_without(&self, nullifiers: &[Nullifier], removed: &[Nullifier]) -> Vec<SmtProof> {
+        let removed_keys: BTreeSet<[u8; 32]> =
+            removed.iter().map(|nullifier| nullifier.inner().as_bytes()).collect();
+
+        let mut tree = PartialTree::default();
+        let mut past_leaves = BTreeMap::new();
+        let mut open = |nullifier: &Nullifier| {
+            let (path, leaf) = self.0.open(&nullifier.inner()).into_parts();
+            let index = leaf.index().value();
+            tree.add_opening(index, leaf.hash(), &path);
+            past_leaves.entry(index).or_insert_with(|| remove_entries(leaf, &removed_keys));
+
+            index
+        };
+        let reverted: BTreeSet<u64> = removed.iter().map(&mut open).collect();
+        let indices: Vec<u64> = nullifiers.iter().map(&mut open).collect();
+
+        tree.revert_leaves(reverted.into_iter().map(|index| (index, past_leaves[&index].hash())));
+
+        indices
+            .into_iter()
+            .map(|index| {
+                let (_, path) = tree.open(index);
+                SmtProof::new(path, past_leaves[&index].clone())
+                    .expect("path has the depth of the tree")
+            })
+            .collect()
+    }
+
     /// Returns block number stored for the given nullifier or `None` if the nullifier wasn't
     /// consumed.
     pub fn get_block_num(&self, nullifier: &Nullifier) -> Option<BlockNumber> {
@@ -68,6 +103,14 @@ impl NullifierTree {
         Some(Self::leaf_value_to_block_num(value))
     }
 
+    /// Returns an iterator over the consumed nullifiers and the block numbers in which they were
+    /// consumed.
+    pub fn entries(&self) -> impl Iterator<Item = (Nullifier, BlockNumber)> + '_ {
+        self.0
+            .entries()
+            .map(|(key, value)| ((*key).into(), Self::leaf_value_to_block_num(*value)))
+    }
+
     /// Returns the nullifier's leaf value in the SMT by its block number.
     fn block_num_to_leaf_value(block: BlockNumber) -> Word {
         [Felt::from(block), Felt::ZERO, Felt::ZERO, Felt::ZERO]
@@ -82,6 +125,25 @@ impl NullifierTree {
     }
 }
 
+/// Returns the `leaf` without the entries whose key is in `removed_keys`.
+fn remove_entries(leaf: SmtLeaf, removed_keys: &BTreeSet<[u8; 32]>) -> SmtLeaf {
+    let index = leaf.index();
+    let entries: Vec<(RpoDigest, Word)> = match leaf {
+        SmtLeaf::Empty(_) => vec![],
+        SmtLeaf::Single(entry) => vec![entry],
+        SmtLeaf::Multiple(entries) => entries,
+    }
+    .into_iter()
+    .filter(|(key, _)| !removed_keys.contains(&key.as_bytes()))
+    .collect();
+
+    match entries.len() {
+        0 => SmtLeaf::new_empty(index),
+        1 => SmtLeaf::new_single(entries[0].0, entries[0].1),
+        _ => SmtLeaf::new_multiple(entries).expect("entries belong to the same leaf"),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use miden_objects::{Felt, ZERO};
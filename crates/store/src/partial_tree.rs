@@ -0,0 +1,81 @@
+//! Openings of the nullifier and account trees as of a past block, computed without copying the
+//! trees.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use miden_objects::crypto::{
+    hash::rpo::{Rpo256, RpoDigest},
+    merkle::MerklePath,
+};
+
+/// Depth of the nullifier and account trees.
+const DEPTH: u8 = 64;
+
+/// Nodes of a Merkle tree of depth 64 on the paths of some of its leaves.
+///
+/// The nodes are recorded from openings of the current tree. Reverting some of the opened leaves
+/// to their past values then updates the nodes on their paths, which yields the openings of the
+/// past tree at the opened leaves in time proportional to the number of leaves, instead of the
+/// size of the tree.
+#[derive(Debug, Default)]
+pub struct PartialTree {
+    /// Nodes keyed by their depth and their index at that depth.
+    nodes: BTreeMap<(u8, u64), RpoDigest>,
+}
+
+impl PartialTree {
+    /// Records the `leaf` at `index` together with the nodes of its authentication `path`.
+    ///
+    /// All the openings must be taken from the same tree, before any leaf is reverted.
+    pub fn add_opening(&mut self, index: u64, leaf: RpoDigest, path: &MerklePath) {
+        self.nodes.insert((DEPTH, index), leaf);
+        for (depth, sibling) in (1..=DEPTH).rev().zip(path.nodes()) {
+            let node_index = index >> (DEPTH - depth);
+            self.nodes.insert((depth, node_index ^ 1), *sibling);
+        }
+    }
+
+    /// Sets the `leaves` to their past values and recomputes the nodes on their paths.
+    ///
+    /// # Panics
+    ///
+    /// If one of the leaves wasn't opened with [PartialTree::add_opening].
+    pub fn revert_leaves(&mut self, leaves: impl IntoIterator<Item = (u64, RpoDigest)>) {
+        let mut changed = BTreeSet::new();
+        for (index, leaf) in leaves {
+            let node = self.nodes.get_mut(&(DEPTH, index)).expect("reverted leaf must be opened");
+            *node = leaf;
+            changed.insert(index);
+        }
+
+        // the nodes are updated level by level, so that the siblings of the changed nodes are
+        // up to date when their parents are computed
+        for depth in (1..=DEPTH).rev() {
+            changed = changed
+                .into_iter()
+                .map(|node_index| {
+                    let left = self.nodes[&(depth, node_index & !1)];
+                    let right = self.nodes[&(depth, node_index | 1)];
+                    let parent_index = node_index >> 1;
+                    self.nodes.insert((depth - 1, parent_index), Rpo256::merge(&[left, right]));
+
+                    parent_index
+                })
+                .collect();
+        }
+    }
+
+    /// Returns the leaf at `index` with its authentication path.
+    ///
+    /// # Panics
+    ///
+    /// If the leaf wasn't opened with [PartialTree::add_opening].
+    pub fn open(&self, index: u64) -> (RpoDigest, MerklePath) {
+        let path = (1..=DEPTH)
+            .rev()
+            .map(|depth| self.nodes[&(depth, (index >> (DEPTH - depth)) ^ 1)])
+            .collect();
+
+        (self.nodes[&(DEPTH, index)], path)
+    }
+}
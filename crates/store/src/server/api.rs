@@ -1,25 +1,41 @@
-use std::sync::Arc;
+use std::{future::Future, iter, sync::Arc};
 
 use miden_node_proto::{
     convert,
-    domain::accounts::AccountUpdateDetails,
+    domain::{
+        accounts::{AccountInfo, AccountUpdateDetails},
+        transactions::TransactionHeader,
+    },
     errors::ConversionError,
     generated::{
         self,
         account::AccountSummary,
         note::NoteSyncRecord,
         requests::{
-            ApplyBlockRequest, CheckNullifiersRequest, GetAccountDetailsRequest,
-            GetBlockHeaderByNumberRequest, GetBlockInputsRequest, GetNotesByIdRequest,
+            ApplyBlockRequest, CheckNullifiersRequest, GetAccountDeltaRequest,
+            GetAccountDetailsRequest, GetAccountProofsRequest, GetAccountStateAtBlockRequest,
+            GetAccountStorageSlotsRequest, GetAccountVaultBalanceRequest, GetBlockByHashRequest,
+            GetBlockByNumberRequest, GetBlockHeaderByNumberRequest, GetBlockInclusionProofsRequest,
+            GetBlockInputsRequest, GetNoteStatusRequest, GetNotesByFaucetRequest,
+            GetNotesByIdRequest, GetNotesByRecipientRequest, GetTransactionInclusionRequest,
             GetTransactionInputsRequest, ListAccountsRequest, ListNotesRequest,
-            ListNullifiersRequest, SyncStateRequest,
+            ListNullifiersRequest, Pagination, QueryAccountsRequest, SubscribeBlocksRequest,
+            SubscribeNotesRequest, SyncNotesRequest, SyncNullifiersRequest, SyncStateRequest,
         },
         responses::{
-            AccountTransactionInputRecord, ApplyBlockResponse, CheckNullifiersResponse,
-            GetAccountDetailsResponse, GetBlockHeaderByNumberResponse, GetBlockInputsResponse,
-            GetNotesByIdResponse, GetTransactionInputsResponse, ListAccountsResponse,
-            ListNotesResponse, ListNullifiersResponse, NullifierTransactionInputRecord,
-            NullifierUpdate, SyncStateResponse,
+            AccountHistoryRecord, AccountProof, AccountStateHeader, AccountTransactionInputRecord,
+            ApplyBlockResponse, BlockInclusionProof, BlockNotes, CheckNullifiersResponse,
+            GetAccountDeltaResponse, GetAccountDetailsResponse, GetAccountProofsResponse,
+            GetAccountStateAtBlockResponse, GetAccountStorageSlotsResponse,
+            GetAccountVaultBalanceResponse, GetBlockByHashResponse, GetBlockByNumberResponse,
+            GetBlockHeaderByNumberResponse, GetBlockInclusionProofsResponse,
+            GetBlockInputsResponse, GetNoteStatusResponse, GetNotesByFaucetResponse,
+            GetNotesByIdResponse, GetNotesByRecipientResponse, GetTransactionInclusionResponse,
+            GetTransactionInputsResponse, ListAccountsResponse, ListNotesResponse,
+            ListNullifiersResponse, NoteStatus, NullifierTransactionInputRecord, NullifierUpdate,
+            QueryAccountsResponse, StorageSlotProof, SubscribeBlocksResponse,
+            SubscribeNotesResponse, SyncNotesResponse, SyncNullifiersResponse, SyncStateResponse,
+            TransactionInclusion, VaultBalanceProof,
         },
         smt::SmtLeafEntry,
         store::api_server,
@@ -29,14 +45,29 @@ use miden_node_proto::{
 use miden_objects::{
     crypto::hash::rpo::RpoDigest,
     notes::{NoteId, NoteType, Nullifier},
-    transaction::AccountDetails,
-    utils::Deserializable,
-    BlockHeader, Felt, NoteError, ZERO,
+    transaction::{AccountDetails, TransactionId},
+    utils::{Deserializable, Serializable},
+    BlockHeader, Felt, NoteError, StarkField, ZERO,
 };
+use prost::Message;
+use tokio::sync::{broadcast::error::RecvError, mpsc};
+use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Response, Status};
 use tracing::{debug, info, instrument};
 
-use crate::{db::NoteCreated, state::State, types::AccountId, COMPONENT};
+use crate::{
+    db::{
+        AccountFilter, AccountKind, AccountMetadataFilter, BlockSummary, Note, NoteCreated,
+        NoteFilter, NoteKey, NullifierFilter, Page, PageRequest,
+    },
+    errors::{
+        AccountStateQueryError, ApplyBlockError, DatabaseError, GetAccountProofsError,
+        GetBlockInclusionProofsError, HistoricalStateError, StateSyncError,
+    },
+    state::{self, State},
+    types::{AccountId, BlockNumber},
+    COMPONENT,
+};
 
 // STORE API
 // ================================================================================================
@@ -80,10 +111,101 @@ impl api_server::Api for StoreApi {
         Ok(Response::new(GetBlockHeaderByNumberResponse { block_header }))
     }
 
+    /// Returns the requested block headers with their authentication paths in the chain MMR
+    /// committed to by the requested block, or the latest block.
+    #[instrument(
+        target = "miden-store",
+        name = "store:get_block_inclusion_proofs",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_block_inclusion_proofs(
+        &self,
+        request: tonic::Request<GetBlockInclusionProofsRequest>,
+    ) -> Result<Response<GetBlockInclusionProofsResponse>, Status> {
+        let request = request.into_inner();
+
+        let (block_header, proofs) = self
+            .state
+            .get_block_inclusion_proofs(&request.block_nums, request.block_num)
+            .await
+            .map_err(|err| match err {
+                GetBlockInclusionProofsError::BlockAfterChainTip { .. }
+                | GetBlockInclusionProofsError::BlockNotInChainMmr { .. } => {
+                    Status::out_of_range(err.to_string())
+                },
+                err => internal_error(err),
+            })?;
+
+        let proofs = proofs
+            .into_iter()
+            .map(|proof| BlockInclusionProof {
+                block_header: Some(proof.block_header.into()),
+                mmr_path: Some(proof.mmr_path.into()),
+            })
+            .collect();
+
+        Ok(Response::new(GetBlockInclusionProofsResponse {
+            block_header: Some(block_header.into()),
+            proofs,
+        }))
+    }
+
+    /// Returns the data of the block with the specified block number.
+    ///
+    /// The block is returned as it was applied, encoded as an `ApplyBlockRequest`.
+    #[instrument(
+        target = "miden-store",
+        name = "store:get_block_by_number",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_block_by_number(
+        &self,
+        request: tonic::Request<GetBlockByNumberRequest>,
+    ) -> Result<Response<GetBlockByNumberResponse>, Status> {
+        info!(target: COMPONENT, ?request);
+
+        let block_num = request.into_inner().block_num;
+        let block = self.state.get_block_by_number(block_num).await.map_err(internal_error)?;
+
+        Ok(Response::new(GetBlockByNumberResponse { block }))
+    }
+
+    /// Returns the data of the block with the specified header hash.
+    ///
+    /// The block is returned as it was applied, encoded as an `ApplyBlockRequest`.
+    #[instrument(
+        target = "miden-store",
+        name = "store:get_block_by_hash",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_block_by_hash(
+        &self,
+        request: tonic::Request<GetBlockByHashRequest>,
+    ) -> Result<Response<GetBlockByHashResponse>, Status> {
+        info!(target: COMPONENT, ?request);
+
+        let block_hash: RpoDigest = request
+            .into_inner()
+            .block_hash
+            .ok_or(invalid_argument("Block hash missing"))?
+            .try_into()
+            .map_err(|err: ConversionError| Status::invalid_argument(err.to_string()))?;
+
+        let block = self.state.get_block_by_hash(block_hash).await.map_err(internal_error)?;
+
+        Ok(Response::new(GetBlockByHashResponse { block }))
+    }
+
     /// Returns info on whether the specified nullifiers have been consumed.
     ///
     /// This endpoint also returns Merkle authentication path for each requested nullifier which can
-    /// be verified against the latest root of the nullifier database.
+    /// be verified against the nullifier root of the requested block, or the latest block.
     #[instrument(
         target = "miden-store",
         name = "store:check_nullifiers",
@@ -100,7 +222,11 @@ impl api_server::Api for StoreApi {
         let nullifiers = validate_nullifiers(&request.nullifiers)?;
 
         // Query the state for the request's nullifiers
-        let proofs = self.state.check_nullifiers(&nullifiers).await;
+        let proofs = self
+            .state
+            .check_nullifiers(&nullifiers, request.block_num)
+            .await
+            .map_err(historical_state_error)?;
 
         Ok(Response::new(CheckNullifiersResponse { proofs: convert(proofs) }))
     }
@@ -126,7 +252,7 @@ impl api_server::Api for StoreApi {
             .state
             .sync_state(request.block_num, &account_ids, &request.note_tags, &request.nullifiers)
             .await
-            .map_err(internal_error)?;
+            .map_err(state_sync_error)?;
 
         let accounts = state
             .account_updates
@@ -148,6 +274,7 @@ impl api_server::Api for StoreApi {
                 sender: Some(note.note_created.sender.into()),
                 tag: note.note_created.tag,
                 merkle_path: Some(note.merkle_path.into()),
+                pruned: note.is_pruned(),
             })
             .collect();
 
@@ -170,6 +297,263 @@ impl api_server::Api for StoreApi {
         }))
     }
 
+    /// Returns the notes matching the requested tags or senders in several blocks at once, with
+    /// the chain MMR delta up to the last block covered.
+    #[instrument(
+        target = "miden-store",
+        name = "store:sync_notes",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn sync_notes(
+        &self,
+        request: tonic::Request<SyncNotesRequest>,
+    ) -> Result<Response<SyncNotesResponse>, Status> {
+        let request = request.into_inner();
+
+        let senders: Vec<u64> = request.senders.iter().map(|e| e.id).collect();
+        let max_blocks = match request.max_blocks as usize {
+            0 => MAX_SYNC_NOTES_BLOCKS,
+            max_blocks => max_blocks.min(MAX_SYNC_NOTES_BLOCKS),
+        };
+
+        let (state, delta, mmr_paths) = self
+            .state
+            .sync_notes(request.block_num, &request.note_tags, &senders, max_blocks)
+            .await
+            .map_err(state_sync_error)?;
+
+        let mut state_notes = state.notes.into_iter().peekable();
+        let blocks = state
+            .block_headers
+            .into_iter()
+            .zip(mmr_paths)
+            .map(|(block_header, mmr_path)| {
+                let block_num = block_header.block_num();
+                let notes =
+                    iter::from_fn(|| state_notes.next_if(|note| note.block_num == block_num))
+                        .map(|note| NoteSyncRecord {
+                            note_index: note.note_created.absolute_note_index(),
+                            note_type: note.note_created.note_type as u32,
+                            note_id: Some(note.note_created.note_id.into()),
+                            sender: Some(note.note_created.sender.into()),
+                            tag: note.note_created.tag,
+                            merkle_path: Some(note.merkle_path.into()),
+                            pruned: note.is_pruned(),
+                        })
+                        .collect();
+
+                BlockNotes {
+                    block_header: Some(block_header.into()),
+                    mmr_path: mmr_path.map(Into::into),
+                    notes,
+                }
+            })
+            .collect();
+
+        Ok(Response::new(SyncNotesResponse {
+            chain_tip: state.chain_tip,
+            block_header: Some(state.block_header.into()),
+            mmr_delta: Some(delta.into()),
+            blocks,
+        }))
+    }
+
+    /// Returns a page of the nullifiers matching the requested prefixes in a block range.
+    #[instrument(
+        target = "miden-store",
+        name = "store:sync_nullifiers",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn sync_nullifiers(
+        &self,
+        request: tonic::Request<SyncNullifiersRequest>,
+    ) -> Result<Response<SyncNullifiersResponse>, Status> {
+        let request = request.into_inner();
+        let page = page_request(request.pagination, MAX_SYNC_NULLIFIERS_PAGE_SIZE)?;
+
+        let (to_block_num, page) = self
+            .state
+            .sync_nullifiers(request.nullifiers, request.from_block_num, request.to_block_num, page)
+            .await
+            .map_err(internal_error)?;
+
+        let nullifiers = page
+            .items
+            .into_iter()
+            .map(|nullifier_info| NullifierUpdate {
+                nullifier: Some(nullifier_info.nullifier.into()),
+                block_num: nullifier_info.block_num,
+            })
+            .collect();
+
+        Ok(Response::new(SyncNullifiersResponse {
+            to_block_num,
+            nullifiers,
+            continuation_token: page.next.map(|key| key.to_bytes()),
+        }))
+    }
+
+    type SubscribeBlocksStream = ReceiverStream<Result<SubscribeBlocksResponse, Status>>;
+
+    /// Streams the headers of the blocks starting at the requested block, followed by the blocks
+    /// committed from now on.
+    ///
+    /// The blocks already in the chain are read from the DB. This is also used to catch up if the
+    /// client doesn't keep up with the new blocks.
+    #[instrument(target = "miden-store", name = "store:subscribe_blocks", skip_all, err)]
+    async fn subscribe_blocks(
+        &self,
+        request: tonic::Request<SubscribeBlocksRequest>,
+    ) -> Result<Response<Self::SubscribeBlocksStream>, Status> {
+        let request = request.into_inner();
+        let include_counts = request.include_counts;
+
+        // subscribe before reading the chain tip, so that no block is missed in between
+        let mut committed_blocks = self.state.subscribe_blocks();
+        let mut next_block_num = match request.block_num {
+            Some(block_num) => block_num,
+            None => {
+                self.state
+                    .get_block_header(None)
+                    .await
+                    .map_err(internal_error)?
+                    .ok_or(Status::internal("Database doesn't have any block header data"))?
+                    .block_num()
+                    + 1
+            },
+        };
+
+        let state = self.state.clone();
+        let (sender, receiver) = mpsc::channel(SUBSCRIPTION_BUFFER_SIZE);
+        tokio::spawn(async move {
+            loop {
+                // catch up with the blocks in the DB
+                loop {
+                    let summaries =
+                        match state.get_block_summaries(next_block_num, REPLAY_PAGE_SIZE).await {
+                            Ok(summaries) => summaries,
+                            Err(err) => {
+                                let _ = sender.send(Err(internal_error(err))).await;
+                                return;
+                            },
+                        };
+                    let is_last_page = summaries.len() < REPLAY_PAGE_SIZE;
+
+                    for summary in summaries {
+                        next_block_num = summary.block_header.block_num() + 1;
+                        let response = subscribe_blocks_response(summary, include_counts);
+                        if sender.send(Ok(response)).await.is_err() {
+                            return;
+                        }
+                    }
+
+                    if is_last_page {
+                        break;
+                    }
+                }
+
+                // follow the new blocks, the ones already streamed from the DB are skipped
+                loop {
+                    let committed_block = tokio::select! {
+                        committed_block = committed_blocks.recv() => committed_block,
+                        _ = sender.closed() => return,
+                    };
+
+                    match committed_block {
+                        Ok(block) if block.block_header.block_num() < next_block_num => continue,
+                        Ok(block) => {
+                            next_block_num = block.block_header.block_num() + 1;
+                            let response =
+                                subscribe_blocks_response(block.summary(), include_counts);
+                            if sender.send(Ok(response)).await.is_err() {
+                                return;
+                            }
+                        },
+                        Err(RecvError::Lagged(_)) => break,
+                        Err(RecvError::Closed) => return,
+                    }
+                }
+            }
+        });
+
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
+
+    type SubscribeNotesStream = ReceiverStream<Result<SubscribeNotesResponse, Status>>;
+
+    /// Streams the notes of the blocks committed from now on, which match the requested tags or
+    /// senders.
+    ///
+    /// Blocks without matching notes are skipped. The stream ends with an error if the client
+    /// doesn't keep up with the new blocks, since some of them were missed.
+    #[instrument(target = "miden-store", name = "store:subscribe_notes", skip_all, err)]
+    async fn subscribe_notes(
+        &self,
+        request: tonic::Request<SubscribeNotesRequest>,
+    ) -> Result<Response<Self::SubscribeNotesStream>, Status> {
+        let request = request.into_inner();
+
+        let note_tags = request.note_tags;
+        let senders: Vec<AccountId> = request.senders.iter().map(|id| id.id).collect();
+
+        let mut committed_blocks = self.state.subscribe_blocks();
+        let (sender, receiver) = mpsc::channel(SUBSCRIPTION_BUFFER_SIZE);
+
+        tokio::spawn(async move {
+            loop {
+                let committed_block = tokio::select! {
+                    committed_block = committed_blocks.recv() => committed_block,
+                    _ = sender.closed() => break,
+                };
+
+                let response = match committed_block {
+                    Ok(block) => {
+                        let notes: Vec<_> = block
+                            .notes
+                            .iter()
+                            .filter(|note| {
+                                note_tags.contains(&note.note_created.tag)
+                                    || senders.contains(&note.note_created.sender)
+                            })
+                            .map(|note| NoteSyncRecord {
+                                note_index: note.note_created.absolute_note_index(),
+                                note_type: note.note_created.note_type as u32,
+                                note_id: Some(note.note_created.note_id.into()),
+                                sender: Some(note.note_created.sender.into()),
+                                tag: note.note_created.tag,
+                                merkle_path: Some(note.merkle_path.clone().into()),
+                                pruned: note.is_pruned(),
+                            })
+                            .collect();
+                        if notes.is_empty() {
+                            continue;
+                        }
+
+                        Ok(SubscribeNotesResponse {
+                            block_header: Some(block.block_header.into()),
+                            notes,
+                        })
+                    },
+                    Err(RecvError::Lagged(skipped)) => Err(Status::data_loss(format!(
+                        "Subscriber missed {skipped} blocks, notes must be synchronized using SyncState"
+                    ))),
+                    Err(RecvError::Closed) => break,
+                };
+
+                let is_err = response.is_err();
+                if sender.send(response).await.is_err() || is_err {
+                    break;
+                }
+            }
+        });
+
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
+
     /// Returns a list of Note's for the specified NoteId's.
     ///
     /// If the list is empty or no Note matched the requested NoteId and empty list is returned.
@@ -197,7 +581,7 @@ impl api_server::Api for StoreApi {
             .state
             .get_notes_by_id(note_ids)
             .await
-            .map_err(internal_error)?
+            .map_err(database_error)?
             .into_iter()
             .map(|note| generated::note::Note {
                 block_num: note.block_num,
@@ -214,6 +598,148 @@ impl api_server::Api for StoreApi {
         Ok(Response::new(GetNotesByIdResponse { notes }))
     }
 
+    /// Returns the block in which each of the given notes was created, its authentication path
+    /// and the block in which it was consumed.
+    #[instrument(
+        target = "miden-store",
+        name = "store:get_note_status",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_note_status(
+        &self,
+        request: tonic::Request<GetNoteStatusRequest>,
+    ) -> Result<Response<GetNoteStatusResponse>, Status> {
+        info!(target: COMPONENT, ?request);
+
+        let note_ids = request.into_inner().note_ids;
+
+        let note_ids: Vec<RpoDigest> = try_convert(note_ids)
+            .map_err(|err| Status::invalid_argument(format!("Invalid NoteId: {}", err)))?;
+
+        let note_ids: Vec<NoteId> = note_ids.into_iter().map(From::from).collect();
+
+        let notes = self
+            .state
+            .get_note_statuses(note_ids)
+            .await
+            .map_err(database_error)?
+            .into_iter()
+            .map(|status| NoteStatus {
+                note_id: Some(status.note_id.into()),
+                block_num: status.block_num,
+                note_index: status.note_index,
+                merkle_path: Some(status.merkle_path.into()),
+                consumed_block_num: status.consumed_block_num,
+            })
+            .collect();
+
+        Ok(Response::new(GetNoteStatusResponse { notes }))
+    }
+
+    /// Returns a page of the public notes with the given recipient digest.
+    #[instrument(
+        target = "miden-store",
+        name = "store:get_notes_by_recipient",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_notes_by_recipient(
+        &self,
+        request: tonic::Request<GetNotesByRecipientRequest>,
+    ) -> Result<Response<GetNotesByRecipientResponse>, Status> {
+        info!(target: COMPONENT, ?request);
+
+        let request = request.into_inner();
+
+        let recipient: RpoDigest = request
+            .recipient
+            .ok_or(invalid_argument("Recipient missing"))?
+            .try_into()
+            .map_err(|err: ConversionError| Status::invalid_argument(err.to_string()))?;
+
+        let filter = NoteFilter {
+            recipient: Some(recipient),
+            ..Default::default()
+        };
+        let page = page_request(request.pagination, MAX_NOTES_PAGE_SIZE)?;
+
+        let page = self.state.list_notes(filter, page).await.map_err(database_error)?;
+        let ListNotesResponse { notes, continuation_token } = notes_response(page);
+
+        Ok(Response::new(GetNotesByRecipientResponse { notes, continuation_token }))
+    }
+
+    /// Returns a page of the public notes holding a fungible asset issued by the given faucet.
+    #[instrument(
+        target = "miden-store",
+        name = "store:get_notes_by_faucet",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_notes_by_faucet(
+        &self,
+        request: tonic::Request<GetNotesByFaucetRequest>,
+    ) -> Result<Response<GetNotesByFaucetResponse>, Status> {
+        info!(target: COMPONENT, ?request);
+
+        let request = request.into_inner();
+
+        let filter = NoteFilter {
+            faucet_id: Some(request.faucet_id.ok_or(invalid_argument("Faucet id missing"))?.into()),
+            ..Default::default()
+        };
+        let page = page_request(request.pagination, MAX_NOTES_PAGE_SIZE)?;
+
+        let page = self.state.list_notes(filter, page).await.map_err(database_error)?;
+        let ListNotesResponse { notes, continuation_token } = notes_response(page);
+
+        Ok(Response::new(GetNotesByFaucetResponse { notes, continuation_token }))
+    }
+
+    /// Returns the block and position at which each of the given transactions was included.
+    ///
+    /// Transactions which have not been included in a block are omitted from the response.
+    #[instrument(
+        target = "miden-store",
+        name = "store:get_transaction_inclusion",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_transaction_inclusion(
+        &self,
+        request: tonic::Request<GetTransactionInclusionRequest>,
+    ) -> Result<Response<GetTransactionInclusionResponse>, Status> {
+        info!(target: COMPONENT, ?request);
+
+        let transaction_ids = request.into_inner().transaction_ids;
+
+        let transaction_ids: Vec<RpoDigest> = try_convert(transaction_ids)
+            .map_err(|err| Status::invalid_argument(format!("Invalid TransactionId: {}", err)))?;
+
+        let transaction_ids: Vec<TransactionId> =
+            transaction_ids.into_iter().map(From::from).collect();
+
+        let transactions = self
+            .state
+            .get_transaction_inclusion(transaction_ids)
+            .await
+            .map_err(internal_error)?
+            .into_iter()
+            .map(|inclusion| TransactionInclusion {
+                transaction_id: Some(inclusion.transaction_id.into()),
+                block_num: inclusion.block_num,
+                position: inclusion.position,
+            })
+            .collect();
+
+        Ok(Response::new(GetTransactionInclusionResponse { transactions }))
+    }
+
     /// Returns details for public (on-chain) account by id.
     #[instrument(
         target = "miden-store",
@@ -240,6 +766,243 @@ impl api_server::Api for StoreApi {
         }))
     }
 
+    /// Returns the hashes of the requested accounts with their authentication paths in the account
+    /// tree of the requested block, or the latest block.
+    #[instrument(
+        target = "miden-store",
+        name = "store:get_account_proofs",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_account_proofs(
+        &self,
+        request: tonic::Request<GetAccountProofsRequest>,
+    ) -> Result<Response<GetAccountProofsResponse>, Status> {
+        let request = request.into_inner();
+        let account_ids: Vec<AccountId> = request.account_ids.iter().map(|e| e.id).collect();
+
+        let (block_header, proofs) = self
+            .state
+            .get_account_proofs(&account_ids, request.include_details, request.block_num)
+            .await
+            .map_err(|err| match err {
+                GetAccountProofsError::HistoricalStateError(err) => historical_state_error(err),
+                err => internal_error(err),
+            })?;
+
+        let account_proofs = proofs
+            .into_iter()
+            .map(|proof| AccountProof {
+                account_id: Some(proof.account_id.into()),
+                account_hash: Some(proof.account_hash.into()),
+                account_proof: Some(proof.path.into()),
+                details: proof.details.map(|account| account.to_bytes()),
+            })
+            .collect();
+
+        Ok(Response::new(GetAccountProofsResponse {
+            block_header: Some(block_header.into()),
+            account_proofs,
+        }))
+    }
+
+    /// Returns the values of storage slots of a public (on-chain) account, with their
+    /// authentication paths in the storage of the account.
+    #[instrument(
+        target = "miden-store",
+        name = "store:get_account_storage_slots",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_account_storage_slots(
+        &self,
+        request: tonic::Request<GetAccountStorageSlotsRequest>,
+    ) -> Result<Response<GetAccountStorageSlotsResponse>, Status> {
+        let request = request.into_inner();
+        let (account, slots) = self
+            .state
+            .get_account_storage_slots(
+                request.account_id.ok_or(invalid_argument("Account missing id"))?.into(),
+                &request.slot_indices,
+            )
+            .await
+            .map_err(account_state_query_error)?;
+
+        let slots = slots
+            .into_iter()
+            .map(|slot| StorageSlotProof {
+                index: slot.index,
+                value: Some(slot.value.into()),
+                path: Some(slot.path.into()),
+            })
+            .collect();
+
+        Ok(Response::new(GetAccountStorageSlotsResponse {
+            account: Some(account_state_header(account)),
+            slots,
+        }))
+    }
+
+    /// Returns the balances of fungible assets in the vault of a public (on-chain) account, with
+    /// the openings of the assets in the vault of the account.
+    #[instrument(
+        target = "miden-store",
+        name = "store:get_account_vault_balance",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_account_vault_balance(
+        &self,
+        request: tonic::Request<GetAccountVaultBalanceRequest>,
+    ) -> Result<Response<GetAccountVaultBalanceResponse>, Status> {
+        let request = request.into_inner();
+        let faucet_ids: Vec<AccountId> = request.faucet_ids.iter().map(|e| e.id).collect();
+
+        let (account, balances) = self
+            .state
+            .get_account_vault_balance(
+                request.account_id.ok_or(invalid_argument("Account missing id"))?.into(),
+                &faucet_ids,
+            )
+            .await
+            .map_err(account_state_query_error)?;
+
+        let balances = balances
+            .into_iter()
+            .map(|balance| VaultBalanceProof {
+                faucet_id: Some(balance.faucet_id.into()),
+                balance: balance.balance,
+                opening: Some(balance.proof.into()),
+            })
+            .collect();
+
+        Ok(Response::new(GetAccountVaultBalanceResponse {
+            account: Some(account_state_header(account)),
+            balances,
+        }))
+    }
+
+    /// Returns the state of an account as of the specified block.
+    #[instrument(
+        target = "miden-store",
+        name = "store:get_account_state_at_block",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_account_state_at_block(
+        &self,
+        request: tonic::Request<GetAccountStateAtBlockRequest>,
+    ) -> Result<Response<GetAccountStateAtBlockResponse>, Status> {
+        let request = request.into_inner();
+        let account_info = self
+            .state
+            .get_account_state_at_block(
+                request.account_id.ok_or(invalid_argument("Account missing id"))?.into(),
+                request.block_num,
+            )
+            .await
+            .map_err(account_query_error)?;
+
+        Ok(Response::new(GetAccountStateAtBlockResponse {
+            account: Some((&account_info).into()),
+        }))
+    }
+
+    /// Returns the updates of a public (on-chain) account in the specified block range.
+    #[instrument(
+        target = "miden-store",
+        name = "store:get_account_delta",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn get_account_delta(
+        &self,
+        request: tonic::Request<GetAccountDeltaRequest>,
+    ) -> Result<Response<GetAccountDeltaResponse>, Status> {
+        let request = request.into_inner();
+
+        if request.from_block_num > request.to_block_num {
+            return Err(invalid_argument("Block range start is after its end"));
+        }
+
+        let updates = self
+            .state
+            .get_account_delta(
+                request.account_id.ok_or(invalid_argument("Account missing id"))?.into(),
+                request.from_block_num,
+                request.to_block_num,
+            )
+            .await
+            .map_err(account_query_error)?
+            .into_iter()
+            .map(|record| AccountHistoryRecord {
+                block_num: record.block_num,
+                account_hash: Some(record.account_hash.into()),
+                details: record.details.to_bytes(),
+            })
+            .collect();
+
+        Ok(Response::new(GetAccountDeltaResponse { updates }))
+    }
+
+    /// Returns a page of the metadata of the accounts matching the filters.
+    #[instrument(
+        target = "miden-store",
+        name = "store:query_accounts",
+        skip_all,
+        ret(level = "debug"),
+        err
+    )]
+    async fn query_accounts(
+        &self,
+        request: tonic::Request<QueryAccountsRequest>,
+    ) -> Result<Response<QueryAccountsResponse>, Status> {
+        let request = request.into_inner();
+
+        let kind = request.account_type.map(AccountKind::try_from).transpose().map_err(
+            |account_type| Status::invalid_argument(format!("Invalid account type {account_type}")),
+        )?;
+        let code_root = request
+            .code_root
+            .map(RpoDigest::try_from)
+            .transpose()
+            .map_err(|err| Status::invalid_argument(err.to_string()))?;
+
+        let filter = AccountMetadataFilter {
+            kind,
+            on_chain: request.on_chain,
+            created_block_num_from: request.created_block_num_from,
+            created_block_num_to: request.created_block_num_to,
+            code_root,
+        };
+        let page = page_request(request.pagination, MAX_ACCOUNTS_PAGE_SIZE)?;
+
+        let page = self.state.query_accounts(filter, page).await.map_err(database_error)?;
+
+        let accounts = page
+            .items
+            .into_iter()
+            .map(|metadata| generated::account::AccountMetadata {
+                summary: Some((&metadata.summary).into()),
+                account_type: metadata.kind as u32,
+                on_chain: metadata.on_chain,
+                created_block_num: metadata.created_block_num,
+                num_updates: metadata.num_updates,
+                code_root: metadata.code_root.map(Into::into),
+            })
+            .collect();
+
+        Ok(Response::new(QueryAccountsResponse {
+            accounts,
+            continuation_token: page.next.map(|key| key.to_bytes()),
+        }))
+    }
+
     // BLOCK PRODUCER ENDPOINTS
     // --------------------------------------------------------------------------------------------
 
@@ -258,6 +1021,10 @@ impl api_server::Api for StoreApi {
         let request = request.into_inner();
 
         debug!(target: COMPONENT, ?request);
+
+        // the block is stored exactly as it was received, so that it can be served back later on
+        let block_data = request.encode_to_vec();
+
         let block_header: BlockHeader = request
             .block
             .ok_or(invalid_argument("Apply block missing block header"))?
@@ -327,7 +1094,13 @@ impl api_server::Api for StoreApi {
             })
             .collect::<Result<Vec<_>, Status>>()?;
 
-        let _ = self.state.apply_block(block_header, nullifiers, accounts, notes).await;
+        let transactions: Vec<TransactionHeader> = try_convert(request.transactions)
+            .map_err(|err: ConversionError| Status::invalid_argument(err.to_string()))?;
+
+        self.state
+            .apply_block(block_header, block_data, nullifiers, accounts, notes, transactions)
+            .await
+            .map_err(apply_block_error)?;
 
         Ok(Response::new(ApplyBlockResponse {}))
     }
@@ -402,7 +1175,7 @@ impl api_server::Api for StoreApi {
     // TESTING ENDPOINTS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns a list of all nullifiers
+    /// Returns a page of the nullifiers
     #[instrument(
         target = "miden-store",
         name = "store:list_nullifiers",
@@ -412,20 +1185,16 @@ impl api_server::Api for StoreApi {
     )]
     async fn list_nullifiers(
         &self,
-        _request: tonic::Request<ListNullifiersRequest>,
+        request: tonic::Request<ListNullifiersRequest>,
     ) -> Result<Response<ListNullifiersResponse>, Status> {
-        let raw_nullifiers = self.state.list_nullifiers().await.map_err(internal_error)?;
-        let nullifiers = raw_nullifiers
-            .into_iter()
-            .map(|(key, block_num)| SmtLeafEntry {
-                key: Some(key.into()),
-                value: Some([Felt::from(block_num), ZERO, ZERO, ZERO].into()),
-            })
-            .collect();
-        Ok(Response::new(ListNullifiersResponse { nullifiers }))
+        let (filter, page) = nullifiers_query(request.into_inner())?;
+
+        let nullifiers = self.state.list_nullifiers(filter, page).await.map_err(database_error)?;
+
+        Ok(Response::new(nullifiers_response(nullifiers)))
     }
 
-    /// Returns a list of all notes
+    /// Returns a page of the notes
     #[instrument(
         target = "miden-store",
         name = "store:list_notes",
@@ -435,29 +1204,16 @@ impl api_server::Api for StoreApi {
     )]
     async fn list_notes(
         &self,
-        _request: tonic::Request<ListNotesRequest>,
+        request: tonic::Request<ListNotesRequest>,
     ) -> Result<Response<ListNotesResponse>, Status> {
-        let notes = self
-            .state
-            .list_notes()
-            .await
-            .map_err(internal_error)?
-            .into_iter()
-            .map(|note| generated::note::Note {
-                block_num: note.block_num,
-                note_index: note.note_created.absolute_note_index(),
-                note_id: Some(note.note_created.note_id.into()),
-                sender: Some(note.note_created.sender.into()),
-                tag: note.note_created.tag,
-                note_type: note.note_created.note_type as u32,
-                merkle_path: Some(note.merkle_path.into()),
-                details: note.note_created.details,
-            })
-            .collect();
-        Ok(Response::new(ListNotesResponse { notes }))
+        let (filter, page) = notes_query(request.into_inner())?;
+
+        let notes = self.state.list_notes(filter, page).await.map_err(database_error)?;
+
+        Ok(Response::new(notes_response(notes)))
     }
 
-    /// Returns a list of all accounts
+    /// Returns a page of the accounts
     #[instrument(
         target = "miden-store",
         name = "store:list_accounts",
@@ -467,17 +1223,309 @@ impl api_server::Api for StoreApi {
     )]
     async fn list_accounts(
         &self,
-        _request: tonic::Request<ListAccountsRequest>,
+        request: tonic::Request<ListAccountsRequest>,
     ) -> Result<Response<ListAccountsResponse>, Status> {
-        let accounts = self
-            .state
-            .list_accounts()
-            .await
-            .map_err(internal_error)?
-            .iter()
-            .map(Into::into)
-            .collect();
-        Ok(Response::new(ListAccountsResponse { accounts }))
+        let (filter, page) = accounts_query(request.into_inner())?;
+
+        let accounts = self.state.list_accounts(filter, page).await.map_err(database_error)?;
+
+        Ok(Response::new(accounts_response(accounts)))
+    }
+
+    type StreamNullifiersStream = PageStream<ListNullifiersResponse>;
+
+    /// Streams all the pages of the nullifiers, starting from the requested one
+    #[instrument(target = "miden-store", name = "store:stream_nullifiers", skip_all, err)]
+    async fn stream_nullifiers(
+        &self,
+        request: tonic::Request<ListNullifiersRequest>,
+    ) -> Result<Response<Self::StreamNullifiersStream>, Status> {
+        let (filter, page) = nullifiers_query(request.into_inner())?;
+
+        let state = self.state.clone();
+        let stream = stream_pages(
+            page,
+            move |page| {
+                let state = state.clone();
+                let filter = filter.clone();
+                async move { state.list_nullifiers(filter, page).await }
+            },
+            nullifiers_response,
+        );
+
+        Ok(Response::new(stream))
+    }
+
+    type StreamAccountsStream = PageStream<ListAccountsResponse>;
+
+    /// Streams all the pages of the accounts, starting from the requested one
+    #[instrument(target = "miden-store", name = "store:stream_accounts", skip_all, err)]
+    async fn stream_accounts(
+        &self,
+        request: tonic::Request<ListAccountsRequest>,
+    ) -> Result<Response<Self::StreamAccountsStream>, Status> {
+        let (filter, page) = accounts_query(request.into_inner())?;
+
+        let state = self.state.clone();
+        let stream = stream_pages(
+            page,
+            move |page| {
+                let state = state.clone();
+                let filter = filter.clone();
+                async move { state.list_accounts(filter, page).await }
+            },
+            accounts_response,
+        );
+
+        Ok(Response::new(stream))
+    }
+
+    type StreamNotesStream = PageStream<ListNotesResponse>;
+
+    /// Streams all the pages of the notes, starting from the requested one
+    #[instrument(target = "miden-store", name = "store:stream_notes", skip_all, err)]
+    async fn stream_notes(
+        &self,
+        request: tonic::Request<ListNotesRequest>,
+    ) -> Result<Response<Self::StreamNotesStream>, Status> {
+        let (filter, page) = notes_query(request.into_inner())?;
+
+        let state = self.state.clone();
+        let stream = stream_pages(
+            page,
+            move |page| {
+                let state = state.clone();
+                let filter = filter.clone();
+                async move { state.list_notes(filter, page).await }
+            },
+            notes_response,
+        );
+
+        Ok(Response::new(stream))
+    }
+}
+
+// STATE SYNC
+// ================================================================================================
+
+/// Maximum number of blocks with matching notes returned by a note sync.
+const MAX_SYNC_NOTES_BLOCKS: usize = 100;
+
+/// Maximum number of nullifiers in a page of a nullifier sync, also used if the page size is not
+/// specified.
+const MAX_SYNC_NULLIFIERS_PAGE_SIZE: usize = 1000;
+
+// SUBSCRIPTIONS
+// ================================================================================================
+
+/// Maximum number of messages buffered for a subscriber.
+const SUBSCRIPTION_BUFFER_SIZE: usize = 8;
+
+/// Number of block headers read at once from the DB when streaming the blocks already in the
+/// chain.
+const REPLAY_PAGE_SIZE: usize = 100;
+
+fn subscribe_blocks_response(
+    summary: BlockSummary,
+    include_counts: bool,
+) -> SubscribeBlocksResponse {
+    let count = |count| include_counts.then_some(count);
+
+    SubscribeBlocksResponse {
+        block_header: Some(summary.block_header.into()),
+        num_account_updates: count(summary.num_account_updates),
+        num_nullifiers: count(summary.num_nullifiers),
+        num_notes: count(summary.num_notes),
+    }
+}
+
+// LIST QUERIES
+// ================================================================================================
+
+/// Maximum number of nullifiers in a page, also used if the page size is not specified.
+const MAX_NULLIFIERS_PAGE_SIZE: usize = 1000;
+
+/// Maximum number of accounts in a page, also used if the page size is not specified.
+const MAX_ACCOUNTS_PAGE_SIZE: usize = 100;
+
+/// Maximum number of notes in a page, also used if the page size is not specified.
+const MAX_NOTES_PAGE_SIZE: usize = 100;
+
+/// Stream of the pages of a list query.
+type PageStream<T> = ReceiverStream<Result<T, Status>>;
+
+/// Streams the pages of a list query starting at `page`. Each page is loaded by `list` and
+/// converted into a message by `into_response`.
+///
+/// The pages are loaded one at a time by a background task, which stops after the last page, on
+/// the first error, or when the client disconnects.
+fn stream_pages<T, K, R, F, Fut>(
+    mut page: PageRequest<K>,
+    mut list: F,
+    into_response: fn(Page<T, K>) -> R,
+) -> PageStream<R>
+where
+    T: Send + 'static,
+    K: Clone + Send + 'static,
+    R: Send + 'static,
+    F: FnMut(PageRequest<K>) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<Page<T, K>, DatabaseError>> + Send,
+{
+    let (sender, receiver) = mpsc::channel(1);
+
+    tokio::spawn(async move {
+        loop {
+            let (response, next) = match list(page.clone()).await {
+                Ok(items) => {
+                    let next = items.next.clone();
+                    (Ok(into_response(items)), next)
+                },
+                Err(err) => (Err(database_error(err)), None),
+            };
+
+            if sender.send(response).await.is_err() {
+                debug!(target: COMPONENT, "List stream closed by the client");
+                break;
+            }
+
+            match next {
+                Some(after) => page.after = Some(after),
+                None => break,
+            }
+        }
+    });
+
+    ReceiverStream::new(receiver)
+}
+
+/// Returns the page requested by `pagination`, whose size is capped to `max_page_size`.
+fn page_request<K: Deserializable>(
+    pagination: Option<Pagination>,
+    max_page_size: usize,
+) -> Result<PageRequest<K>, Status> {
+    let pagination = pagination.unwrap_or_default();
+
+    let after = pagination
+        .continuation_token
+        .map(|token| K::read_from_bytes(&token))
+        .transpose()
+        .map_err(|err| Status::invalid_argument(format!("Invalid continuation token: {err}")))?;
+
+    let size = match pagination.page_size as usize {
+        0 => max_page_size,
+        size => size.min(max_page_size),
+    };
+
+    Ok(PageRequest { after, size })
+}
+
+fn nullifiers_query(
+    request: ListNullifiersRequest,
+) -> Result<(NullifierFilter, PageRequest<Nullifier>), Status> {
+    let filter = NullifierFilter {
+        block_num_from: request.block_num_from,
+        block_num_to: request.block_num_to,
+    };
+
+    Ok((filter, page_request(request.pagination, MAX_NULLIFIERS_PAGE_SIZE)?))
+}
+
+fn accounts_query(
+    request: ListAccountsRequest,
+) -> Result<(AccountFilter, PageRequest<AccountId>), Status> {
+    let filter = AccountFilter {
+        block_num_from: request.block_num_from,
+        block_num_to: request.block_num_to,
+        on_chain: request.on_chain,
+    };
+
+    Ok((filter, page_request(request.pagination, MAX_ACCOUNTS_PAGE_SIZE)?))
+}
+
+fn notes_query(request: ListNotesRequest) -> Result<(NoteFilter, PageRequest<NoteKey>), Status> {
+    let note_type = request
+        .note_type
+        .map(|note_type| NoteType::try_from(note_type as u64))
+        .transpose()
+        .map_err(|err: NoteError| Status::invalid_argument(err.to_string()))?;
+
+    let recipient = request
+        .recipient
+        .map(RpoDigest::try_from)
+        .transpose()
+        .map_err(|err| Status::invalid_argument(err.to_string()))?;
+    let script_root = request
+        .script_root
+        .map(RpoDigest::try_from)
+        .transpose()
+        .map_err(|err| Status::invalid_argument(err.to_string()))?;
+
+    let filter = NoteFilter {
+        block_num_from: request.block_num_from,
+        block_num_to: request.block_num_to,
+        sender: request.sender.map(Into::into),
+        tag: request.tag,
+        note_type,
+        recipient,
+        script_root,
+        faucet_id: request.faucet_id.map(Into::into),
+    };
+
+    Ok((filter, page_request(request.pagination, MAX_NOTES_PAGE_SIZE)?))
+}
+
+fn account_state_header(account: state::AccountStateHeader) -> AccountStateHeader {
+    AccountStateHeader {
+        summary: Some((&account.summary).into()),
+        nonce: account.nonce.as_int(),
+        vault_root: Some(account.vault_root.into()),
+        storage_root: Some(account.storage_root.into()),
+        code_root: Some(account.code_root.into()),
+    }
+}
+
+fn nullifiers_response(page: Page<(Nullifier, BlockNumber), Nullifier>) -> ListNullifiersResponse {
+    let nullifiers = page
+        .items
+        .into_iter()
+        .map(|(key, block_num)| SmtLeafEntry {
+            key: Some(key.into()),
+            value: Some([Felt::from(block_num), ZERO, ZERO, ZERO].into()),
+        })
+        .collect();
+
+    ListNullifiersResponse {
+        nullifiers,
+        continuation_token: page.next.map(|key| key.to_bytes()),
+    }
+}
+
+fn accounts_response(page: Page<AccountInfo, AccountId>) -> ListAccountsResponse {
+    ListAccountsResponse {
+        accounts: page.items.iter().map(Into::into).collect(),
+        continuation_token: page.next.map(|key| key.to_bytes()),
+    }
+}
+
+fn notes_response(page: Page<Note, NoteKey>) -> ListNotesResponse {
+    let notes = page
+        .items
+        .into_iter()
+        .map(|note| generated::note::Note {
+            block_num: note.block_num,
+            note_index: note.note_created.absolute_note_index(),
+            note_id: Some(note.note_created.note_id.into()),
+            sender: Some(note.note_created.sender.into()),
+            tag: note.note_created.tag,
+            note_type: note.note_created.note_type as u32,
+            merkle_path: Some(note.merkle_path.into()),
+            details: note.note_created.details,
+        })
+        .collect();
+
+    ListNotesResponse {
+        notes,
+        continuation_token: page.next.map(|key| key.to_bytes()),
     }
 }
 
@@ -493,6 +1541,59 @@ fn invalid_argument<E: core::fmt::Debug>(err: E) -> Status {
     Status::invalid_argument(format!("{:?}", err))
 }
 
+/// Maps errors of queries against the state of a past block to the matching [Status].
+fn historical_state_error(err: HistoricalStateError) -> Status {
+    Status::out_of_range(err.to_string())
+}
+
+/// Maps errors of account queries which are caused by the request to the matching [Status].
+fn account_query_error(err: DatabaseError) -> Status {
+    match err {
+        DatabaseError::AccountNotFoundInDb(_) => Status::not_found(err.to_string()),
+        DatabaseError::AccountNotOnChain(_) => Status::invalid_argument(err.to_string()),
+        err => database_error(err),
+    }
+}
+
+/// Maps errors of account state queries to the matching [Status], invalid slots and faucets are
+/// reported as invalid arguments.
+fn account_state_query_error(err: AccountStateQueryError) -> Status {
+    match err {
+        AccountStateQueryError::DatabaseError(err) => account_query_error(err),
+        err => Status::invalid_argument(err.to_string()),
+    }
+}
+
+/// Maps errors of DB queries to the matching [Status], requests for pruned data are out of range.
+fn database_error(err: DatabaseError) -> Status {
+    match err {
+        DatabaseError::NotePruned(_) | DatabaseError::AccountStatePruned { .. } => {
+            Status::out_of_range(err.to_string())
+        },
+        err => internal_error(err),
+    }
+}
+
+/// Maps errors of block applications to the matching [Status], a full write queue is reported as
+/// backpressure and an aborted request as a conflict, so that the producer can retry later.
+fn apply_block_error(err: ApplyBlockError) -> Status {
+    match err {
+        ApplyBlockError::ConflictingBlock { .. } => Status::already_exists(err.to_string()),
+        ApplyBlockError::WriteQueueFull { .. } => Status::resource_exhausted(err.to_string()),
+        ApplyBlockError::WriteQueueTimeout { .. } => Status::deadline_exceeded(err.to_string()),
+        ApplyBlockError::WriteQueueAborted { .. } => Status::aborted(err.to_string()),
+        err => internal_error(err),
+    }
+}
+
+/// Maps errors of state syncs to the matching [Status].
+fn state_sync_error(err: StateSyncError) -> Status {
+    match err {
+        StateSyncError::DatabaseError(err) => database_error(err),
+        err => internal_error(err),
+    }
+}
+
 #[instrument(target = "miden-store", skip_all, err)]
 fn validate_nullifiers(nullifiers: &[generated::digest::Digest]) -> Result<Vec<Nullifier>, Status> {
     nullifiers
@@ -502,3 +1603,29 @@ fn validate_nullifiers(nullifiers: &[generated::digest::Digest]) -> Result<Vec<N
         .collect::<Result<_, ConversionError>>()
         .map_err(|_| invalid_argument("Digest field is not in the modulus range"))
 }
+
+#[cfg(test)]
+mod tests {
+    use tonic::Code;
+
+    use super::*;
+
+    #[test]
+    fn test_apply_block_error_codes() {
+        let conflicting_block = ApplyBlockError::ConflictingBlock {
+            block_num: 1,
+            stored_hash: RpoDigest::default(),
+            block_hash: RpoDigest::default(),
+        };
+        assert_eq!(apply_block_error(conflicting_block).code(), Code::AlreadyExists);
+
+        let queue_full = ApplyBlockError::WriteQueueFull { capacity: 16 };
+        assert_eq!(apply_block_error(queue_full).code(), Code::ResourceExhausted);
+
+        let aborted = ApplyBlockError::WriteQueueAborted { block_num: 2, next_block: 1 };
+        assert_eq!(apply_block_error(aborted).code(), Code::Aborted);
+
+        let invalid_block = ApplyBlockError::NewBlockInvalidPrevHash;
+        assert_eq!(apply_block_error(invalid_block).code(), Code::Internal);
+    }
+}
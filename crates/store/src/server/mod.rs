@@ -1,11 +1,16 @@
-use std::{net::ToSocketAddrs, sync::Arc};
+use std::{net::ToSocketAddrs, sync::Arc, time::Duration};
 
 use miden_node_proto::generated::store::api_server;
 use miden_node_utils::errors::ApiError;
 use tonic::transport::Server;
-use tracing::info;
+use tracing::{info, warn};
 
-use crate::{config::StoreConfig, db::Db, state::State, COMPONENT};
+use crate::{
+    config::{RetentionConfig, StoreConfig},
+    db::Db,
+    state::State,
+    COMPONENT,
+};
 
 mod api;
 
@@ -16,10 +21,14 @@ pub async fn serve(config: StoreConfig, db: Db) -> Result<(), ApiError> {
     info!(target: COMPONENT, %config, "Initializing server");
 
     let state = Arc::new(
-        State::load(db)
+        State::load(db, config.snapshot_file())
             .await
             .map_err(|err| ApiError::DatabaseConnectionFailed(err.to_string()))?,
     );
+    if let Some(retention) = config.retention {
+        tokio::spawn(prune_periodically(state.clone(), retention));
+    }
+
     let store = api_server::ApiServer::new(api::StoreApi { state });
 
     info!(target: COMPONENT, "Server initialized");
@@ -39,3 +48,22 @@ pub async fn serve(config: StoreConfig, db: Db) -> Result<(), ApiError> {
 
     Ok(())
 }
+
+// PRUNING
+// ================================================================================================
+
+/// Prunes the data of the old blocks every `retention.interval` seconds.
+async fn prune_periodically(state: Arc<State>, retention: RetentionConfig) {
+    // `tokio::time::interval` panics on a zero period
+    let period = Duration::from_secs(retention.interval.max(1));
+    let mut interval = tokio::time::interval(period);
+
+    loop {
+        interval.tick().await;
+
+        match state.prune(&retention).await {
+            Ok(count) => info!(target: COMPONENT, count, "Pruned old data"),
+            Err(err) => warn!(target: COMPONENT, err = err.to_string(), "Failed to prune old data"),
+        }
+    }
+}
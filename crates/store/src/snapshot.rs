@@ -0,0 +1,264 @@
+//! Snapshots of the in-memory Merkle structures of the store.
+//!
+//! Rebuilding the nullifier tree, the chain MMR and the account tree requires scanning and
+//! deserializing every nullifier, block header and account in the DB. A snapshot stores the leaves
+//! of these structures as of a given block in a compact file, together with their roots. At
+//! startup the structures are rebuilt from the snapshot, and only the blocks after it are replayed
+//! from the DB.
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use miden_objects::{
+    crypto::{
+        hash::rpo::RpoDigest,
+        merkle::{Mmr, SimpleSmt},
+    },
+    notes::Nullifier,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Word, ACCOUNT_TREE_DEPTH,
+};
+
+use crate::{errors::SnapshotError, nullifier_tree::NullifierTree, types::BlockNumber};
+
+/// Version of the snapshot file format, bumped on incompatible changes.
+const SNAPSHOT_VERSION: u8 = 1;
+
+// SNAPSHOT FILE
+// ================================================================================================
+
+/// Location of the snapshot file and frequency at which it is refreshed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotFile {
+    path: PathBuf,
+    interval: u32,
+}
+
+impl SnapshotFile {
+    /// Returns a new [SnapshotFile] written to `path` every `interval` blocks. An interval of zero
+    /// disables writing new snapshots, an existing snapshot is still used at startup.
+    pub fn new(path: PathBuf, interval: u32) -> Self {
+        Self { path, interval }
+    }
+
+    /// Returns the path of the snapshot file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns true if a snapshot must be taken after applying the block `block_num`.
+    pub fn is_due(&self, block_num: BlockNumber) -> bool {
+        self.interval != 0 && block_num % self.interval == 0
+    }
+
+    /// Reads the snapshot from disk, returns [None] if no snapshot was written yet.
+    pub fn read(&self) -> Result<Option<TreeSnapshot>, SnapshotError> {
+        if !self.path.exists() {
+            return Ok(None);
+        }
+
+        let data = fs::read(&self.path)?;
+        let snapshot =
+            TreeSnapshot::read_from_bytes(&data).map_err(SnapshotError::DeserializationError)?;
+
+        Ok(Some(snapshot))
+    }
+
+    /// Writes the snapshot to disk.
+    ///
+    /// The snapshot is written to a temporary file first, which then replaces the previous
+    /// snapshot, so that a crash while writing never leaves a truncated snapshot behind.
+    pub fn write(&self, snapshot: &TreeSnapshot) -> Result<(), SnapshotError> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let tmp_path = self.path.with_extension("tmp");
+        fs::write(&tmp_path, snapshot.to_bytes())?;
+        fs::rename(&tmp_path, &self.path)?;
+
+        Ok(())
+    }
+}
+
+// TREE SNAPSHOT
+// ================================================================================================
+
+/// Leaves and roots of the in-memory Merkle structures as of block `block_num`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeSnapshot {
+    pub block_num: BlockNumber,
+    pub nullifier_root: RpoDigest,
+    pub account_root: RpoDigest,
+    pub chain_root: RpoDigest,
+    nullifiers: Vec<(Nullifier, BlockNumber)>,
+    accounts: Vec<(u64, Word)>,
+    block_hashes: Vec<RpoDigest>,
+}
+
+impl TreeSnapshot {
+    /// Takes a snapshot of the given structures.
+    ///
+    /// The block number of the snapshot is the one of the last block added to `chain_mmr`.
+    pub fn new(
+        nullifier_tree: &NullifierTree,
+        chain_mmr: &Mmr,
+        account_tree: &SimpleSmt<ACCOUNT_TREE_DEPTH>,
+    ) -> Result<Self, SnapshotError> {
+        let forest = chain_mmr.forest();
+        let block_num = forest.checked_sub(1).ok_or(SnapshotError::EmptyChainMmr)?;
+
+        let block_hashes =
+            (0..forest).map(|pos| chain_mmr.get(pos)).collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            block_num: block_num as BlockNumber,
+            nullifier_root: nullifier_tree.root(),
+            account_root: account_tree.root(),
+            chain_root: chain_mmr.peaks(forest)?.hash_peaks(),
+            nullifiers: nullifier_tree.entries().collect(),
+            accounts: account_tree.leaves().map(|(account_id, hash)| (account_id, *hash)).collect(),
+            block_hashes,
+        })
+    }
+
+    /// Rebuilds the structures from the snapshot, checking them against the recorded roots.
+    pub fn into_parts(
+        self,
+    ) -> Result<(NullifierTree, Mmr, SimpleSmt<ACCOUNT_TREE_DEPTH>), SnapshotError> {
+        let nullifier_tree = NullifierTree::with_entries(self.nullifiers)?;
+        if nullifier_tree.root() != self.nullifier_root {
+            return Err(SnapshotError::CorruptedSnapshot("nullifier tree root mismatch"));
+        }
+
+        let account_tree = SimpleSmt::with_leaves(self.accounts)?;
+        if account_tree.root() != self.account_root {
+            return Err(SnapshotError::CorruptedSnapshot("account tree root mismatch"));
+        }
+
+        let chain_mmr: Mmr = self.block_hashes.into();
+        if chain_mmr.forest() != self.block_num as usize + 1 {
+            return Err(SnapshotError::CorruptedSnapshot("chain MMR size mismatch"));
+        }
+        if chain_mmr.peaks(chain_mmr.forest())?.hash_peaks() != self.chain_root {
+            return Err(SnapshotError::CorruptedSnapshot("chain MMR root mismatch"));
+        }
+
+        Ok((nullifier_tree, chain_mmr, account_tree))
+    }
+}
+
+impl Serializable for TreeSnapshot {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(SNAPSHOT_VERSION);
+        target.write_u32(self.block_num);
+        self.nullifier_root.write_into(target);
+        self.account_root.write_into(target);
+        self.chain_root.write_into(target);
+
+        target.write_usize(self.nullifiers.len());
+        for (nullifier, block_num) in self.nullifiers.iter() {
+            nullifier.write_into(target);
+            target.write_u32(*block_num);
+        }
+
+        target.write_usize(self.accounts.len());
+        for (account_id, hash) in self.accounts.iter() {
+            target.write_u64(*account_id);
+            RpoDigest::from(*hash).write_into(target);
+        }
+
+        target.write_usize(self.block_hashes.len());
+        target.write_many(&self.block_hashes);
+    }
+}
+
+impl Deserializable for TreeSnapshot {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let version = source.read_u8()?;
+        if version != SNAPSHOT_VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "Unsupported snapshot version {version}, expected {SNAPSHOT_VERSION}"
+            )));
+        }
+
+        let block_num = source.read_u32()?;
+        let nullifier_root = RpoDigest::read_from(source)?;
+        let account_root = RpoDigest::read_from(source)?;
+        let chain_root = RpoDigest::read_from(source)?;
+
+        let num_nullifiers = source.read_usize()?;
+        let nullifiers = (0..num_nullifiers)
+            .map(|_| Ok((Nullifier::read_from(source)?, source.read_u32()?)))
+            .collect::<Result<_, DeserializationError>>()?;
+
+        let num_accounts = source.read_usize()?;
+        let accounts = (0..num_accounts)
+            .map(|_| Ok((source.read_u64()?, RpoDigest::read_from(source)?.into())))
+            .collect::<Result<_, DeserializationError>>()?;
+
+        let num_block_hashes = source.read_usize()?;
+        let block_hashes = source.read_many::<RpoDigest>(num_block_hashes)?;
+
+        Ok(Self {
+            block_num,
+            nullifier_root,
+            account_root,
+            chain_root,
+            nullifiers,
+            accounts,
+            block_hashes,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::{Felt, ZERO};
+
+    use super::*;
+
+    fn num_to_rpo_digest(n: u64) -> RpoDigest {
+        RpoDigest::new([ZERO, ZERO, ZERO, Felt::new(n)])
+    }
+
+    fn snapshot() -> TreeSnapshot {
+        let nullifier_tree = NullifierTree::with_entries([
+            (Nullifier::from(num_to_rpo_digest(1)), 1),
+            (Nullifier::from(num_to_rpo_digest(2)), 2),
+        ])
+        .unwrap();
+        let chain_mmr: Mmr = (0..3).map(num_to_rpo_digest).collect::<Vec<_>>().into();
+        let account_tree = SimpleSmt::with_leaves([
+            (1, num_to_rpo_digest(3).into()),
+            (5, num_to_rpo_digest(4).into()),
+        ])
+        .unwrap();
+
+        TreeSnapshot::new(&nullifier_tree, &chain_mmr, &account_tree).unwrap()
+    }
+
+    #[test]
+    fn test_snapshot_roundtrip() {
+        let snapshot = snapshot();
+        assert_eq!(snapshot.block_num, 2);
+
+        let read = TreeSnapshot::read_from_bytes(&snapshot.to_bytes()).unwrap();
+        assert_eq!(read, snapshot);
+
+        let (nullifier_tree, chain_mmr, account_tree) = read.into_parts().unwrap();
+        assert_eq!(nullifier_tree.root(), snapshot.nullifier_root);
+        assert_eq!(nullifier_tree.get_block_num(&Nullifier::from(num_to_rpo_digest(2))), Some(2));
+        assert_eq!(account_tree.root(), snapshot.account_root);
+        assert_eq!(chain_mmr.forest(), 3);
+    }
+
+    #[test]
+    fn test_snapshot_root_mismatch() {
+        let mut snapshot = snapshot();
+        snapshot.account_root = num_to_rpo_digest(99);
+
+        assert!(matches!(snapshot.into_parts(), Err(SnapshotError::CorruptedSnapshot(_))));
+    }
+}
@@ -2,35 +2,56 @@
 //!
 //! The [State] provides data access and modifications methods, its main purpose is to ensure that
 //! data is atomically written, and that reads are consistent.
-use std::{mem, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    iter, mem,
+    sync::Arc,
+    time::Duration,
+};
 
 use miden_node_proto::{
-    domain::accounts::{AccountInfo, AccountUpdateDetails},
+    domain::{
+        accounts::{AccountInfo, AccountSummary, AccountUpdateDetails},
+        transactions::TransactionHeader,
+    },
     AccountInputRecord, NullifierWitness,
 };
 use miden_node_utils::formatting::{format_account_id, format_array};
 use miden_objects::{
+    accounts::Account,
+    assets::{Asset, FungibleAsset},
     block::BlockNoteTree,
     crypto::{
         hash::rpo::RpoDigest,
-        merkle::{LeafIndex, Mmr, MmrDelta, MmrPeaks, SimpleSmt, SmtProof, ValuePath},
+        merkle::{LeafIndex, MerklePath, Mmr, MmrDelta, MmrPeaks, SimpleSmt, SmtProof, ValuePath},
     },
     notes::{NoteId, NoteMetadata, Nullifier},
-    AccountError, BlockHeader, ACCOUNT_TREE_DEPTH, ZERO,
+    transaction::TransactionId,
+    AccountError, BlockHeader, Felt, Word, ACCOUNT_TREE_DEPTH, GENESIS_BLOCK, ZERO,
 };
 use tokio::{
-    sync::{oneshot, Mutex, RwLock},
-    time::Instant,
+    sync::{broadcast, oneshot, watch, Mutex, RwLock, Semaphore, SemaphorePermit},
+    task::JoinHandle,
+    time::{timeout, Instant},
 };
-use tracing::{error, info, info_span, instrument};
+use tracing::{error, info, info_span, instrument, warn};
 
 use crate::{
-    db::{Db, Note, NoteCreated, NullifierInfo, StateSyncUpdate},
+    config::RetentionConfig,
+    db::{
+        AccountFilter, AccountHistoryRecord, AccountMetadata, AccountMetadataFilter, BlockSummary,
+        Note, NoteCreated, NoteFilter, NoteKey, NoteStatus, NoteSyncUpdate, NullifierFilter,
+        NullifierInfo, NullifierSyncKey, Page, PageRequest, StateReplay, StateSyncUpdate, Storage,
+        TransactionInclusion,
+    },
     errors::{
-        ApplyBlockError, DatabaseError, GetBlockInputsError, StateInitializationError,
-        StateSyncError,
+        AccountStateQueryError, ApplyBlockError, DatabaseError, GetAccountProofsError,
+        GetBlockInclusionProofsError, GetBlockInputsError, HistoricalStateError, RollbackError,
+        SnapshotError, StateInitializationError, StateMismatchError, StateSyncError, VerifyError,
     },
     nullifier_tree::NullifierTree,
+    partial_tree::PartialTree,
+    snapshot::{SnapshotFile, TreeSnapshot},
     types::{AccountId, BlockNumber},
     COMPONENT,
 };
@@ -44,16 +65,477 @@ pub struct TransactionInputs {
     pub nullifiers: Vec<NullifierInfo>,
 }
 
+/// Latest hash of an account with its authentication path in the account tree.
+#[derive(Debug)]
+pub struct AccountProof {
+    pub account_id: AccountId,
+    pub account_hash: RpoDigest,
+    pub path: MerklePath,
+    /// Full state of the account, only set for public accounts if requested.
+    pub details: Option<Account>,
+}
+
+/// Latest hash of a public account with the commitments it is computed from.
+#[derive(Debug)]
+pub struct AccountStateHeader {
+    pub summary: AccountSummary,
+    pub nonce: Felt,
+    pub vault_root: RpoDigest,
+    pub storage_root: RpoDigest,
+    pub code_root: RpoDigest,
+}
+
+impl AccountStateHeader {
+    fn new(summary: AccountSummary, account: &Account) -> Self {
+        Self {
+            summary,
+            nonce: account.nonce(),
+            vault_root: account.vault().commitment(),
+            storage_root: account.storage().root(),
+            code_root: account.code().root(),
+        }
+    }
+}
+
+/// Value of a storage slot with its authentication path in the storage of the account.
+#[derive(Debug)]
+pub struct StorageSlotProof {
+    pub index: u32,
+    pub value: RpoDigest,
+    pub path: MerklePath,
+}
+
+/// Balance of a fungible asset with the opening of the asset in the vault of the account.
+#[derive(Debug)]
+pub struct VaultBalanceProof {
+    pub faucet_id: AccountId,
+    pub balance: u64,
+    pub proof: SmtProof,
+}
+
+/// Block header with its authentication path in the chain MMR.
+#[derive(Debug)]
+pub struct BlockInclusionProof {
+    pub block_header: BlockHeader,
+    pub mmr_path: MerklePath,
+}
+
+/// Maximum number of committed blocks buffered for each subscriber, a subscriber lagging further
+/// behind misses blocks.
+const COMMITTED_BLOCKS_CAPACITY: usize = 32;
+
+/// Data of a block, published to the subscribers once the block is committed.
+#[derive(Debug)]
+pub struct CommittedBlock {
+    pub block_header: BlockHeader,
+    pub notes: Vec<Note>,
+    pub num_account_updates: u32,
+    pub num_nullifiers: u32,
+}
+
+impl CommittedBlock {
+    /// Returns the header of the block with the number of items it added to the chain.
+    pub fn summary(&self) -> BlockSummary {
+        BlockSummary {
+            block_header: self.block_header,
+            num_account_updates: self.num_account_updates,
+            num_nullifiers: self.num_nullifiers,
+            num_notes: self.notes.len() as u32,
+        }
+    }
+}
+
+/// Maximum number of [State::apply_block] requests in the write queue, including the one being
+/// applied. Requests beyond it are rejected until the queue drains.
+const WRITE_QUEUE_CAPACITY: usize = 16;
+
+/// Maximum time a block which arrived early waits for its predecessor to be committed.
+const WRITE_QUEUE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Orders the [State::apply_block] requests by block number.
+///
+/// A request waits until its predecessor is committed, so that blocks which arrive early are held
+/// back instead of being rejected. The number of queued requests is bounded, a producer running too
+/// far ahead of the store gets a [ApplyBlockError::WriteQueueFull] error.
+///
+/// The waiting requests are aborted if the request for the next block fails or if the chain tip
+/// moves backwards, since their predecessor won't be committed until it is submitted again.
+struct WriteQueue {
+    /// Number of the next block to be applied, updated once a block is committed.
+    next_block: watch::Sender<BlockNumber>,
+    /// Number of times the waiting requests were aborted.
+    aborts: watch::Sender<u64>,
+    /// One permit per slot of the queue.
+    slots: Semaphore,
+}
+
+impl WriteQueue {
+    fn new(chain_tip: BlockNumber) -> Self {
+        let (next_block, _) = watch::channel(chain_tip + 1);
+        let (aborts, _) = watch::channel(0);
+        Self {
+            next_block,
+            aborts,
+            slots: Semaphore::new(WRITE_QUEUE_CAPACITY),
+        }
+    }
+
+    /// Waits until `block_num` is the next block to be applied, blocks which were already applied
+    /// don't wait.
+    ///
+    /// The returned turn holds the request's slot in the queue, it must be kept until the block
+    /// is applied.
+    async fn wait_turn(&self, block_num: BlockNumber) -> Result<QueueTurn<'_>, ApplyBlockError> {
+        let next_block = *self.next_block.borrow();
+        if block_num.saturating_sub(next_block) >= WRITE_QUEUE_CAPACITY as BlockNumber {
+            return Err(ApplyBlockError::WriteQueueFull { capacity: WRITE_QUEUE_CAPACITY });
+        }
+
+        let permit = self
+            .slots
+            .try_acquire()
+            .map_err(|_| ApplyBlockError::WriteQueueFull { capacity: WRITE_QUEUE_CAPACITY })?;
+
+        let mut aborts = self.aborts.subscribe();
+        let mut receiver = self.next_block.subscribe();
+        let wait = async {
+            tokio::select! {
+                res = receiver.wait_for(|&next| next >= block_num) => res.map(|next| *next).ok(),
+                _ = aborts.changed() => None,
+            }
+        };
+
+        let next_block = match timeout(WRITE_QUEUE_TIMEOUT, wait).await {
+            Ok(Some(next_block)) => next_block,
+            // the senders are owned by the queue, so the wait can otherwise only end by an abort
+            Ok(None) => {
+                return Err(ApplyBlockError::WriteQueueAborted {
+                    block_num,
+                    next_block: *self.next_block.borrow(),
+                })
+            },
+            Err(_) => {
+                return Err(ApplyBlockError::WriteQueueTimeout {
+                    block_num,
+                    next_block: *self.next_block.borrow(),
+                })
+            },
+        };
+
+        Ok(QueueTurn {
+            queue: self,
+            _permit: permit,
+            block_num,
+            is_next: block_num == next_block,
+        })
+    }
+
+    /// Sets the chain tip, letting the request for the following block proceed.
+    ///
+    /// If the chain tip moves backwards, the waiting requests are aborted.
+    fn set_chain_tip(&self, chain_tip: BlockNumber) {
+        let prev_next_block = self.next_block.send_replace(chain_tip + 1);
+        if chain_tip + 1 < prev_next_block {
+            self.abort_waiters();
+        }
+    }
+
+    fn abort_waiters(&self) {
+        self.aborts.send_modify(|aborts| *aborts += 1);
+    }
+}
+
+/// Turn of a request in the [WriteQueue].
+///
+/// If the request was for the next block and the block wasn't committed once the turn is dropped,
+/// the requests waiting for the following blocks are aborted.
+struct QueueTurn<'a> {
+    queue: &'a WriteQueue,
+    _permit: SemaphorePermit<'a>,
+    block_num: BlockNumber,
+    is_next: bool,
+}
+
+impl Drop for QueueTurn<'_> {
+    fn drop(&mut self) {
+        if self.is_next && *self.queue.next_block.borrow() <= self.block_num {
+            self.queue.abort_waiters();
+        }
+    }
+}
+
+/// Number of recent blocks for which proofs against the nullifier and account trees of the block
+/// can be generated, in addition to the latest block.
+const MAX_HISTORICAL_BLOCKS: usize = 32;
+
+/// Changes made by a block to the nullifier and account trees, used to rebuild the trees as of a
+/// recent block.
+#[derive(Debug)]
+struct BlockTreeChanges {
+    nullifiers: Vec<Nullifier>,
+    /// Accounts updated by the block, with their hash prior to the block.
+    prev_account_hashes: Vec<(AccountId, Word)>,
+}
+
 /// Container for state that needs to be updated atomically.
 struct InnerState {
     nullifier_tree: NullifierTree,
     chain_mmr: Mmr,
     account_tree: SimpleSmt<ACCOUNT_TREE_DEPTH>,
+
+    /// Changes made to the trees by the latest blocks, the last entry is the chain tip.
+    ///
+    /// The history is rebuilt from the DB when the state is loaded or rolled back.
+    tree_history: VecDeque<BlockTreeChanges>,
+}
+
+impl InnerState {
+    fn new(
+        nullifier_tree: NullifierTree,
+        chain_mmr: Mmr,
+        account_tree: SimpleSmt<ACCOUNT_TREE_DEPTH>,
+    ) -> Self {
+        Self {
+            nullifier_tree,
+            chain_mmr,
+            account_tree,
+            tree_history: VecDeque::new(),
+        }
+    }
+
+    /// Loads the in-memory structures from the `db`.
+    async fn load(db: &dyn Storage) -> Result<Self, StateInitializationError> {
+        let nullifier_tree = load_nullifier_tree(db).await?;
+        let chain_mmr = load_mmr(db).await?;
+        let account_tree = load_accounts(db).await?;
+
+        Ok(Self::new(nullifier_tree, chain_mmr, account_tree))
+    }
+
+    /// Builds the in-memory structures from all the data of the chain.
+    fn from_chain(chain: StateReplay) -> Result<Self, StateInitializationError> {
+        let nullifier_tree = NullifierTree::with_entries(chain.nullifiers)
+            .map_err(StateInitializationError::FailedToCreateNullifierTree)?;
+        let chain_mmr =
+            chain.block_headers.iter().map(BlockHeader::hash).collect::<Vec<_>>().into();
+        let account_tree = SimpleSmt::with_leaves(
+            chain
+                .account_hashes
+                .into_iter()
+                .map(|(account_id, account_hash)| (account_id, account_hash.into())),
+        )
+        .map_err(StateInitializationError::FailedToCreateAccountsTree)?;
+
+        Ok(Self::new(nullifier_tree, chain_mmr, account_tree))
+    }
+
+    /// Returns the number of the latest block added to the chain MMR.
+    fn chain_tip(&self) -> BlockNumber {
+        self.chain_mmr.forest().saturating_sub(1) as BlockNumber
+    }
+
+    /// Records the changes made to the trees by a new block, forgetting the oldest block of the
+    /// history if it is full.
+    fn push_tree_changes(&mut self, changes: BlockTreeChanges) {
+        if self.tree_history.len() == MAX_HISTORICAL_BLOCKS {
+            self.tree_history.pop_front();
+        }
+        self.tree_history.push_back(changes);
+    }
+
+    /// Rebuilds the changes made to the trees by the latest `MAX_HISTORICAL_BLOCKS` blocks from
+    /// the `db`, which must hold the same chain as the trees.
+    ///
+    /// The trees rebuilt from the history are checked against the headers of the past blocks, and
+    /// the history is cut after the latest block they don't match, e.g. when the DB predates the
+    /// account history.
+    async fn load_tree_history(&mut self, db: &dyn Storage) -> Result<(), DatabaseError> {
+        let chain_tip = self.chain_tip();
+        let oldest_block_num = chain_tip.saturating_sub(MAX_HISTORICAL_BLOCKS as BlockNumber);
+
+        let mut history: VecDeque<_> = (oldest_block_num..chain_tip)
+            .map(|_| BlockTreeChanges {
+                nullifiers: vec![],
+                prev_account_hashes: vec![],
+            })
+            .collect();
+        let index_of = |block_num: BlockNumber| {
+            block_num.checked_sub(oldest_block_num + 1).map(|index| index as usize)
+        };
+
+        for (nullifier, block_num) in db.select_state_since(oldest_block_num).await?.nullifiers {
+            if let Some(changes) = index_of(block_num).and_then(|index| history.get_mut(index)) {
+                changes.nullifiers.push(nullifier);
+            }
+        }
+        for (account_id, block_num, prev_hash) in
+            db.select_account_updates_since(oldest_block_num).await?
+        {
+            if let Some(changes) = index_of(block_num).and_then(|index| history.get_mut(index)) {
+                // accounts created by the block were empty leaves before it
+                changes
+                    .prev_account_hashes
+                    .push((account_id, prev_hash.unwrap_or_default().into()));
+            }
+        }
+        self.tree_history = history;
+
+        let past_headers = db
+            .select_block_headers_by_block_nums((oldest_block_num..chain_tip).collect())
+            .await?;
+        if let Some(header) =
+            past_headers.iter().rev().find(|header| !self.matches_past_block(header))
+        {
+            let block_num = header.block_num();
+            warn!(
+                block_num,
+                COMPONENT,
+                "Tree history doesn't match a past block, proofs against it are not served"
+            );
+            let num_blocks = (chain_tip - block_num - 1) as usize;
+            while self.tree_history.len() > num_blocks {
+                self.tree_history.pop_front();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the roots of the trees as of the past block `header`, rebuilt from the history,
+    /// match the header.
+    fn matches_past_block(&self, header: &BlockHeader) -> bool {
+        let block_num = Some(header.block_num());
+
+        let nullifier_root = self
+            .open_nullifiers_at(&[Nullifier::from(RpoDigest::default())], block_num)
+            .map(|proofs| proofs[0].compute_root());
+        let account_root = self
+            .open_accounts_at(&[0], block_num)
+            .map(|openings| openings[0].path.compute_root(0, openings[0].value));
+
+        nullifier_root.ok() == Some(header.nullifier_root())
+            && matches!(account_root, Ok(Ok(root)) if root == header.account_root())
+    }
+
+    /// Returns the changes made to the trees by the blocks after `block_num`, latest first.
+    fn tree_changes_since(
+        &self,
+        block_num: BlockNumber,
+    ) -> Result<impl Iterator<Item = &BlockTreeChanges>, HistoricalStateError> {
+        let chain_tip = self.chain_tip();
+        if block_num > chain_tip {
+            return Err(HistoricalStateError::BlockAfterChainTip { block_num, chain_tip });
+        }
+
+        let num_blocks = (chain_tip - block_num) as usize;
+        if num_blocks > self.tree_history.len() {
+            return Err(HistoricalStateError::BlockNotInHistory {
+                block_num,
+                oldest_block_num: chain_tip - self.tree_history.len() as BlockNumber,
+            });
+        }
+
+        Ok(self.tree_history.iter().rev().take(num_blocks))
+    }
+
+    /// Returns the openings of the `nullifiers` in the nullifier tree as of block `block_num`, or
+    /// in the latest tree if [None].
+    ///
+    /// The past openings are computed from the leaves changed since `block_num`, so that the tree
+    /// isn't copied.
+    fn open_nullifiers_at(
+        &self,
+        nullifiers: &[Nullifier],
+        block_num: Option<BlockNumber>,
+    ) -> Result<Vec<SmtProof>, HistoricalStateError> {
+        let Some(block_num) = block_num.filter(|&block_num| block_num != self.chain_tip()) else {
+            return Ok(nullifiers.iter().map(|n| self.nullifier_tree.open(n)).collect());
+        };
+
+        let removed: Vec<Nullifier> = self
+            .tree_changes_since(block_num)?
+            .flat_map(|changes| changes.nullifiers.iter().copied())
+            .collect();
+
+        Ok(self.nullifier_tree.open_without(nullifiers, &removed))
+    }
+
+    /// Returns the openings of the `account_ids` in the account tree as of block `block_num`, or
+    /// in the latest tree if [None].
+    ///
+    /// The past openings are computed from the leaves changed since `block_num`, so that the tree
+    /// isn't copied.
+    fn open_accounts_at(
+        &self,
+        account_ids: &[AccountId],
+        block_num: Option<BlockNumber>,
+    ) -> Result<Vec<ValuePath>, HistoricalStateError> {
+        let open = |account_id| self.account_tree.open(&LeafIndex::new_max_depth(account_id));
+        let Some(block_num) = block_num.filter(|&block_num| block_num != self.chain_tip()) else {
+            return Ok(account_ids.iter().map(|&account_id| open(account_id)).collect());
+        };
+
+        // the changes are visited from the latest to the oldest, so that the hash prior to the
+        // oldest change of each account is kept
+        let mut prev_hashes = BTreeMap::new();
+        for changes in self.tree_changes_since(block_num)? {
+            for &(account_id, prev_hash) in changes.prev_account_hashes.iter().rev() {
+                prev_hashes.insert(account_id, prev_hash);
+            }
+        }
+
+        let mut tree = PartialTree::default();
+        for &account_id in prev_hashes.keys().chain(account_ids) {
+            let ValuePath { value, path } = open(account_id);
+            tree.add_opening(account_id, value, &path);
+        }
+        tree.revert_leaves(
+            prev_hashes
+                .into_iter()
+                .map(|(account_id, prev_hash)| (account_id, prev_hash.into())),
+        );
+
+        Ok(account_ids
+            .iter()
+            .map(|&account_id| {
+                let (value, path) = tree.open(account_id);
+                ValuePath { value, path }
+            })
+            .collect())
+    }
+
+    /// Checks the in-memory structures are consistent with the block `header`, i.e. that they
+    /// represent the state right after the block was applied.
+    fn verify(&self, header: &BlockHeader) -> Result<(), StateMismatchError> {
+        let block_num = header.block_num();
+        let mismatch = |reason| StateMismatchError { block_num, reason };
+
+        if self.nullifier_tree.root() != header.nullifier_root() {
+            return Err(mismatch("nullifier root"));
+        }
+        if self.account_tree.root() != header.account_root() {
+            return Err(mismatch("account root"));
+        }
+
+        // the block's chain root commits to the chain MMR prior to the block being added to it
+        let forest = block_num as usize;
+        if self.chain_mmr.forest() != forest + 1
+            || self.chain_mmr.get(forest).ok() != Some(header.hash())
+        {
+            return Err(mismatch("chain tip"));
+        }
+        let chain_root = self.chain_mmr.peaks(forest).map(|peaks| peaks.hash_peaks());
+        if chain_root.ok() != Some(header.chain_root()) {
+            return Err(mismatch("chain root"));
+        }
+
+        Ok(())
+    }
 }
 
 /// The rollup state
 pub struct State {
-    db: Arc<Db>,
+    db: Arc<dyn Storage>,
 
     /// Read-write lock used to prevent writing to a structure while it is being used.
     ///
@@ -63,21 +545,65 @@ pub struct State {
     /// To allow readers to access the tree data while an update in being performed, and prevent
     /// TOCTOU issues, there must be no concurrent writers. This locks to serialize the writers.
     writer: Mutex<()>,
+
+    /// Orders the blocks to be applied, so that they are written one after the other.
+    write_queue: WriteQueue,
+
+    /// Snapshot of the in-memory structures, refreshed periodically while applying blocks.
+    snapshot_file: Option<SnapshotFile>,
+
+    /// Background write of the latest snapshot, so that a single snapshot is written at a time.
+    snapshot_write: Mutex<Option<JoinHandle<()>>>,
+
+    /// Publishes the blocks to the subscribers once committed.
+    committed_blocks: broadcast::Sender<Arc<CommittedBlock>>,
 }
 
 impl State {
     /// Loads the state from the `db`.
+    ///
+    /// If a `snapshot_file` is provided, the in-memory structures are loaded from the snapshot and
+    /// only the blocks applied after it are replayed. The structures are rebuilt from the `db` if
+    /// the snapshot is missing or inconsistent with the stored block headers.
     #[instrument(target = "miden-store", skip_all)]
-    pub async fn load(mut db: Db) -> Result<Self, StateInitializationError> {
-        let nullifier_tree = load_nullifier_tree(&mut db).await?;
-        let chain_mmr = load_mmr(&mut db).await?;
-        let account_tree = load_accounts(&mut db).await?;
+    pub async fn load(
+        db: impl Storage,
+        snapshot_file: Option<SnapshotFile>,
+    ) -> Result<Self, StateInitializationError> {
+        let snapshot_state = match &snapshot_file {
+            Some(snapshot_file) => {
+                load_from_snapshot(&db, snapshot_file).await.unwrap_or_else(|err| {
+                    warn!(
+                        err = err.to_string(),
+                        COMPONENT,
+                        "Failed to load state from snapshot, rebuilding it from the database"
+                    );
+                    None
+                })
+            },
+            None => None,
+        };
 
-        let inner = RwLock::new(InnerState { nullifier_tree, chain_mmr, account_tree });
+        let mut inner = match snapshot_state {
+            Some(inner) => inner,
+            None => InnerState::load(&db).await?,
+        };
+        inner.load_tree_history(&db).await?;
 
+        let write_queue = WriteQueue::new(inner.chain_tip());
+        let inner = RwLock::new(inner);
         let writer = Mutex::new(());
-        let db = Arc::new(db);
-        Ok(Self { db, inner, writer })
+        let db: Arc<dyn Storage> = Arc::new(db);
+        let (committed_blocks, _) = broadcast::channel(COMMITTED_BLOCKS_CAPACITY);
+        Ok(Self {
+            db,
+            inner,
+            writer,
+            write_queue,
+            snapshot_file,
+            snapshot_write: Mutex::new(None),
+            committed_blocks,
+        })
     }
 
     /// Apply changes of a new block to the DB and in-memory data structures.
@@ -89,6 +615,8 @@ impl State {
     /// provide consistent results for all endpoints. In order to achieve consistency, the
     /// following steps are used:
     ///
+    /// - the request waits in the write queue until the previous block is committed, and takes
+    ///   the writer lock.
     /// - the request data is validated, prior to starting any modifications.
     /// - a transaction is open in the DB and the writes are started.
     /// - while the transaction is not committed, concurrent reads are allowed, both the DB and
@@ -104,11 +632,14 @@ impl State {
     pub async fn apply_block(
         &self,
         block_header: BlockHeader,
+        block_data: Vec<u8>,
         nullifiers: Vec<Nullifier>,
         accounts: Vec<AccountUpdateDetails>,
         notes: Vec<NoteCreated>,
+        transactions: Vec<TransactionHeader>,
     ) -> Result<(), ApplyBlockError> {
-        let _ = self.writer.try_lock().map_err(|_| ApplyBlockError::ConcurrentWrite)?;
+        let _turn = self.write_queue.wait_turn(block_header.block_num()).await?;
+        let _writer = self.writer.lock().await;
 
         // ensures the right block header is being processed
         let prev_block = self
@@ -117,6 +648,11 @@ impl State {
             .await?
             .ok_or(ApplyBlockError::DbBlockHeaderEmpty)?;
 
+        // the block may be re-submitted by a producer which didn't get the response of a previous
+        // request, it is accepted as long as it matches the stored block
+        if block_header.block_num() <= prev_block.block_num() {
+            return self.check_applied_block(&block_header).await;
+        }
         if block_header.block_num() != prev_block.block_num() + 1 {
             return Err(ApplyBlockError::NewBlockInvalidBlockNum);
         }
@@ -125,7 +661,7 @@ impl State {
         }
 
         // scope to read in-memory data, validate the request, and compute intermediary values
-        let (account_tree, chain_mmr, nullifier_tree, notes) = {
+        let (account_tree, chain_mmr, nullifier_tree, notes, tree_changes) = {
             let inner = self.inner.read().await;
 
             let span = info_span!(target: COMPONENT, "update_in_memory_structs").entered();
@@ -179,12 +715,17 @@ impl State {
 
             // update account tree
             let mut account_tree = inner.account_tree.clone();
-            for update in &accounts {
-                account_tree.insert(
-                    LeafIndex::new_max_depth(update.account_id.into()),
-                    update.final_state_hash.into(),
-                );
-            }
+            let prev_account_hashes = accounts
+                .iter()
+                .map(|update| {
+                    let account_id: AccountId = update.account_id.into();
+                    let prev_hash = account_tree.insert(
+                        LeafIndex::new_max_depth(account_id),
+                        update.final_state_hash.into(),
+                    );
+                    (account_id, prev_hash)
+                })
+                .collect();
 
             if account_tree.root() != block_header.account_root() {
                 return Err(ApplyBlockError::NewBlockInvalidAccountRoot);
@@ -216,7 +757,12 @@ impl State {
                 })
                 .collect::<Result<Vec<_>, ApplyBlockError>>()?;
 
-            (account_tree, chain_mmr, nullifier_tree, notes)
+            let tree_changes = BlockTreeChanges {
+                nullifiers: nullifiers.clone(),
+                prev_account_hashes,
+            };
+
+            (account_tree, chain_mmr, nullifier_tree, notes, tree_changes)
         };
 
         // signals the transaction is ready to be committed, and the write lock can be acquired
@@ -228,139 +774,563 @@ impl State {
         // overlapping. Namely, the DB transaction only proceeds after this task acquires the
         // in-memory write lock. This requires the DB update to run concurrently, so a new task is
         // spawned.
+        let committed_block = CommittedBlock {
+            block_header,
+            notes: notes.clone(),
+            // counted the same way as the block summaries loaded from the DB
+            num_account_updates: accounts
+                .iter()
+                .map(|update| AccountId::from(update.account_id))
+                .collect::<BTreeSet<_>>()
+                .len() as u32,
+            num_nullifiers: nullifiers.len() as u32,
+        };
         let db = self.db.clone();
         let handle = tokio::spawn(async move {
-            db.apply_block(allow_acquire, acquire_done, block_header, notes, nullifiers, accounts)
-                .await
+            db.apply_block(
+                allow_acquire,
+                acquire_done,
+                block_header,
+                block_data,
+                notes,
+                nullifiers,
+                accounts,
+                transactions,
+            )
+            .await
         });
 
-        acquired_allowed
-            .await
-            .map_err(ApplyBlockError::BlockApplyingBrokenBecauseOfClosedChannel)?;
+        // the DB update drops the channel when the block can't be written, its own error is
+        // returned in that case
+        if let Err(err) = acquired_allowed.await {
+            return Err(match handle.await {
+                Ok(Err(db_err)) => ApplyBlockError::DatabaseError(db_err),
+                Err(join_err) => ApplyBlockError::ApplyBlockTaskFailed(join_err.to_string()),
+                Ok(Ok(())) => ApplyBlockError::BlockApplyingBrokenBecauseOfClosedChannel(err),
+            });
+        }
 
         // scope to update the in-memory data
         {
             let mut inner = self.inner.write().await;
             let _ = inform_acquire_done.send(());
 
-            let _ = mem::replace(&mut inner.chain_mmr, chain_mmr);
-            let _ = mem::replace(&mut inner.nullifier_tree, nullifier_tree);
-            let _ = mem::replace(&mut inner.account_tree, account_tree);
-        }
+            let prev_chain_mmr = mem::replace(&mut inner.chain_mmr, chain_mmr);
+            let prev_nullifier_tree = mem::replace(&mut inner.nullifier_tree, nullifier_tree);
+            let prev_account_tree = mem::replace(&mut inner.account_tree, account_tree);
+
+            // The write lock is held until the DB transaction is done, so that the new in-memory
+            // data is never served unless the block was committed.
+            let result = match handle.await {
+                Ok(result) => result.map_err(ApplyBlockError::DatabaseError),
+                Err(err) => {
+                    error!(
+                        is_cancelled = err.is_cancelled(),
+                        is_panic = err.is_panic(),
+                        COMPONENT,
+                        "apply_block task joined with an error"
+                    );
+                    Err(ApplyBlockError::ApplyBlockTaskFailed(err.to_string()))
+                },
+            };
 
-        match handle.await {
-            // These errors should never happen. It is unclear if the state of the node would be
-            // valid because the apply_block task may have failed when committing the transaction, so
-            // the in-memory and the DB state would be out-of-sync.
-            //
-            // TODO: shutdown #91
-            Err(err) => {
+            // The DB transaction is rolled back when it isn't committed, revert the in-memory
+            // data to keep it consistent with the DB.
+            if let Err(err) = result {
                 error!(
-                    is_cancelled = err.is_cancelled(),
-                    is_panic = err.is_panic(),
-                    COMPONENT,
-                    "apply_block task joined with an error"
-                );
-            },
-            Ok(Err(err)) => {
-                error!(err = err.to_string(), COMPONENT, "apply_block failed with a DB error");
-            },
-            Ok(Ok(())) => {
-                info!(
-                    block_hash = block_header.hash().to_hex(),
+                    err = err.to_string(),
                     block_num = block_header.block_num(),
                     COMPONENT,
-                    "apply_block sucessfull"
+                    "apply_block failed, reverting in-memory data"
                 );
-            },
+
+                inner.chain_mmr = prev_chain_mmr;
+                inner.nullifier_tree = prev_nullifier_tree;
+                inner.account_tree = prev_account_tree;
+
+                return Err(err);
+            }
+
+            inner.push_tree_changes(tree_changes);
+        }
+
+        self.write_queue.set_chain_tip(block_header.block_num());
+
+        info!(
+            block_hash = block_header.hash().to_hex(),
+            block_num = block_header.block_num(),
+            COMPONENT,
+            "apply_block sucessfull"
+        );
+
+        // sending only fails if there are no subscribers
+        let _ = self.committed_blocks.send(Arc::new(committed_block));
+
+        if let Some(snapshot_file) = self
+            .snapshot_file
+            .as_ref()
+            .filter(|snapshot_file| snapshot_file.is_due(block_header.block_num()))
+        {
+            self.write_snapshot(snapshot_file.clone()).await;
         }
 
         Ok(())
     }
 
-    /// Queries a [BlockHeader] from the database.
-    ///
-    /// If [None] is given as the value of `block_num`, the latest [BlockHeader] is returned.
-    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
-    pub async fn get_block_header(
-        &self,
-        block_num: Option<BlockNumber>,
-    ) -> Result<Option<BlockHeader>, DatabaseError> {
-        self.db.select_block_header_by_block_num(block_num).await
-    }
+    /// Checks that the stored block at the height of `block_header` is the same block, so that
+    /// applying it again is a no-op.
+    async fn check_applied_block(&self, block_header: &BlockHeader) -> Result<(), ApplyBlockError> {
+        let block_num = block_header.block_num();
+        let stored_header = self
+            .db
+            .select_block_header_by_block_num(Some(block_num))
+            .await?
+            .ok_or(ApplyBlockError::DbBlockHeaderEmpty)?;
 
-    /// Generates membership proofs for each one of the `nullifiers` against the latest nullifier
-    /// tree.
-    ///
-    /// Note: these proofs are invalidated once the nullifier tree is modified, i.e. on a new block.
-    #[instrument(target = "miden-store", skip_all, ret(level = "debug"))]
-    pub async fn check_nullifiers(&self, nullifiers: &[Nullifier]) -> Vec<SmtProof> {
-        let inner = self.inner.read().await;
-        nullifiers.iter().map(|n| inner.nullifier_tree.open(n)).collect()
+        if stored_header.hash() != block_header.hash() {
+            return Err(ApplyBlockError::ConflictingBlock {
+                block_num,
+                stored_hash: stored_header.hash(),
+                block_hash: block_header.hash(),
+            });
+        }
+
+        info!(
+            block_hash = block_header.hash().to_hex(),
+            block_num, COMPONENT, "apply_block skipped, block already applied"
+        );
+
+        Ok(())
     }
 
-    /// Queries a list of [Note] from the database.
-    ///
-    /// If the provided list of [NoteId] given is empty or no [Note] matches the provided [NoteId]
-    /// an empty list is returned.
-    pub async fn get_notes_by_id(&self, note_ids: Vec<NoteId>) -> Result<Vec<Note>, DatabaseError> {
-        self.db.select_notes_by_id(note_ids).await
+    /// Subscribes to the blocks committed from now on.
+    pub fn subscribe_blocks(&self) -> broadcast::Receiver<Arc<CommittedBlock>> {
+        self.committed_blocks.subscribe()
     }
 
-    /// Loads data to synchronize a client.
+    /// Reverts the chain to block `block_num`, deleting the data of all the blocks after it.
     ///
-    /// The client's request contains a list of tag prefixes, this method will return the first
-    /// block with a matching tag, or the chain tip. All the other values are filter based on this
-    /// block range.
+    /// The accounts are restored to their state at `block_num`, the in-memory structures are then
+    /// rebuilt from the rolled back data and checked against the header of `block_num`. The DB
+    /// rollback is only committed if the check passes, otherwise the DB and the in-memory
+    /// structures are left untouched.
     ///
-    /// # Arguments
+    /// # Returns
     ///
-    /// - `block_num`: The last block *know* by the client, updates start from the next block.
-    /// - `account_ids`: Include the account's hash if their _last change_ was in the result's block
-    ///   range.
-    /// - `note_tag_prefixes`: Only the 16 high bits of the tags the client is interested in, result
-    ///   will include notes with matching prefixes, the first block with a matching note determines
-    ///   the block range.
-    /// - `nullifier_prefixes`: Only the 16 high bits of the nullifiers the client is interested in,
-    ///   results will include nullifiers matching prefixes produced in the given block range.
-    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
-    pub async fn sync_state(
-        &self,
-        block_num: BlockNumber,
-        account_ids: &[AccountId],
-        note_tag_prefixes: &[u32],
-        nullifier_prefixes: &[u32],
-    ) -> Result<(StateSyncUpdate, MmrDelta), StateSyncError> {
-        let inner = self.inner.read().await;
+    /// The number of affected rows in the DB.
+    #[instrument(target = "miden-store", skip_all, err)]
+    pub async fn rollback_to(&self, block_num: BlockNumber) -> Result<usize, RollbackError> {
+        let _writer = self.writer.lock().await;
 
-        let state_sync = self
+        // hold the in-memory data for the whole operation, so that no reads are served while the
+        // DB and the in-memory structures are out-of-sync
+        let mut inner = self.inner.write().await;
+
+        let chain_tip = self
             .db
-            .get_state_sync(block_num, account_ids, note_tag_prefixes, nullifier_prefixes)
-            .await?;
+            .select_block_header_by_block_num(None)
+            .await?
+            .ok_or(RollbackError::DbBlockHeaderEmpty)?
+            .block_num();
+        if block_num > chain_tip {
+            return Err(RollbackError::TargetAfterChainTip { target: block_num, chain_tip });
+        }
 
-        let delta = if block_num == state_sync.block_header.block_num() {
-            // The client is in sync with the chain tip.
-            MmrDelta { forest: block_num as usize, data: vec![] }
-        } else {
-            // Important notes about the boundary conditions:
-            //
-            // - The Mmr forest is 1-indexed whereas the block number is 0-indexed. The Mmr root
-            // contained in the block header always lag behind by one block, this is because the Mmr
-            // leaves are hashes of block headers, and we can't have self-referential hashes. These two
-            // points cancel out and don't require adjusting.
-            // - Mmr::get_delta is inclusive, whereas the sync_state request block_num is defined to be
-            // exclusive, so the from_forest has to be adjusted with a +1
-            let from_forest = (block_num + 1) as usize;
-            let to_forest = state_sync.block_header.block_num() as usize;
-            inner
-                .chain_mmr
-                .get_delta(from_forest, to_forest)
-                .map_err(StateSyncError::FailedToBuildMmrDelta)?
-        };
+        let target_header = self
+            .db
+            .select_block_header_by_block_num(Some(block_num))
+            .await?
+            .ok_or(RollbackError::DbBlockHeaderEmpty)?;
+
+        // the DB sends the rolled back data, and waits for the check of the rebuilt structures
+        // before committing the rollback
+        let (send_rolled_back, rolled_back) = oneshot::channel();
+        let (send_commit, commit) = oneshot::channel();
+        let db = self.db.clone();
+        let handle =
+            tokio::spawn(async move { db.rollback_to(block_num, send_rolled_back, commit).await });
+
+        let rebuilt = match rolled_back.await {
+            Ok(chain) => Some(InnerState::from_chain(chain).map_err(RollbackError::from).and_then(
+                |rebuilt| {
+                    rebuilt.verify(&target_header)?;
+                    Ok(rebuilt)
+                },
+            )),
+            // the DB rollback failed before sending the data, its error is returned below
+            Err(_) => None,
+        };
+        if matches!(rebuilt, Some(Ok(_))) {
+            let _ = send_commit.send(());
+        } else {
+            // closing the channel discards the DB rollback
+            drop(send_commit);
+        }
+
+        let result =
+            handle.await.map_err(|err| RollbackError::RollbackTaskFailed(err.to_string()))?;
+        // a failed check also fails the DB rollback, its reason is returned instead
+        let rebuilt = match rebuilt {
+            Some(rebuilt) => rebuilt?,
+            None => {
+                result?;
+                return Err(RollbackError::RollbackTaskFailed(
+                    "rolled back data wasn't received".to_string(),
+                ));
+            },
+        };
+        let count = result?;
+
+        *inner = rebuilt;
+        self.write_queue.set_chain_tip(block_num);
+
+        // the rollback is committed, a failure to rebuild the history only limits the proofs
+        if let Err(err) = inner.load_tree_history(self.db.as_ref()).await {
+            warn!(
+                err = err.to_string(),
+                COMPONENT, "Failed to rebuild the tree history after rollback"
+            );
+        }
+
+        // an existing snapshot may be ahead of the new chain tip, replace it so that it can be
+        // used on the next start
+        if let Some(snapshot_file) = &self.snapshot_file {
+            // a snapshot of the previous chain which is still being written would replace this one
+            if let Some(snapshot_write) = self.snapshot_write.lock().await.take() {
+                let _ = snapshot_write.await;
+            }
+            let snapshot =
+                TreeSnapshot::new(&inner.nullifier_tree, &inner.chain_mmr, &inner.account_tree)
+                    .and_then(|snapshot| snapshot_file.write(&snapshot));
+            if let Err(err) = snapshot {
+                warn!(
+                    err = err.to_string(),
+                    COMPONENT, "Failed to replace snapshot after rollback"
+                );
+            }
+        }
+
+        info!(block_num, chain_tip, COMPONENT, "Rolled back chain");
+
+        Ok(count)
+    }
+
+    /// Prunes the data of the blocks which are older than the `retention` allows.
+    ///
+    /// # Returns
+    ///
+    /// The number of pruned items in the DB.
+    #[instrument(target = "miden-store", skip_all, err)]
+    pub async fn prune(&self, retention: &RetentionConfig) -> Result<usize, DatabaseError> {
+        // the writer lock is also held by `apply_block` and `rollback_to`, so that the chain tip
+        // can't move while pruning
+        let _writer = self.writer.lock().await;
+
+        let chain_tip = self.inner.read().await.chain_tip();
+        let target = retention.pruning_target(chain_tip);
+
+        self.db.prune(target).await
+    }
+
+    /// Takes a snapshot of the in-memory structures, which is then written to disk in the
+    /// background.
+    ///
+    /// The snapshot is skipped while the previous one is still being written, so that the writes
+    /// can't interleave and an older snapshot can't replace a newer one.
+    async fn write_snapshot(&self, snapshot_file: SnapshotFile) {
+        let mut snapshot_write = self.snapshot_write.lock().await;
+        if snapshot_write.as_ref().is_some_and(|handle| !handle.is_finished()) {
+            warn!(COMPONENT, "Previous snapshot is still being written, skipping snapshot");
+            return;
+        }
+
+        let snapshot = {
+            let inner = self.inner.read().await;
+            TreeSnapshot::new(&inner.nullifier_tree, &inner.chain_mmr, &inner.account_tree)
+        };
+
+        let snapshot = match snapshot {
+            Ok(snapshot) => snapshot,
+            Err(err) => {
+                error!(err = err.to_string(), COMPONENT, "Failed to take snapshot");
+                return;
+            },
+        };
+
+        *snapshot_write = Some(tokio::task::spawn_blocking(move || {
+            let block_num = snapshot.block_num;
+            match snapshot_file.write(&snapshot) {
+                Ok(()) => info!(block_num, COMPONENT, "Snapshot written"),
+                Err(err) => {
+                    error!(err = err.to_string(), block_num, COMPONENT, "Failed to write snapshot")
+                },
+            }
+        }));
+    }
+
+    /// Returns the headers of the blocks `block_nums` with their authentication paths in the chain
+    /// MMR committed to by the block `chain_block_num`, or by the latest block if [None].
+    ///
+    /// The MMR committed to by a block contains all the blocks before it, so that the proofs verify
+    /// against the `chain_root` of the returned header of `chain_block_num`.
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    pub async fn get_block_inclusion_proofs(
+        &self,
+        block_nums: &[BlockNumber],
+        chain_block_num: Option<BlockNumber>,
+    ) -> Result<(BlockHeader, Vec<BlockInclusionProof>), GetBlockInclusionProofsError> {
+        let inner = self.inner.read().await;
+
+        let chain_tip = inner.chain_tip();
+        let chain_block_num = chain_block_num.unwrap_or(chain_tip);
+        if chain_block_num > chain_tip {
+            return Err(GetBlockInclusionProofsError::BlockAfterChainTip {
+                block_num: chain_block_num,
+                chain_tip,
+            });
+        }
+
+        let forest = chain_block_num as usize;
+        let mmr_paths = block_nums
+            .iter()
+            .map(|&block_num| {
+                if block_num >= chain_block_num {
+                    return Err(GetBlockInclusionProofsError::BlockNotInChainMmr {
+                        block_num,
+                        chain_block_num,
+                    });
+                }
+                inner
+                    .chain_mmr
+                    .open(block_num as usize, forest)
+                    .map(|proof| proof.merkle_path)
+                    .map_err(GetBlockInclusionProofsError::FailedToOpenMmr)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let headers: BTreeMap<BlockNumber, BlockHeader> = self
+            .db
+            .select_block_headers_by_block_nums(
+                block_nums.iter().copied().chain([chain_block_num]).collect(),
+            )
+            .await?
+            .into_iter()
+            .map(|header| (header.block_num(), header))
+            .collect();
+        let get_header = |block_num| {
+            headers
+                .get(&block_num)
+                .copied()
+                .ok_or(GetBlockInclusionProofsError::BlockHeaderNotFound(block_num))
+        };
+
+        let chain_block_header = get_header(chain_block_num)?;
+        let proofs = block_nums
+            .iter()
+            .zip(mmr_paths)
+            .map(|(&block_num, mmr_path)| {
+                Ok(BlockInclusionProof {
+                    block_header: get_header(block_num)?,
+                    mmr_path,
+                })
+            })
+            .collect::<Result<_, GetBlockInclusionProofsError>>()?;
+
+        Ok((chain_block_header, proofs))
+    }
+
+    /// Queries a [BlockHeader] from the database.
+    ///
+    /// If [None] is given as the value of `block_num`, the latest [BlockHeader] is returned.
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    pub async fn get_block_header(
+        &self,
+        block_num: Option<BlockNumber>,
+    ) -> Result<Option<BlockHeader>, DatabaseError> {
+        self.db.select_block_header_by_block_num(block_num).await
+    }
+
+    /// Queries at most `limit` block headers starting at `block_num`, with the number of items
+    /// added by each block.
+    pub async fn get_block_summaries(
+        &self,
+        block_num: BlockNumber,
+        limit: usize,
+    ) -> Result<Vec<BlockSummary>, DatabaseError> {
+        self.db.select_block_summaries(block_num, limit).await
+    }
+
+    /// Queries the data of a block from the database by its number.
+    ///
+    /// The block is returned as it was applied, encoded as an `ApplyBlockRequest`.
+    pub async fn get_block_by_number(
+        &self,
+        block_num: BlockNumber,
+    ) -> Result<Option<Vec<u8>>, DatabaseError> {
+        self.db.select_block_by_num(block_num).await
+    }
+
+    /// Queries the data of a block from the database by the hash of its header.
+    ///
+    /// The block is returned as it was applied, encoded as an `ApplyBlockRequest`.
+    pub async fn get_block_by_hash(
+        &self,
+        block_hash: RpoDigest,
+    ) -> Result<Option<Vec<u8>>, DatabaseError> {
+        self.db.select_block_by_hash(block_hash).await
+    }
+
+    /// Generates membership proofs for each one of the `nullifiers` against the nullifier tree of
+    /// block `block_num`, or the latest nullifier tree if [None].
+    ///
+    /// Only the latest `MAX_HISTORICAL_BLOCKS` blocks can be requested. The proofs against the latest tree are invalidated once the nullifier tree is
+    /// modified, i.e. on a new block.
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    pub async fn check_nullifiers(
+        &self,
+        nullifiers: &[Nullifier],
+        block_num: Option<BlockNumber>,
+    ) -> Result<Vec<SmtProof>, HistoricalStateError> {
+        let inner = self.inner.read().await;
+
+        inner.open_nullifiers_at(nullifiers, block_num)
+    }
+
+    /// Queries a list of [Note] from the database.
+    ///
+    /// If the provided list of [NoteId] given is empty or no [Note] matches the provided [NoteId]
+    /// an empty list is returned.
+    pub async fn get_notes_by_id(&self, note_ids: Vec<NoteId>) -> Result<Vec<Note>, DatabaseError> {
+        self.db.select_notes_by_id(note_ids).await
+    }
+
+    /// Returns the block in which each of the given notes was created, its authentication path in
+    /// the note tree of that block, and the block in which it was consumed.
+    ///
+    /// Only the consumption of notes with public details can be tracked, as the nullifier of the
+    /// other notes is unknown to the store. Notes which are not in the store are omitted.
+    pub async fn get_note_statuses(
+        &self,
+        note_ids: Vec<NoteId>,
+    ) -> Result<Vec<NoteStatus>, DatabaseError> {
+        self.db.select_note_statuses_by_id(note_ids).await
+    }
+
+    /// Returns the block and position at which each of the given transactions was included.
+    ///
+    /// Transactions which were not included in any block are omitted from the result.
+    pub async fn get_transaction_inclusion(
+        &self,
+        transaction_ids: Vec<TransactionId>,
+    ) -> Result<Vec<TransactionInclusion>, DatabaseError> {
+        self.db.select_transactions_by_id(transaction_ids).await
+    }
+
+    /// Loads data to synchronize a client.
+    ///
+    /// The client's request contains a list of tag prefixes, this method will return the first
+    /// block with a matching tag, or the chain tip. All the other values are filter based on this
+    /// block range.
+    ///
+    /// # Arguments
+    ///
+    /// - `block_num`: The last block *know* by the client, updates start from the next block.
+    /// - `account_ids`: Include the account's hash if their _last change_ was in the result's block
+    ///   range.
+    /// - `note_tag_prefixes`: Only the 16 high bits of the tags the client is interested in, result
+    ///   will include notes with matching prefixes, the first block with a matching note determines
+    ///   the block range.
+    /// - `nullifier_prefixes`: Only the 16 high bits of the nullifiers the client is interested in,
+    ///   results will include nullifiers matching prefixes produced in the given block range.
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    pub async fn sync_state(
+        &self,
+        block_num: BlockNumber,
+        account_ids: &[AccountId],
+        note_tag_prefixes: &[u32],
+        nullifier_prefixes: &[u32],
+    ) -> Result<(StateSyncUpdate, MmrDelta), StateSyncError> {
+        let inner = self.inner.read().await;
+
+        let state_sync = self
+            .db
+            .get_state_sync(block_num, account_ids, note_tag_prefixes, nullifier_prefixes)
+            .await?;
+
+        let delta =
+            get_mmr_delta(&inner.chain_mmr, block_num, state_sync.block_header.block_num())?;
 
         Ok((state_sync, delta))
     }
 
+    /// Loads the notes matching the requested tags or senders in the first `max_blocks` blocks
+    /// after `block_num` which contain such notes.
+    ///
+    /// Unlike [State::sync_state], which stops at the first block with matching notes, this
+    /// covers several blocks at once. Together with the MMR delta up to the last block covered,
+    /// the Merkle path of each block with matching notes in the updated chain MMR is returned, so
+    /// that the client can authenticate these blocks. The last block covered is not part of that
+    /// MMR, so no path is returned for it.
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    pub async fn sync_notes(
+        &self,
+        block_num: BlockNumber,
+        note_tag_prefixes: &[u32],
+        account_ids: &[AccountId],
+        max_blocks: usize,
+    ) -> Result<(NoteSyncUpdate, MmrDelta, Vec<Option<MerklePath>>), StateSyncError> {
+        let inner = self.inner.read().await;
+
+        let note_sync = self
+            .db
+            .get_note_sync(block_num, note_tag_prefixes, account_ids, max_blocks)
+            .await?;
+
+        let forest = note_sync.block_header.block_num() as usize;
+        let delta = get_mmr_delta(&inner.chain_mmr, block_num, note_sync.block_header.block_num())?;
+        let mmr_paths = note_sync
+            .block_headers
+            .iter()
+            .map(|header| {
+                let pos = header.block_num() as usize;
+                if pos < forest {
+                    inner
+                        .chain_mmr
+                        .open(pos, forest)
+                        .map(|proof| Some(proof.merkle_path))
+                        .map_err(StateSyncError::FailedToOpenMmr)
+                } else {
+                    Ok(None)
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok((note_sync, delta, mmr_paths))
+    }
+
+    /// Loads a page of the nullifiers matching the `nullifier_prefixes` created in the block range
+    /// `(block_start, block_end]`.
+    ///
+    /// If `block_end` is [None] or after the chain tip, the nullifiers are loaded up to the chain
+    /// tip. Returns the end of the block range with the page.
+    pub async fn sync_nullifiers(
+        &self,
+        nullifier_prefixes: Vec<u32>,
+        block_start: BlockNumber,
+        block_end: Option<BlockNumber>,
+        page: PageRequest<NullifierSyncKey>,
+    ) -> Result<(BlockNumber, Page<NullifierInfo, NullifierSyncKey>), DatabaseError> {
+        let chain_tip = self.inner.read().await.chain_tip();
+        let block_end = block_end.map_or(chain_tip, |block_end| block_end.min(chain_tip));
+
+        let page = self
+            .db
+            .select_nullifiers_by_block_range_page(block_start, block_end, nullifier_prefixes, page)
+            .await?;
+
+        Ok((block_end, page))
+    }
+
     /// Returns data needed by the block producer to construct and prove the next block.
     pub async fn get_block_inputs(
         &self,
@@ -420,6 +1390,64 @@ impl State {
         Ok((latest, peaks, account_states, nullifier_input_records))
     }
 
+    /// Returns the hashes of the accounts with their authentication paths in the account tree as of
+    /// block `block_num`, or the latest block if [None], together with the header of that block
+    /// whose `account_root` they verify against.
+    ///
+    /// Accounts which are not part of the chain are returned with an empty hash, the path then
+    /// proves that the account is not in the tree. If `include_details` is set, the full state of
+    /// the public accounts is also returned. Only the latest `MAX_HISTORICAL_BLOCKS` blocks can be
+    /// requested.
+    #[instrument(target = "miden-store", skip_all, ret(level = "debug"), err)]
+    pub async fn get_account_proofs(
+        &self,
+        account_ids: &[AccountId],
+        include_details: bool,
+        block_num: Option<BlockNumber>,
+    ) -> Result<(BlockHeader, Vec<AccountProof>), GetAccountProofsError> {
+        // the read lock also prevents new blocks from being committed, so that the header and
+        // account details are consistent with the account tree
+        let inner = self.inner.read().await;
+        let openings = inner.open_accounts_at(account_ids, block_num)?;
+
+        let block_header = self
+            .db
+            .select_block_header_by_block_num(block_num)
+            .await?
+            .ok_or(GetAccountProofsError::DbBlockHeaderEmpty)?;
+
+        let details: BTreeMap<AccountId, Account> = if include_details {
+            let accounts = match block_num {
+                Some(block_num) => {
+                    self.db.select_accounts_at_block(account_ids.to_vec(), block_num).await?
+                },
+                None => self.db.select_accounts_by_ids(account_ids.to_vec()).await?,
+            };
+            accounts
+                .into_iter()
+                .filter_map(|info| Some((info.summary.account_id.into(), info.details?)))
+                .collect()
+        } else {
+            BTreeMap::new()
+        };
+
+        let proofs = account_ids
+            .iter()
+            .zip(openings)
+            .map(|(&account_id, ValuePath { value, path })| {
+                AccountProof {
+                    account_id,
+                    account_hash: value,
+                    path,
+                    // the same account may be requested more than once
+                    details: details.get(&account_id).cloned(),
+                }
+            })
+            .collect();
+
+        Ok((block_header, proofs))
+    }
+
     /// Returns data needed by the block producer to verify transactions validity.
     #[instrument(target = "miden-store", skip_all, ret)]
     pub async fn get_transaction_inputs(
@@ -444,31 +1472,258 @@ impl State {
         TransactionInputs { account_hash, nullifiers }
     }
 
-    /// Lists all known nullifiers with their inclusion blocks, intended for testing.
-    pub async fn list_nullifiers(&self) -> Result<Vec<(Nullifier, u32)>, DatabaseError> {
-        self.db.select_nullifiers().await
+    /// Lists a page of the known nullifiers with their inclusion blocks, intended for testing.
+    pub async fn list_nullifiers(
+        &self,
+        filter: NullifierFilter,
+        page: PageRequest<Nullifier>,
+    ) -> Result<Page<(Nullifier, BlockNumber), Nullifier>, DatabaseError> {
+        self.db.select_nullifiers_page(filter, page).await
+    }
+
+    /// Lists a page of the known accounts, with their ids, latest state hash, and block at which
+    /// the account was last modified, intended for testing.
+    pub async fn list_accounts(
+        &self,
+        filter: AccountFilter,
+        page: PageRequest<AccountId>,
+    ) -> Result<Page<AccountInfo, AccountId>, DatabaseError> {
+        self.db.select_accounts_page(filter, page).await
     }
 
-    /// Lists all known accounts, with their ids, latest state hash, and block at which the account was last
-    /// modified, intended for testing.
-    pub async fn list_accounts(&self) -> Result<Vec<AccountInfo>, DatabaseError> {
-        self.db.select_accounts().await
+    /// Returns a page of the metadata of the accounts matching the `filter`.
+    pub async fn query_accounts(
+        &self,
+        filter: AccountMetadataFilter,
+        page: PageRequest<AccountId>,
+    ) -> Result<Page<AccountMetadata, AccountId>, DatabaseError> {
+        self.db.select_account_metadata_page(filter, page).await
     }
 
-    /// Lists all known notes, intended for testing.
-    pub async fn list_notes(&self) -> Result<Vec<Note>, DatabaseError> {
-        self.db.select_notes().await
+    /// Lists a page of the known notes, intended for testing.
+    pub async fn list_notes(
+        &self,
+        filter: NoteFilter,
+        page: PageRequest<NoteKey>,
+    ) -> Result<Page<Note, NoteKey>, DatabaseError> {
+        self.db.select_notes_page(filter, page).await
     }
 
     /// Returns details for public (on-chain) account.
     pub async fn get_account_details(&self, id: AccountId) -> Result<AccountInfo, DatabaseError> {
         self.db.select_account(id).await
     }
+
+    /// Returns the values of the storage slots `slot_indices` of the public account with the given
+    /// ID, with their authentication paths in the account's storage.
+    pub async fn get_account_storage_slots(
+        &self,
+        id: AccountId,
+        slot_indices: &[u32],
+    ) -> Result<(AccountStateHeader, Vec<StorageSlotProof>), AccountStateQueryError> {
+        let (summary, account) = self.select_public_account(id).await?;
+
+        let slots = slot_indices
+            .iter()
+            .map(|&index| {
+                let leaf_index = LeafIndex::new(index.into())
+                    .map_err(|_| AccountStateQueryError::InvalidSlotIndex(index))?;
+                let ValuePath { value, path } = account.storage().slots().open(&leaf_index);
+
+                Ok(StorageSlotProof { index, value, path })
+            })
+            .collect::<Result<_, AccountStateQueryError>>()?;
+
+        Ok((AccountStateHeader::new(summary, &account), slots))
+    }
+
+    /// Returns the balances of the fungible assets issued by `faucet_ids` in the vault of the
+    /// public account with the given ID, with the openings of the assets in the account's vault.
+    pub async fn get_account_vault_balance(
+        &self,
+        id: AccountId,
+        faucet_ids: &[AccountId],
+    ) -> Result<(AccountStateHeader, Vec<VaultBalanceProof>), AccountStateQueryError> {
+        let (summary, account) = self.select_public_account(id).await?;
+
+        let balances = faucet_ids
+            .iter()
+            .map(|&faucet_id| {
+                let not_fungible_faucet = AccountStateQueryError::NotFungibleFaucet(faucet_id);
+                let asset = miden_objects::accounts::AccountId::try_from(faucet_id)
+                    .ok()
+                    .and_then(|faucet_id| FungibleAsset::new(faucet_id, 0).ok())
+                    .ok_or(not_fungible_faucet)?;
+
+                // The faucet is fungible at this point, so the lookup can't fail.
+                let balance = account.vault().get_balance(asset.faucet_id()).unwrap_or_default();
+                let proof =
+                    account.vault().asset_tree().open(&Asset::from(asset).vault_key().into());
+
+                Ok(VaultBalanceProof { faucet_id, balance, proof })
+            })
+            .collect::<Result<_, AccountStateQueryError>>()?;
+
+        Ok((AccountStateHeader::new(summary, &account), balances))
+    }
+
+    /// Returns the latest state of the public account with the given ID.
+    async fn select_public_account(
+        &self,
+        id: AccountId,
+    ) -> Result<(AccountSummary, Account), DatabaseError> {
+        let AccountInfo { summary, details } = self.db.select_account(id).await?;
+        let account = details.ok_or(DatabaseError::AccountNotOnChain(id))?;
+
+        Ok((summary, account))
+    }
+
+    /// Returns the state of the account with the given ID as of the given block.
+    pub async fn get_account_state_at_block(
+        &self,
+        id: AccountId,
+        block_num: BlockNumber,
+    ) -> Result<AccountInfo, DatabaseError> {
+        self.db.select_account_at_block(id, block_num).await
+    }
+
+    /// Returns the updates of the on-chain account with the given ID in the block range
+    /// `(from_block_num, to_block_num]`.
+    pub async fn get_account_delta(
+        &self,
+        id: AccountId,
+        from_block_num: BlockNumber,
+        to_block_num: BlockNumber,
+    ) -> Result<Vec<AccountHistoryRecord>, DatabaseError> {
+        self.db.select_account_history(id, from_block_num, to_block_num).await
+    }
+}
+
+// VERIFICATION
+// ================================================================================================
+
+/// Checks the data stored in the `db` is consistent with the stored block headers.
+///
+/// The in-memory structures are rebuilt block by block from the stored nullifiers, account
+/// hashes and block headers, and checked against each block header, together with the note root
+/// recomputed from the stored notes.
+///
+/// The account roots are computed from the account history. A database created before the
+/// history was recorded only has the latest state of each account, so its older blocks are
+/// reported with an account root mismatch.
+///
+/// # Returns
+///
+/// The number of the last block, or an error describing the first block which doesn't match its
+/// header.
+#[instrument(target = "miden-store", skip_all, err)]
+pub async fn verify_db(db: &dyn Storage) -> Result<BlockNumber, VerifyError> {
+    let block_headers = db.select_block_headers().await?;
+    let mut nullifiers = db.select_nullifiers().await?.into_iter().peekable();
+    let mut account_hashes = db.select_account_hash_history().await?.into_iter().peekable();
+    let mut notes = db.select_notes().await?.into_iter().peekable();
+
+    let mut inner =
+        InnerState::new(NullifierTree::with_entries([])?, Mmr::new(), SimpleSmt::new()?);
+
+    for (expected_block_num, block_header) in (GENESIS_BLOCK..).zip(block_headers.iter()) {
+        let block_num = block_header.block_num();
+        if block_num != expected_block_num {
+            return Err(VerifyError::BlockHeaderNotFound(expected_block_num));
+        }
+
+        while let Some((nullifier, _)) = nullifiers.next_if(|(_, n)| *n == block_num) {
+            inner
+                .nullifier_tree
+                .insert(&nullifier, block_num)
+                .map_err(|error| VerifyError::FailedToUpdateNullifierTree { block_num, error })?;
+        }
+
+        while let Some((account_id, _, account_hash)) =
+            account_hashes.next_if(|(_, n, _)| *n == block_num)
+        {
+            inner
+                .account_tree
+                .insert(LeafIndex::new_max_depth(account_id), account_hash.into());
+        }
+
+        let block_notes: Vec<_> =
+            iter::from_fn(|| notes.next_if(|note| note.block_num == block_num))
+                .map(|note| note.note_created)
+                .collect();
+
+        // the note root of the genesis block isn't computed from a note tree
+        if block_num != GENESIS_BLOCK {
+            let note_tree = build_note_tree(&block_notes).map_err(|error| {
+                VerifyError::FailedToBuildNoteTree { block_num, error: Box::new(error) }
+            })?;
+            if note_tree.root() != block_header.note_root() {
+                return Err(StateMismatchError { block_num, reason: "note root" }.into());
+            }
+        }
+
+        inner.chain_mmr.add(block_header.hash());
+        inner.verify(block_header)?;
+    }
+
+    block_headers
+        .last()
+        .map(BlockHeader::block_num)
+        .ok_or(VerifyError::DbBlockHeaderEmpty)
+}
+
+/// Checks the in-memory structures loaded from the `db` are consistent with the latest block
+/// header.
+///
+/// Unlike [verify_db], only the chain tip is checked, so this also applies to a database imported
+/// from an archive, which lacks the history needed to check the older blocks.
+///
+/// # Returns
+///
+/// The number of the last block, or an error describing the mismatch.
+#[instrument(target = "miden-store", skip_all, err)]
+pub async fn verify_chain_tip(db: &dyn Storage) -> Result<BlockNumber, VerifyError> {
+    let tip_header = db
+        .select_block_header_by_block_num(None)
+        .await?
+        .ok_or(VerifyError::DbBlockHeaderEmpty)?;
+
+    let inner = InnerState::load(db).await?;
+    inner.verify(&tip_header)?;
+
+    Ok(tip_header.block_num())
 }
 
 // UTILITIES
 // ================================================================================================
 
+/// Returns the delta of the chain MMR between the blocks `block_num` (exclusive) and
+/// `to_block_num` (inclusive) of a sync request.
+fn get_mmr_delta(
+    chain_mmr: &Mmr,
+    block_num: BlockNumber,
+    to_block_num: BlockNumber,
+) -> Result<MmrDelta, StateSyncError> {
+    if block_num == to_block_num {
+        // The client is in sync with the chain tip.
+        return Ok(MmrDelta { forest: block_num as usize, data: vec![] });
+    }
+
+    // Important notes about the boundary conditions:
+    //
+    // - The Mmr forest is 1-indexed whereas the block number is 0-indexed. The Mmr root
+    // contained in the block header always lag behind by one block, this is because the Mmr
+    // leaves are hashes of block headers, and we can't have self-referential hashes. These two
+    // points cancel out and don't require adjusting.
+    // - Mmr::get_delta is inclusive, whereas the sync request block_num is defined to be
+    // exclusive, so the from_forest has to be adjusted with a +1
+    let from_forest = (block_num + 1) as usize;
+    let to_forest = to_block_num as usize;
+    chain_mmr
+        .get_delta(from_forest, to_forest)
+        .map_err(StateSyncError::FailedToBuildMmrDelta)
+}
+
 /// Creates a [BlockNoteTree] from the `notes`.
 #[instrument(target = "miden-store", skip_all)]
 pub fn build_note_tree(notes: &[NoteCreated]) -> Result<BlockNoteTree, ApplyBlockError> {
@@ -490,7 +1745,7 @@ pub fn build_note_tree(notes: &[NoteCreated]) -> Result<BlockNoteTree, ApplyBloc
 }
 
 #[instrument(target = "miden-store", skip_all)]
-async fn load_nullifier_tree(db: &mut Db) -> Result<NullifierTree, StateInitializationError> {
+async fn load_nullifier_tree(db: &dyn Storage) -> Result<NullifierTree, StateInitializationError> {
     let nullifiers = db.select_nullifiers().await?;
     let len = nullifiers.len();
 
@@ -508,8 +1763,59 @@ async fn load_nullifier_tree(db: &mut Db) -> Result<NullifierTree, StateInitiali
     Ok(nullifier_tree)
 }
 
+/// Rebuilds the in-memory structures from the snapshot and replays the blocks applied after it.
+///
+/// Returns [None] if no snapshot was written yet.
+#[instrument(target = "miden-store", skip_all)]
+async fn load_from_snapshot(
+    db: &dyn Storage,
+    snapshot_file: &SnapshotFile,
+) -> Result<Option<InnerState>, SnapshotError> {
+    let now = Instant::now();
+
+    let Some(snapshot) = snapshot_file.read()? else {
+        return Ok(None);
+    };
+    let snapshot_block_num = snapshot.block_num;
+
+    let (nullifier_tree, chain_mmr, account_tree) = snapshot.into_parts()?;
+    let mut inner = InnerState::new(nullifier_tree, chain_mmr, account_tree);
+
+    let snapshot_header = db
+        .select_block_header_by_block_num(Some(snapshot_block_num))
+        .await?
+        .ok_or(SnapshotError::BlockHeaderNotFound(snapshot_block_num))?;
+    inner.verify(&snapshot_header)?;
+
+    let replay = db.select_state_since(snapshot_block_num).await?;
+    for block_header in replay.block_headers.iter() {
+        inner.chain_mmr.add(block_header.hash());
+    }
+    for (nullifier, block_num) in replay.nullifiers.iter() {
+        inner.nullifier_tree.insert(nullifier, *block_num)?;
+    }
+    for (account_id, account_hash) in replay.account_hashes {
+        inner
+            .account_tree
+            .insert(LeafIndex::new_max_depth(account_id), account_hash.into());
+    }
+
+    let tip_header = replay.block_headers.last().unwrap_or(&snapshot_header);
+    inner.verify(tip_header)?;
+
+    info!(
+        snapshot_block_num,
+        num_replayed_blocks = replay.block_headers.len(),
+        state_construction = now.elapsed().as_secs(),
+        COMPONENT,
+        "Loaded state from snapshot"
+    );
+
+    Ok(Some(inner))
+}
+
 #[instrument(target = "miden-store", skip_all)]
-async fn load_mmr(db: &mut Db) -> Result<Mmr, StateInitializationError> {
+async fn load_mmr(db: &dyn Storage) -> Result<Mmr, StateInitializationError> {
     let block_hashes: Vec<RpoDigest> =
         db.select_block_headers().await?.iter().map(BlockHeader::hash).collect();
 
@@ -518,7 +1824,7 @@ async fn load_mmr(db: &mut Db) -> Result<Mmr, StateInitializationError> {
 
 #[instrument(target = "miden-store", skip_all)]
 async fn load_accounts(
-    db: &mut Db,
+    db: &dyn Storage,
 ) -> Result<SimpleSmt<ACCOUNT_TREE_DEPTH>, StateInitializationError> {
     let account_data: Vec<_> = db
         .select_account_hashes()
@@ -530,3 +1836,479 @@ async fn load_accounts(
     SimpleSmt::with_leaves(account_data)
         .map_err(StateInitializationError::FailedToCreateAccountsTree)
 }
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::{accounts::ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, Felt, ONE};
+
+    use super::*;
+    use crate::{db::MemoryDb, genesis::GenesisState};
+
+    fn num_to_rpo_digest(n: u64) -> RpoDigest {
+        RpoDigest::new([ZERO, ZERO, ZERO, Felt::new(n)])
+    }
+
+    /// Writes a block without notes to the `db`, without checking it against the stored state.
+    async fn write_block(
+        db: &dyn Storage,
+        block_header: BlockHeader,
+        nullifiers: Vec<Nullifier>,
+        accounts: Vec<AccountUpdateDetails>,
+    ) {
+        let (allow_acquire, _acquired_allowed) = oneshot::channel();
+        let (inform_acquire_done, acquire_done) = oneshot::channel();
+        inform_acquire_done.send(()).unwrap();
+
+        db.apply_block(
+            allow_acquire,
+            acquire_done,
+            block_header,
+            vec![],
+            vec![],
+            nullifiers,
+            accounts,
+            vec![],
+        )
+        .await
+        .unwrap();
+    }
+
+    #[test]
+    fn test_trees_at_past_blocks() {
+        let mut inner = InnerState::new(
+            NullifierTree::with_entries([]).unwrap(),
+            vec![num_to_rpo_digest(0)].into(),
+            SimpleSmt::new().unwrap(),
+        );
+
+        // apply blocks 1 to 3, each consuming a nullifier in the same leaf and updating the same
+        // account twice
+        let (account_id, absent_account_id) = (1, 2);
+        let nullifier = |block_num: BlockNumber| {
+            Nullifier::from(RpoDigest::new([Felt::from(block_num), ZERO, ZERO, ONE]))
+        };
+        let mut trees = vec![(inner.nullifier_tree.clone(), inner.account_tree.clone())];
+        for block_num in 1..=3 {
+            inner.nullifier_tree.insert(&nullifier(block_num), block_num).unwrap();
+            let prev_account_hashes = [ONE, Felt::from(2u32)]
+                .into_iter()
+                .map(|update| {
+                    let prev_hash = inner.account_tree.insert(
+                        LeafIndex::new_max_depth(account_id),
+                        [update, ZERO, ZERO, Felt::from(block_num)],
+                    );
+                    (account_id, prev_hash)
+                })
+                .collect();
+            inner.chain_mmr.add(num_to_rpo_digest(block_num as u64));
+            inner.push_tree_changes(BlockTreeChanges {
+                nullifiers: vec![nullifier(block_num)],
+                prev_account_hashes,
+            });
+
+            trees.push((inner.nullifier_tree.clone(), inner.account_tree.clone()));
+        }
+
+        let nullifiers: Vec<Nullifier> = (1..=4).map(nullifier).collect();
+        let account_ids = [account_id, absent_account_id];
+        for (block_num, (nullifier_tree, account_tree)) in trees.into_iter().enumerate() {
+            let block_num = Some(block_num as BlockNumber);
+
+            let proofs = inner.open_nullifiers_at(&nullifiers, block_num).unwrap();
+            for (proof, nullifier) in proofs.into_iter().zip(&nullifiers) {
+                assert_eq!(proof.into_parts(), nullifier_tree.open(nullifier).into_parts());
+            }
+
+            let openings = inner.open_accounts_at(&account_ids, block_num).unwrap();
+            for (opening, &account_id) in openings.into_iter().zip(&account_ids) {
+                let expected = account_tree.open(&LeafIndex::new_max_depth(account_id));
+                assert_eq!(opening.value, expected.value);
+                assert_eq!(opening.path, expected.path);
+            }
+        }
+
+        assert!(matches!(
+            inner.open_nullifiers_at(&nullifiers, Some(4)),
+            Err(HistoricalStateError::BlockAfterChainTip { block_num: 4, chain_tip: 3 })
+        ));
+
+        // blocks before the history are not available
+        inner.tree_history.pop_front();
+        assert!(matches!(
+            inner.open_accounts_at(&account_ids, Some(0)),
+            Err(HistoricalStateError::BlockNotInHistory { block_num: 0, oldest_block_num: 1 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_tree_history_is_rebuilt_from_db() {
+        // blocks 1 to 3 are written to the DB as by a previous run of the node, each consuming a
+        // nullifier and updating an account, which is created by block 1
+        let db = MemoryDb::new(GenesisState::new(vec![], 1, 1)).unwrap();
+        let account_id = ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN;
+        let nullifier = |block_num: BlockNumber| {
+            Nullifier::from(RpoDigest::new([Felt::from(block_num), ZERO, ZERO, ONE]))
+        };
+
+        let mut inner = InnerState::load(&db).await.unwrap();
+        let mut prev_hash =
+            db.select_block_header_by_block_num(None).await.unwrap().unwrap().hash();
+        let mut trees = vec![(inner.nullifier_tree.clone(), inner.account_tree.clone())];
+        for block_num in 1..=3 {
+            let account_hash = num_to_rpo_digest(block_num as u64);
+            inner.nullifier_tree.insert(&nullifier(block_num), block_num).unwrap();
+            inner
+                .account_tree
+                .insert(LeafIndex::new_max_depth(account_id), account_hash.into());
+            let block_header = BlockHeader::new(
+                prev_hash,
+                block_num,
+                inner.chain_mmr.peaks(inner.chain_mmr.forest()).unwrap().hash_peaks(),
+                inner.account_tree.root(),
+                inner.nullifier_tree.root(),
+                build_note_tree(&[]).unwrap().root(),
+                num_to_rpo_digest(7),
+                num_to_rpo_digest(8),
+                ONE,
+                ONE,
+            );
+            inner.chain_mmr.add(block_header.hash());
+
+            let account_update = AccountUpdateDetails {
+                account_id: account_id.try_into().unwrap(),
+                final_state_hash: account_hash,
+                details: None,
+            };
+            write_block(&db, block_header, vec![nullifier(block_num)], vec![account_update]).await;
+            prev_hash = block_header.hash();
+            trees.push((inner.nullifier_tree.clone(), inner.account_tree.clone()));
+        }
+
+        let assert_past_trees = |inner: &InnerState, chain_tip: BlockNumber| {
+            assert_eq!(inner.tree_history.len(), chain_tip as usize);
+            for (block_num, (nullifier_tree, account_tree)) in
+                trees.iter().enumerate().take(chain_tip as usize)
+            {
+                let block_num = Some(block_num as BlockNumber);
+                let proofs = inner.open_nullifiers_at(&[nullifier(1)], block_num).unwrap();
+                assert_eq!(
+                    proofs[0].clone().into_parts(),
+                    nullifier_tree.open(&nullifier(1)).into_parts()
+                );
+
+                let openings = inner.open_accounts_at(&[account_id], block_num).unwrap();
+                let expected = account_tree.open(&LeafIndex::new_max_depth(account_id));
+                assert_eq!(openings[0].value, expected.value);
+                assert_eq!(openings[0].path, expected.path);
+            }
+        };
+
+        // the past blocks can be proven against right after loading
+        let state = State::load(db, None).await.unwrap();
+        assert_past_trees(&*state.inner.read().await, 3);
+
+        // and after a rollback
+        state.rollback_to(2).await.unwrap();
+        assert_past_trees(&*state.inner.read().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_rollback_is_discarded_on_state_mismatch() {
+        // blocks whose roots don't match their data, as in a corrupted DB
+        let db = MemoryDb::new(GenesisState::new(vec![], 1, 1)).unwrap();
+        let mut prev_hash =
+            db.select_block_header_by_block_num(None).await.unwrap().unwrap().hash();
+        for block_num in 1..=2 {
+            let block_header = BlockHeader::new(
+                prev_hash,
+                block_num,
+                num_to_rpo_digest(3),
+                num_to_rpo_digest(4),
+                num_to_rpo_digest(5),
+                num_to_rpo_digest(6),
+                num_to_rpo_digest(7),
+                num_to_rpo_digest(8),
+                ONE,
+                ONE,
+            );
+            write_block(&db, block_header, vec![], vec![]).await;
+            prev_hash = block_header.hash();
+        }
+        let state = State::load(db, None).await.unwrap();
+
+        let res = state.rollback_to(1).await;
+        assert!(matches!(res, Err(RollbackError::StateMismatch(_))));
+
+        // neither the DB nor the in-memory state were rolled back
+        let chain_tip = state.get_block_header(None).await.unwrap().unwrap();
+        assert_eq!(chain_tip.block_num(), 2);
+        assert_eq!(state.inner.read().await.chain_tip(), 2);
+        assert_eq!(*state.write_queue.next_block.borrow(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_prune_waits_for_writers() {
+        let db = MemoryDb::new(GenesisState::new(vec![], 1, 1)).unwrap();
+        let state = Arc::new(State::load(db, None).await.unwrap());
+        let retention = RetentionConfig {
+            notes: Some(1),
+            consumed_notes: None,
+            account_history: Some(1),
+            interval: 1,
+        };
+
+        // a block is being written
+        let writer = state.writer.lock().await;
+
+        let prune = tokio::spawn({
+            let state = state.clone();
+            async move { state.prune(&retention).await }
+        });
+        tokio::task::yield_now().await;
+        assert!(!prune.is_finished());
+
+        drop(writer);
+        prune.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_write_queue_orders_blocks() {
+        let queue = Arc::new(WriteQueue::new(0));
+
+        // block 2 arrived early, it waits for block 1 to be committed
+        let early = tokio::spawn({
+            let queue = queue.clone();
+            async move { queue.wait_turn(2).await.map(drop) }
+        });
+
+        let slot = queue.wait_turn(1).await.unwrap();
+        tokio::task::yield_now().await;
+        assert!(!early.is_finished());
+
+        queue.set_chain_tip(1);
+        drop(slot);
+        early.await.unwrap().unwrap();
+
+        // blocks which were already applied don't wait
+        queue.wait_turn(1).await.unwrap();
+        // blocks too far ahead of the chain tip can't be queued
+        assert!(matches!(
+            queue.wait_turn(2 + WRITE_QUEUE_CAPACITY as BlockNumber).await,
+            Err(ApplyBlockError::WriteQueueFull { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_write_queue_aborts_waiters_on_rollback() {
+        let queue = Arc::new(WriteQueue::new(2));
+
+        let early = tokio::spawn({
+            let queue = queue.clone();
+            async move { queue.wait_turn(5).await.map(drop) }
+        });
+        tokio::task::yield_now().await;
+
+        queue.set_chain_tip(1);
+        assert!(matches!(
+            early.await.unwrap(),
+            Err(ApplyBlockError::WriteQueueAborted { block_num: 5, next_block: 2 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_apply_blocks_out_of_order() {
+        let db = MemoryDb::new(GenesisState::new(vec![], 1, 1)).unwrap();
+        let state = Arc::new(State::load(db, None).await.unwrap());
+        let genesis = state.get_block_header(None).await.unwrap().unwrap();
+        let (mut chain_mmr, account_root, nullifier_root) = {
+            let inner = state.inner.read().await;
+            (inner.chain_mmr.clone(), inner.account_tree.root(), inner.nullifier_tree.root())
+        };
+
+        // headers of empty blocks, the chain MMR is extended with each new block
+        let empty_block = |prev: &BlockHeader, chain_mmr: &mut Mmr, note_root| {
+            let block_header = BlockHeader::new(
+                prev.hash(),
+                prev.block_num() + 1,
+                chain_mmr.peaks(chain_mmr.forest()).unwrap().hash_peaks(),
+                account_root,
+                nullifier_root,
+                note_root,
+                num_to_rpo_digest(7),
+                num_to_rpo_digest(8),
+                ONE,
+                ONE,
+            );
+            chain_mmr.add(block_header.hash());
+            block_header
+        };
+        let note_root = build_note_tree(&[]).unwrap().root();
+        let apply_block = |block_header| {
+            let state = state.clone();
+            tokio::spawn(async move {
+                state.apply_block(block_header, vec![], vec![], vec![], vec![], vec![]).await
+            })
+        };
+
+        // block 2 arrives first and waits for block 1
+        let block_1 = empty_block(&genesis, &mut chain_mmr, note_root);
+        let block_2 = empty_block(&block_1, &mut chain_mmr, note_root);
+        let early = apply_block(block_2);
+        tokio::task::yield_now().await;
+        assert!(!early.is_finished());
+
+        apply_block(block_1).await.unwrap().unwrap();
+        early.await.unwrap().unwrap();
+        assert_eq!(state.inner.read().await.chain_tip(), 2);
+
+        // block 4 waits for block 3, and is aborted once the request for block 3 fails
+        let block_3 = empty_block(&block_2, &mut chain_mmr.clone(), num_to_rpo_digest(6));
+        let valid_block_3 = empty_block(&block_2, &mut chain_mmr, note_root);
+        let block_4 = empty_block(&valid_block_3, &mut chain_mmr, note_root);
+        let early = apply_block(block_4);
+        tokio::task::yield_now().await;
+        assert!(!early.is_finished());
+
+        let res = apply_block(block_3).await.unwrap();
+        assert!(matches!(res, Err(ApplyBlockError::NewBlockInvalidNoteRoot)));
+        assert!(matches!(
+            early.await.unwrap(),
+            Err(ApplyBlockError::WriteQueueAborted { block_num: 4, next_block: 3 })
+        ));
+        assert_eq!(*state.write_queue.next_block.borrow(), 3);
+    }
+
+    /// Returns the header of an empty block on top of the chain tip of the `state`.
+    async fn empty_block_header(state: &State) -> BlockHeader {
+        let chain_tip = state.get_block_header(None).await.unwrap().unwrap();
+        let inner = state.inner.read().await;
+        BlockHeader::new(
+            chain_tip.hash(),
+            chain_tip.block_num() + 1,
+            inner.chain_mmr.peaks(inner.chain_mmr.forest()).unwrap().hash_peaks(),
+            inner.account_tree.root(),
+            inner.nullifier_tree.root(),
+            build_note_tree(&[]).unwrap().root(),
+            num_to_rpo_digest(7),
+            num_to_rpo_digest(8),
+            ONE,
+            ONE,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_apply_block_is_idempotent() {
+        let db = MemoryDb::new(GenesisState::new(vec![], 1, 1)).unwrap();
+        let state = State::load(db, None).await.unwrap();
+
+        let block_header = empty_block_header(&state).await;
+        state
+            .apply_block(block_header, vec![], vec![], vec![], vec![], vec![])
+            .await
+            .unwrap();
+
+        // re-submitting the stored block succeeds without writing anything
+        let mut committed_blocks = state.subscribe_blocks();
+        state
+            .apply_block(block_header, vec![], vec![], vec![], vec![], vec![])
+            .await
+            .unwrap();
+        assert!(committed_blocks.try_recv().is_err());
+        assert_eq!(state.db.select_block_headers().await.unwrap().len(), 2);
+        assert_eq!(state.inner.read().await.chain_tip(), 1);
+        assert_eq!(*state.write_queue.next_block.borrow(), 2);
+
+        // a different block at the same height conflicts with the stored one
+        let conflicting_header = BlockHeader::new(
+            block_header.prev_hash(),
+            1,
+            block_header.chain_root(),
+            block_header.account_root(),
+            block_header.nullifier_root(),
+            block_header.note_root(),
+            block_header.batch_root(),
+            block_header.proof_hash(),
+            ONE,
+            Felt::from(2u32),
+        );
+        let res = state
+            .apply_block(conflicting_header, vec![], vec![], vec![], vec![], vec![])
+            .await;
+        assert!(matches!(
+            res,
+            Err(ApplyBlockError::ConflictingBlock { block_num: 1, stored_hash, block_hash })
+                if stored_hash == block_header.hash() && block_hash == conflicting_header.hash()
+        ));
+        assert!(committed_blocks.try_recv().is_err());
+        assert_eq!(state.db.select_block_headers().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_apply_block_returns_db_error() {
+        let db = MemoryDb::new(GenesisState::new(vec![], 1, 1)).unwrap();
+        let state = State::load(db, None).await.unwrap();
+
+        // the DB rejects the block before signaling it is ready to be committed
+        let transaction = TransactionHeader {
+            id: TransactionId::from(num_to_rpo_digest(1)),
+            account_id: ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN.try_into().unwrap(),
+            initial_account_hash: num_to_rpo_digest(2),
+            final_account_hash: num_to_rpo_digest(3),
+            input_notes: vec![],
+            output_notes: vec![],
+        };
+        let block_header = empty_block_header(&state).await;
+        let res = state
+            .apply_block(
+                block_header,
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![transaction.clone(), transaction],
+            )
+            .await;
+        assert!(matches!(
+            res,
+            Err(ApplyBlockError::DatabaseError(DatabaseError::DuplicateEntry(_)))
+        ));
+        assert_eq!(state.inner.read().await.chain_tip(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_writes_are_serialized() {
+        let path = std::env::temp_dir()
+            .join(format!("miden-store-serialized-snapshot-{}.bin", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let snapshot_file = SnapshotFile::new(path.clone(), 1);
+        let db = MemoryDb::new(GenesisState::new(vec![], 1, 1)).unwrap();
+        let state = State::load(db, Some(snapshot_file.clone())).await.unwrap();
+
+        // a snapshot is skipped while the previous one is still being written
+        let (finish_write, write_finished) = oneshot::channel::<()>();
+        *state.snapshot_write.lock().await = Some(tokio::spawn(async move {
+            let _ = write_finished.await;
+        }));
+        state.write_snapshot(snapshot_file.clone()).await;
+        assert!(!path.exists());
+
+        finish_write.send(()).unwrap();
+        state.snapshot_write.lock().await.take().unwrap().await.unwrap();
+        state.write_snapshot(snapshot_file.clone()).await;
+        state.snapshot_write.lock().await.take().unwrap().await.unwrap();
+        assert_eq!(snapshot_file.read().unwrap().unwrap().block_num, 0);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_write_queue_is_bounded() {
+        let queue = WriteQueue::new(0);
+
+        let _slots: Vec<_> = iter::repeat_with(|| queue.slots.try_acquire().unwrap())
+            .take(WRITE_QUEUE_CAPACITY)
+            .collect();
+
+        assert!(matches!(queue.wait_turn(1).await, Err(ApplyBlockError::WriteQueueFull { .. })));
+    }
+}